@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const IFAL_ADDRESS: u16 = 1;
+const IFAL_LABEL: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum AddressLabelAttribute {
+    /// IPv6 prefix, the prefix length is stored in
+    /// [super::AddressLabelHeader::prefix_len]
+    Address(Ipv6Addr),
+    /// Label used by RFC 6724 source and destination address selection
+    Label(u32),
+    Other(DefaultNla),
+}
+
+impl nla::Nla for AddressLabelAttribute {
+    fn value_len(&self) -> usize {
+        match *self {
+            Self::Address(_) => IPV6_ADDR_LEN,
+            Self::Label(_) => 4,
+            Self::Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match *self {
+            Self::Address(ref addr) => buffer.copy_from_slice(&addr.octets()),
+            Self::Label(value) => NativeEndian::write_u32(buffer, value),
+            Self::Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match *self {
+            Self::Address(_) => IFAL_ADDRESS,
+            Self::Label(_) => IFAL_LABEL,
+            Self::Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for AddressLabelAttribute
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFAL_ADDRESS => Self::Address(parse_ipv6_addr(payload)?),
+            IFAL_LABEL => Self::Label(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
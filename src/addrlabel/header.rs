@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const ADDRESS_LABEL_HEADER_LEN: usize = 12;
+
+buffer!(AddressLabelMessageBuffer(ADDRESS_LABEL_HEADER_LEN) {
+    family: (u8, 0),
+    reserved: (u8, 1),
+    prefix_len: (u8, 2),
+    flags: (u8, 3),
+    index: (u32, 4..8),
+    seq: (u32, 8..12),
+    payload: (slice, ADDRESS_LABEL_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> AddressLabelMessageBuffer<&'a T> {
+    pub fn nlas(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload()).map(|nla| {
+            nla.map_err(|e| {
+                DecodeError::from(format!("AddressLabelMessageBuffer: {e}"))
+            })
+        })
+    }
+}
+
+// Linux kernel struct `struct ifaddrlblmsg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AddressLabelHeader {
+    /// Always [AddressFamily::Inet6]
+    pub family: AddressFamily,
+    pub prefix_len: u8,
+    /// Not used by kernel yet
+    pub flags: u8,
+    /// Interface index, `0` means any interface
+    pub index: u32,
+    /// Sequence number of the label table, kernel will reject
+    /// `RTM_GETADDRLABEL` with mismatched non-zero sequence number
+    pub seq: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<AddressLabelMessageBuffer<T>>
+    for AddressLabelHeader
+{
+    type Error = DecodeError;
+    fn parse(buf: &AddressLabelMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            prefix_len: buf.prefix_len(),
+            flags: buf.flags(),
+            index: buf.index(),
+            seq: buf.seq(),
+        })
+    }
+}
+
+impl Emitable for AddressLabelHeader {
+    fn buffer_len(&self) -> usize {
+        ADDRESS_LABEL_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = AddressLabelMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_reserved(0);
+        packet.set_prefix_len(self.prefix_len);
+        packet.set_flags(self.flags);
+        packet.set_index(self.index);
+        packet.set_seq(self.seq);
+    }
+}
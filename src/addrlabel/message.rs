@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    attribute::AddressLabelAttribute,
+    header::{AddressLabelHeader, AddressLabelMessageBuffer},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct AddressLabelMessage {
+    pub header: AddressLabelHeader,
+    pub attributes: Vec<AddressLabelAttribute>,
+}
+
+impl Emitable for AddressLabelMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressLabelMessageBuffer<&'a T>>
+    for AddressLabelMessage
+{
+    type Error = DecodeError;
+    fn parse(
+        buf: &AddressLabelMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: AddressLabelHeader::parse(buf)?,
+            attributes: Vec::<AddressLabelAttribute>::parse(buf)?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressLabelMessageBuffer<&'a T>>
+    for Vec<AddressLabelAttribute>
+{
+    type Error = DecodeError;
+    fn parse(
+        buf: &AddressLabelMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(AddressLabelAttribute::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
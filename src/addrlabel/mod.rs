@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::AddressLabelAttribute;
+pub use self::header::{AddressLabelHeader, AddressLabelMessageBuffer};
+pub use self::message::AddressLabelMessage;
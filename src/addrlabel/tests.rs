@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+
+use std::{net::Ipv6Addr, str::FromStr};
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    addrlabel::{
+        AddressLabelAttribute, AddressLabelHeader, AddressLabelMessage,
+        AddressLabelMessageBuffer,
+    },
+    AddressFamily,
+};
+
+// Kernel reply of `ip addrlabel list` for the default `::1/128` entry
+#[test]
+fn test_get_address_label() {
+    #[rustfmt::skip]
+    let data = vec![
+        // ifaddrlblmsg
+        // AF_INET6, reserved, prefix length, flags
+        0x0a, 0x00, 0x80, 0x00,
+        // ifindex
+        0x00, 0x00, 0x00, 0x00,
+        // seq
+        0x07, 0x00, 0x00, 0x00,
+        // IFAL_ADDRESS attribute
+        0x14, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        // IFAL_LABEL attribute
+        0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+    let actual =
+        AddressLabelMessage::parse(&AddressLabelMessageBuffer::new(&data))
+            .unwrap();
+
+    let expected = AddressLabelMessage {
+        header: AddressLabelHeader {
+            family: AddressFamily::Inet6,
+            prefix_len: 128,
+            flags: 0,
+            index: 0,
+            seq: 7,
+        },
+        attributes: vec![
+            AddressLabelAttribute::Address(Ipv6Addr::LOCALHOST),
+            AddressLabelAttribute::Label(0),
+        ],
+    };
+
+    assert_eq!(expected, actual);
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(data, buf);
+}
+
+// RTM_NEWADDRLABEL request sent by
+//      ip addrlabel add prefix 2001:db8::/32 dev eth0 label 100
+#[test]
+fn test_new_address_label() {
+    #[rustfmt::skip]
+    let data = vec![
+        // ifaddrlblmsg
+        // AF_INET6, reserved, prefix length, flags
+        0x0a, 0x00, 0x20, 0x00,
+        // ifindex
+        0x02, 0x00, 0x00, 0x00,
+        // seq
+        0x00, 0x00, 0x00, 0x00,
+        // IFAL_ADDRESS attribute
+        0x14, 0x00, 0x01, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        // IFAL_LABEL attribute
+        0x08, 0x00, 0x02, 0x00,
+        0x64, 0x00, 0x00, 0x00,
+    ];
+    let actual =
+        AddressLabelMessage::parse(&AddressLabelMessageBuffer::new(&data))
+            .unwrap();
+
+    let expected = AddressLabelMessage {
+        header: AddressLabelHeader {
+            family: AddressFamily::Inet6,
+            prefix_len: 32,
+            flags: 0,
+            index: 2,
+            seq: 0,
+        },
+        attributes: vec![
+            AddressLabelAttribute::Address(
+                Ipv6Addr::from_str("2001:db8::").unwrap(),
+            ),
+            AddressLabelAttribute::Label(100),
+        ],
+    };
+
+    assert_eq!(expected, actual);
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(data, buf);
+}
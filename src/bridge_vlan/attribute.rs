@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{BridgeVlanEntry, BridgeVlanGlobalOption};
+
+const BRIDGE_VLANDB_ENTRY: u16 = 1;
+const BRIDGE_VLANDB_DUMP_FLAGS: u16 = 2;
+const BRIDGE_VLANDB_GLOBAL_OPTIONS: u16 = 3;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeVlanAttribute {
+    Entry(Vec<BridgeVlanEntry>),
+    /// Only valid in `RTM_GETVLAN` dump request
+    DumpFlags(BridgeVlanDumpFlags),
+    GlobalOptions(Vec<BridgeVlanGlobalOption>),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().buffer_len(),
+            Self::DumpFlags(_) => BridgeVlanDumpFlags::LENGTH,
+            Self::GlobalOptions(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().emit(buffer),
+            Self::DumpFlags(value) => {
+                NativeEndian::write_u32(buffer, value.bits())
+            }
+            Self::GlobalOptions(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => BRIDGE_VLANDB_ENTRY | NLA_F_NESTED,
+            Self::DumpFlags(_) => BRIDGE_VLANDB_DUMP_FLAGS,
+            Self::GlobalOptions(_) => {
+                BRIDGE_VLANDB_GLOBAL_OPTIONS | NLA_F_NESTED
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanAttribute
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_ENTRY => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeVlanEntry::parse(nla)?);
+                }
+                Self::Entry(nlas)
+            }
+            BRIDGE_VLANDB_DUMP_FLAGS => Self::DumpFlags(
+                BridgeVlanDumpFlags::from_bits_retain(parse_u32(payload)?),
+            ),
+            BRIDGE_VLANDB_GLOBAL_OPTIONS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeVlanGlobalOption::parse(nla)?);
+                }
+                Self::GlobalOptions(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const BRIDGE_VLANDB_DUMPF_STATS: u32 = 1 << 0;
+const BRIDGE_VLANDB_DUMPF_GLOBAL: u32 = 1 << 1;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct BridgeVlanDumpFlags: u32 {
+        /// Include per-VLAN statistics in dump
+        const Stats = BRIDGE_VLANDB_DUMPF_STATS;
+        /// Dump global VLAN options instead of per-port entries
+        const Global = BRIDGE_VLANDB_DUMPF_GLOBAL;
+        const _ = !0;
+    }
+}
+
+impl BridgeVlanDumpFlags {
+    pub const LENGTH: usize = 4;
+}
@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::{BridgePortMulticastRouter, BridgePortState, BridgeVlanInfo};
+
+const BRIDGE_VLANDB_ENTRY_INFO: u16 = 1;
+const BRIDGE_VLANDB_ENTRY_RANGE: u16 = 2;
+const BRIDGE_VLANDB_ENTRY_STATE: u16 = 3;
+const BRIDGE_VLANDB_ENTRY_TUNNEL_INFO: u16 = 4;
+const BRIDGE_VLANDB_ENTRY_STATS: u16 = 5;
+const BRIDGE_VLANDB_ENTRY_MCAST_ROUTER: u16 = 6;
+const BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS: u16 = 7;
+const BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS: u16 = 8;
+const BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS: u16 = 9;
+
+const BRIDGE_VLANDB_TINFO_ID: u16 = 1;
+const BRIDGE_VLANDB_TINFO_CMD: u16 = 2;
+
+const BRIDGE_VLANDB_STATS_RX_BYTES: u16 = 1;
+const BRIDGE_VLANDB_STATS_RX_PACKETS: u16 = 2;
+const BRIDGE_VLANDB_STATS_TX_BYTES: u16 = 3;
+const BRIDGE_VLANDB_STATS_TX_PACKETS: u16 = 4;
+
+/// Per-VLAN entry nested in `BRIDGE_VLANDB_ENTRY`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeVlanEntry {
+    Info(BridgeVlanInfo),
+    /// Last VLAN ID of the range starting at `Info`
+    Range(u16),
+    /// Per-VLAN STP state
+    State(BridgePortState),
+    TunnelInfo(Vec<BridgeVlanTunnelEntry>),
+    Stats(Vec<BridgeVlanStats>),
+    MulticastRouter(BridgePortMulticastRouter),
+    /// Number of multicast groups joined, only in kernel reply
+    MulticastGroups(u32),
+    MulticastMaxGroups(u32),
+    NeighSuppress(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Info(_) => BridgeVlanInfo::LENGTH,
+            Self::TunnelInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::Stats(nlas) => nlas.as_slice().buffer_len(),
+            Self::MulticastGroups(_) | Self::MulticastMaxGroups(_) => 4,
+            Self::Range(_) => 2,
+            Self::State(_)
+            | Self::MulticastRouter(_)
+            | Self::NeighSuppress(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Info(value) => buffer[..BridgeVlanInfo::LENGTH]
+                .copy_from_slice(<[u8; 4]>::from(value).as_slice()),
+            Self::TunnelInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::Stats(nlas) => nlas.as_slice().emit(buffer),
+            Self::Range(value) => NativeEndian::write_u16(buffer, *value),
+            Self::State(value) => buffer[0] = (*value).into(),
+            Self::MulticastRouter(value) => buffer[0] = (*value).into(),
+            Self::MulticastGroups(value) | Self::MulticastMaxGroups(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::NeighSuppress(value) => buffer[0] = *value as u8,
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Info(_) => BRIDGE_VLANDB_ENTRY_INFO,
+            Self::Range(_) => BRIDGE_VLANDB_ENTRY_RANGE,
+            Self::State(_) => BRIDGE_VLANDB_ENTRY_STATE,
+            Self::TunnelInfo(_) => {
+                BRIDGE_VLANDB_ENTRY_TUNNEL_INFO | NLA_F_NESTED
+            }
+            Self::Stats(_) => BRIDGE_VLANDB_ENTRY_STATS | NLA_F_NESTED,
+            Self::MulticastRouter(_) => BRIDGE_VLANDB_ENTRY_MCAST_ROUTER,
+            Self::MulticastGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS,
+            Self::MulticastMaxGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS,
+            Self::NeighSuppress(_) => BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanEntry
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_ENTRY_INFO => {
+                Self::Info(BridgeVlanInfo::try_from(payload)?)
+            }
+            BRIDGE_VLANDB_ENTRY_RANGE => Self::Range(parse_u16(payload)?),
+            BRIDGE_VLANDB_ENTRY_STATE => Self::State(parse_u8(payload)?.into()),
+            BRIDGE_VLANDB_ENTRY_TUNNEL_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeVlanTunnelEntry::parse(nla)?);
+                }
+                Self::TunnelInfo(nlas)
+            }
+            BRIDGE_VLANDB_ENTRY_STATS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeVlanStats::parse(nla)?);
+                }
+                Self::Stats(nlas)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_ROUTER => {
+                Self::MulticastRouter(parse_u8(payload)?.into())
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS => {
+                Self::MulticastGroups(parse_u32(payload)?)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS => {
+                Self::MulticastMaxGroups(parse_u32(payload)?)
+            }
+            BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS => {
+                Self::NeighSuppress(parse_u8(payload)? > 0)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeVlanTunnelEntry {
+    /// Tunnel ID
+    Id(u32),
+    /// Either `RTM_SETLINK`(19) or `RTM_DELLINK`(17)
+    Command(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanTunnelEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) | Self::Command(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(value) | Self::Command(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => BRIDGE_VLANDB_TINFO_ID,
+            Self::Command(_) => BRIDGE_VLANDB_TINFO_CMD,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanTunnelEntry
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_TINFO_ID => Self::Id(parse_u32(payload)?),
+            BRIDGE_VLANDB_TINFO_CMD => Self::Command(parse_u32(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeVlanStats {
+    RxBytes(u64),
+    RxPackets(u64),
+    TxBytes(u64),
+    TxPackets(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanStats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RxBytes(_)
+            | Self::RxPackets(_)
+            | Self::TxBytes(_)
+            | Self::TxPackets(_) => 8,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RxBytes(value)
+            | Self::RxPackets(value)
+            | Self::TxBytes(value)
+            | Self::TxPackets(value) => NativeEndian::write_u64(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RxBytes(_) => BRIDGE_VLANDB_STATS_RX_BYTES,
+            Self::RxPackets(_) => BRIDGE_VLANDB_STATS_RX_PACKETS,
+            Self::TxBytes(_) => BRIDGE_VLANDB_STATS_TX_BYTES,
+            Self::TxPackets(_) => BRIDGE_VLANDB_STATS_TX_PACKETS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanStats
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_STATS_RX_BYTES => Self::RxBytes(parse_u64(payload)?),
+            BRIDGE_VLANDB_STATS_RX_PACKETS => {
+                Self::RxPackets(parse_u64(payload)?)
+            }
+            BRIDGE_VLANDB_STATS_TX_BYTES => Self::TxBytes(parse_u64(payload)?),
+            BRIDGE_VLANDB_STATS_TX_PACKETS => {
+                Self::TxPackets(parse_u64(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+const BRIDGE_VLANDB_GOPTS_ID: u16 = 1;
+const BRIDGE_VLANDB_GOPTS_RANGE: u16 = 2;
+const BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING: u16 = 3;
+const BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION: u16 = 4;
+const BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION: u16 = 5;
+const BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT: u16 = 6;
+const BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT: u16 = 7;
+const BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL: u16 = 8;
+// const BRIDGE_VLANDB_GOPTS_PAD: u16 = 9;
+const BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL: u16 = 10;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL: u16 = 11;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL: u16 = 12;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL: u16 = 13;
+const BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL: u16 = 14;
+const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER: u16 = 15;
+// const BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS: u16 = 16;
+// const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE: u16 = 17;
+const BRIDGE_VLANDB_GOPTS_MSTI: u16 = 18;
+
+/// Bridge global VLAN options nested in `BRIDGE_VLANDB_GLOBAL_OPTIONS`.
+/// `BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS` and
+/// `BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE` are stored as `Other`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeVlanGlobalOption {
+    Id(u16),
+    /// Last VLAN ID of the range starting at `Id`
+    Range(u16),
+    MulticastSnooping(bool),
+    MulticastIgmpVersion(u8),
+    MulticastMldVersion(u8),
+    MulticastLastMemberCount(u32),
+    MulticastStartupQueryCount(u32),
+    /// In centiseconds
+    MulticastLastMemberInterval(u64),
+    /// In centiseconds
+    MulticastMembershipInterval(u64),
+    /// In centiseconds
+    MulticastQuerierInterval(u64),
+    /// In centiseconds
+    MulticastQueryInterval(u64),
+    /// In centiseconds
+    MulticastQueryResponseInterval(u64),
+    /// In centiseconds
+    MulticastStartupQueryInterval(u64),
+    MulticastQuerier(bool),
+    /// Multiple spanning tree instance this VLAN is mapped to
+    Msti(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanGlobalOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MulticastLastMemberInterval(_)
+            | Self::MulticastMembershipInterval(_)
+            | Self::MulticastQuerierInterval(_)
+            | Self::MulticastQueryInterval(_)
+            | Self::MulticastQueryResponseInterval(_)
+            | Self::MulticastStartupQueryInterval(_) => 8,
+            Self::MulticastLastMemberCount(_)
+            | Self::MulticastStartupQueryCount(_) => 4,
+            Self::Id(_) | Self::Range(_) | Self::Msti(_) => 2,
+            Self::MulticastSnooping(_)
+            | Self::MulticastIgmpVersion(_)
+            | Self::MulticastMldVersion(_)
+            | Self::MulticastQuerier(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(value) | Self::Range(value) | Self::Msti(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            Self::MulticastSnooping(value) | Self::MulticastQuerier(value) => {
+                buffer[0] = *value as u8
+            }
+            Self::MulticastIgmpVersion(value)
+            | Self::MulticastMldVersion(value) => buffer[0] = *value,
+            Self::MulticastLastMemberCount(value)
+            | Self::MulticastStartupQueryCount(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::MulticastLastMemberInterval(value)
+            | Self::MulticastMembershipInterval(value)
+            | Self::MulticastQuerierInterval(value)
+            | Self::MulticastQueryInterval(value)
+            | Self::MulticastQueryResponseInterval(value)
+            | Self::MulticastStartupQueryInterval(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => BRIDGE_VLANDB_GOPTS_ID,
+            Self::Range(_) => BRIDGE_VLANDB_GOPTS_RANGE,
+            Self::MulticastSnooping(_) => BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING,
+            Self::MulticastIgmpVersion(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION
+            }
+            Self::MulticastMldVersion(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION
+            }
+            Self::MulticastLastMemberCount(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT
+            }
+            Self::MulticastStartupQueryCount(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT
+            }
+            Self::MulticastLastMemberInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL
+            }
+            Self::MulticastMembershipInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL
+            }
+            Self::MulticastQuerierInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL
+            }
+            Self::MulticastQueryInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL
+            }
+            Self::MulticastQueryResponseInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL
+            }
+            Self::MulticastStartupQueryInterval(_) => {
+                BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL
+            }
+            Self::MulticastQuerier(_) => BRIDGE_VLANDB_GOPTS_MCAST_QUERIER,
+            Self::Msti(_) => BRIDGE_VLANDB_GOPTS_MSTI,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanGlobalOption
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_GOPTS_ID => Self::Id(parse_u16(payload)?),
+            BRIDGE_VLANDB_GOPTS_RANGE => Self::Range(parse_u16(payload)?),
+            BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING => {
+                Self::MulticastSnooping(parse_u8(payload)? > 0)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION => {
+                Self::MulticastIgmpVersion(parse_u8(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION => {
+                Self::MulticastMldVersion(parse_u8(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT => {
+                Self::MulticastLastMemberCount(parse_u32(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT => {
+                Self::MulticastStartupQueryCount(parse_u32(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL => {
+                Self::MulticastLastMemberInterval(parse_u64(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL => {
+                Self::MulticastMembershipInterval(parse_u64(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL => {
+                Self::MulticastQuerierInterval(parse_u64(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL => {
+                Self::MulticastQueryInterval(parse_u64(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL => {
+                Self::MulticastQueryResponseInterval(parse_u64(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL => {
+                Self::MulticastStartupQueryInterval(parse_u64(payload)?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERIER => {
+                Self::MulticastQuerier(parse_u8(payload)? > 0)
+            }
+            BRIDGE_VLANDB_GOPTS_MSTI => Self::Msti(parse_u16(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
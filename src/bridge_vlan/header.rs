@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::AddressFamily;
+
+const BRIDGE_VLAN_HEADER_LEN: usize = 8;
+
+buffer!(BridgeVlanMessageBuffer(BRIDGE_VLAN_HEADER_LEN) {
+    family: (u8, 0),
+    reserved1: (u8, 1),
+    reserved2: (u16, 2..4),
+    ifindex: (u32, 4..8),
+    payload: (slice, BRIDGE_VLAN_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> BridgeVlanMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload()).map(|nla| {
+            nla.map_err(|e| {
+                DecodeError::from(format!(
+                    "Failed to parse bridge VLAN attributes {e}"
+                ))
+            })
+        })
+    }
+}
+
+// Linux kernel struct `struct br_vlan_msg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BridgeVlanHeader {
+    /// Always `AddressFamily::Bridge`
+    pub family: AddressFamily,
+    /// Interface index of bridge or bridge port, 0 for all in dump request
+    pub ifindex: u32,
+}
+
+impl Emitable for BridgeVlanHeader {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_VLAN_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = BridgeVlanMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_reserved1(0);
+        packet.set_reserved2(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<BridgeVlanMessageBuffer<T>>
+    for BridgeVlanHeader
+{
+    type Error = DecodeError;
+    fn parse(buf: &BridgeVlanMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{BridgeVlanAttribute, BridgeVlanHeader, BridgeVlanMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct BridgeVlanMessage {
+    pub header: BridgeVlanHeader,
+    pub attributes: Vec<BridgeVlanAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<BridgeVlanMessageBuffer<&'a T>>
+    for BridgeVlanMessage
+{
+    type Error = DecodeError;
+    fn parse(
+        buf: &BridgeVlanMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: BridgeVlanHeader::parse(buf)?,
+            attributes: Vec::<BridgeVlanAttribute>::parse(buf)?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<BridgeVlanMessageBuffer<&'a T>>
+    for Vec<BridgeVlanAttribute>
+{
+    type Error = DecodeError;
+    fn parse(
+        buf: &BridgeVlanMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(BridgeVlanAttribute::parse(&nla_buf?)?);
+        }
+        Ok(attributes)
+    }
+}
+
+impl Emitable for BridgeVlanMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod entry;
+mod global_option;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::{BridgeVlanAttribute, BridgeVlanDumpFlags};
+pub use self::entry::{
+    BridgeVlanEntry, BridgeVlanStats, BridgeVlanTunnelEntry,
+};
+pub use self::global_option::BridgeVlanGlobalOption;
+pub use self::header::{BridgeVlanHeader, BridgeVlanMessageBuffer};
+pub use self::message::BridgeVlanMessage;
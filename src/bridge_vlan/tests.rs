@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable, ParseableParametrized};
+
+use crate::{
+    bridge_vlan::{
+        BridgeVlanAttribute, BridgeVlanEntry, BridgeVlanGlobalOption,
+        BridgeVlanHeader, BridgeVlanMessage, BridgeVlanMessageBuffer,
+        BridgeVlanStats,
+    },
+    link::{
+        BridgePortMulticastRouter, BridgePortState, BridgeVlanInfo,
+        BridgeVlanInfoFlags,
+    },
+    AddressFamily, RouteNetlinkMessage, RouteNetlinkMessageBuffer,
+};
+
+// Kernel RTM_NEWVLAN reply to RTM_GETVLAN dump request with
+// BRIDGE_VLANDB_DUMPF_STATS for eth1 attached to br0.
+#[test]
+fn test_bridge_vlan_entry_with_stats() {
+    let raw: Vec<u8> = vec![
+        0x07, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x68, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x06, 0x00, 0x01, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x34, 0x00, 0x05, 0x80, 0x0c, 0x00, 0x01, 0x00, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x02, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x00, 0x00, 0x08, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x04, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 3,
+        },
+        attributes: vec![BridgeVlanAttribute::Entry(vec![
+            BridgeVlanEntry::Info(BridgeVlanInfo {
+                flags: BridgeVlanInfoFlags::Pvid
+                    | BridgeVlanInfoFlags::Untagged,
+                vid: 1,
+            }),
+            BridgeVlanEntry::State(BridgePortState::Forwarding),
+            BridgeVlanEntry::MulticastRouter(
+                BridgePortMulticastRouter::TempQuery,
+            ),
+            BridgeVlanEntry::MulticastGroups(0),
+            BridgeVlanEntry::MulticastMaxGroups(0),
+            BridgeVlanEntry::NeighSuppress(false),
+            BridgeVlanEntry::Stats(vec![
+                BridgeVlanStats::RxBytes(1024),
+                BridgeVlanStats::RxPackets(16),
+                BridgeVlanStats::TxBytes(2048),
+                BridgeVlanStats::TxPackets(32),
+            ]),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        BridgeVlanMessage::parse(&BridgeVlanMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// RTM_NEWVLAN request generated by:
+//      bridge vlan set dev eth1 vid 10 state blocking
+#[test]
+fn test_bridge_vlan_set_state() {
+    let raw: Vec<u8> = vec![
+        0x07, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x04, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteNetlinkMessage::NewBridgeVlan(BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 3,
+        },
+        attributes: vec![BridgeVlanAttribute::Entry(vec![
+            BridgeVlanEntry::Info(BridgeVlanInfo {
+                flags: BridgeVlanInfoFlags::empty(),
+                vid: 10,
+            }),
+            BridgeVlanEntry::State(BridgePortState::Blocking),
+        ])],
+    });
+
+    assert_eq!(
+        expected,
+        RouteNetlinkMessage::parse_with_param(
+            &RouteNetlinkMessageBuffer::new(&raw),
+            expected.message_type()
+        )
+        .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Kernel RTM_NEWVLAN reply of `bridge vlan global show dev br0`, only
+// the options of VLAN 1 up to BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL and
+// BRIDGE_VLANDB_GOPTS_MSTI are kept.
+#[test]
+fn test_bridge_vlan_global_options() {
+    let raw: Vec<u8> = vec![
+        0x07, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x38, 0x00, 0x03, 0x80,
+        0x06, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x08, 0x00,
+        0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 2,
+        },
+        attributes: vec![BridgeVlanAttribute::GlobalOptions(vec![
+            BridgeVlanGlobalOption::Id(1),
+            BridgeVlanGlobalOption::MulticastSnooping(true),
+            BridgeVlanGlobalOption::MulticastIgmpVersion(2),
+            BridgeVlanGlobalOption::MulticastMldVersion(1),
+            BridgeVlanGlobalOption::MulticastLastMemberInterval(100),
+            BridgeVlanGlobalOption::Msti(0),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        BridgeVlanMessage::parse(&BridgeVlanMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -7,12 +7,21 @@
 //! This crate grouped Netlink route protocol into these modules:
 //!  * `link`: NIC interface, similar to to `ip link` command.
 //!  * `address`: IP address, similar to `ip address` command.
+//!  * `addrlabel`: IPv6 address label, similar to `ip addrlabel` command.
+//!  * `bridge_vlan`: Bridge VLAN database, similar to `bridge vlan` command.
 //!  * `route`: Route, similar to `ip route` command.
 //!  * `rule`: Route rule, similar to `ip rule` command.
 //!  * `tc`: Traffic control, similar to `tc` command.
+//!  * `mdb`: Bridge multicast database, similar to `bridge mdb` command.
 //!  * `neighbour`: Neighbour, similar to `ip neighbour` command.
 //!  * `neighbour_table`: Neighbour table, similar to `ip ntable` command.
+//!  * `nduseropt`: IPv6 Neighbor Discovery options forwarded by kernel,
+//!    like RDNSS and DNSSL used by `rdisc6` command.
+//!  * `netconf`: Per-interface IP configuration, similar to `ip netconf`
+//!    command.
+//!  * `nexthop`: Nexthop object, similar to `ip nexthop` command.
 //!  * `nsid`: Namespace, similar to `ip netns` command.
+//!  * `stats`: Interface statistics, similar to `ip stats` command.
 //!
 //! At the top level of this crate, we also provide:
 //!  * [AddressFamily]
@@ -23,13 +32,20 @@
 //! [rtnetlink_url]: https://docs.rs/rtnetlink
 
 pub mod address;
+pub mod addrlabel;
+pub mod bridge_vlan;
 pub mod link;
+pub mod mdb;
+pub mod nduseropt;
 pub mod neighbour;
 pub mod neighbour_table;
+pub mod netconf;
+pub mod nexthop;
 pub mod nsid;
 pub mod prefix;
 pub mod route;
 pub mod rule;
+pub mod stats;
 pub mod tc;
 
 mod message;
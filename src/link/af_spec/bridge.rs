@@ -2,19 +2,21 @@
 
 use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
-    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
     parsers::{parse_u16, parse_u32},
     traits::Parseable,
     DecodeError, Emitable,
 };
 
+use super::{BridgeCfm, BridgeMrp, BridgeMst};
+
 const IFLA_BRIDGE_FLAGS: u16 = 0;
 const IFLA_BRIDGE_MODE: u16 = 1;
 const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
 const IFLA_BRIDGE_VLAN_TUNNEL_INFO: u16 = 3;
-// const IFLA_BRIDGE_MRP: u16 = 4;
-// const IFLA_BRIDGE_CFM: u16 = 5;
-// const IFLA_BRIDGE_MST: u16 = 6;
+const IFLA_BRIDGE_MRP: u16 = 4;
+const IFLA_BRIDGE_CFM: u16 = 5;
+const IFLA_BRIDGE_MST: u16 = 6;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 #[non_exhaustive]
@@ -23,6 +25,9 @@ pub enum AfSpecBridge {
     Mode(BridgeMode),
     VlanInfo(BridgeVlanInfo),
     VlanTunnelInfo(Vec<BridgeVlanTunnelInfo>),
+    Mrp(Vec<BridgeMrp>),
+    Cfm(Vec<BridgeCfm>),
+    Mst(Vec<BridgeMst>),
     Other(DefaultNla),
 }
 
@@ -33,6 +38,9 @@ impl Nla for AfSpecBridge {
             Self::Mode(_) => BridgeMode::LENGTH,
             Self::VlanInfo(_) => BridgeVlanInfo::LENGTH,
             Self::VlanTunnelInfo(s) => s.as_slice().buffer_len(),
+            Self::Mrp(s) => s.as_slice().buffer_len(),
+            Self::Cfm(s) => s.as_slice().buffer_len(),
+            Self::Mst(s) => s.as_slice().buffer_len(),
             Self::Other(nla) => nla.value_len(),
         }
     }
@@ -49,6 +57,9 @@ impl Nla for AfSpecBridge {
                 buffer[..4].copy_from_slice(<[u8; 4]>::from(info).as_slice())
             }
             Self::VlanTunnelInfo(s) => s.as_slice().emit(buffer),
+            Self::Mrp(s) => s.as_slice().emit(buffer),
+            Self::Cfm(s) => s.as_slice().emit(buffer),
+            Self::Mst(s) => s.as_slice().emit(buffer),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
@@ -59,6 +70,9 @@ impl Nla for AfSpecBridge {
             Self::Mode(_) => IFLA_BRIDGE_MODE,
             Self::VlanInfo(_) => IFLA_BRIDGE_VLAN_INFO,
             Self::VlanTunnelInfo(_) => IFLA_BRIDGE_VLAN_TUNNEL_INFO,
+            Self::Mrp(_) => IFLA_BRIDGE_MRP | NLA_F_NESTED,
+            Self::Cfm(_) => IFLA_BRIDGE_CFM | NLA_F_NESTED,
+            Self::Mst(_) => IFLA_BRIDGE_MST | NLA_F_NESTED,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -83,6 +97,30 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecBridge {
                 }
                 Self::VlanTunnelInfo(nlas)
             }
+            IFLA_BRIDGE_MRP => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrp::parse(nla)?);
+                }
+                Self::Mrp(nlas)
+            }
+            IFLA_BRIDGE_CFM => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfm::parse(nla)?);
+                }
+                Self::Cfm(nlas)
+            }
+            IFLA_BRIDGE_MST => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMst::parse(nla)?);
+                }
+                Self::Mst(nlas)
+            }
             _kind => Self::Other(DefaultNla::parse(buf)?),
         })
     }
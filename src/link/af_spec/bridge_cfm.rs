@@ -0,0 +1,1069 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_mac, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const IFLA_BRIDGE_CFM_MEP_CREATE: u16 = 1;
+const IFLA_BRIDGE_CFM_MEP_DELETE: u16 = 2;
+const IFLA_BRIDGE_CFM_MEP_CONFIG: u16 = 3;
+const IFLA_BRIDGE_CFM_CC_CONFIG: u16 = 4;
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_ADD: u16 = 5;
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_REMOVE: u16 = 6;
+const IFLA_BRIDGE_CFM_CC_RDI: u16 = 7;
+const IFLA_BRIDGE_CFM_CC_CCM_TX: u16 = 8;
+const IFLA_BRIDGE_CFM_MEP_CREATE_INFO: u16 = 9;
+const IFLA_BRIDGE_CFM_MEP_CONFIG_INFO: u16 = 10;
+const IFLA_BRIDGE_CFM_CC_CONFIG_INFO: u16 = 11;
+const IFLA_BRIDGE_CFM_CC_RDI_INFO: u16 = 12;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_INFO: u16 = 13;
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_INFO: u16 = 14;
+const IFLA_BRIDGE_CFM_MEP_STATUS_INFO: u16 = 15;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_INFO: u16 = 16;
+
+const IFLA_BRIDGE_CFM_MEP_CREATE_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_MEP_CREATE_DOMAIN: u16 = 2;
+const IFLA_BRIDGE_CFM_MEP_CREATE_DIRECTION: u16 = 3;
+const IFLA_BRIDGE_CFM_MEP_CREATE_IFINDEX: u16 = 4;
+
+const IFLA_BRIDGE_CFM_MEP_DELETE_INSTANCE: u16 = 1;
+
+const IFLA_BRIDGE_CFM_MEP_CONFIG_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_MEP_CONFIG_UNICAST_MAC: u16 = 2;
+const IFLA_BRIDGE_CFM_MEP_CONFIG_MDLEVEL: u16 = 3;
+const IFLA_BRIDGE_CFM_MEP_CONFIG_MEPID: u16 = 4;
+
+const IFLA_BRIDGE_CFM_CC_CONFIG_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_CONFIG_ENABLE: u16 = 2;
+const IFLA_BRIDGE_CFM_CC_CONFIG_EXP_INTERVAL: u16 = 3;
+const IFLA_BRIDGE_CFM_CC_CONFIG_EXP_MAID: u16 = 4;
+
+const IFLA_BRIDGE_CFM_CC_PEER_MEP_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_PEER_MEPID: u16 = 2;
+
+const IFLA_BRIDGE_CFM_CC_RDI_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_RDI_RDI: u16 = 2;
+
+const IFLA_BRIDGE_CFM_CC_CCM_TX_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_DMAC: u16 = 2;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_SEQ_NO_UPDATE: u16 = 3;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_PERIOD: u16 = 4;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV: u16 = 5;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV_VALUE: u16 = 6;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV: u16 = 7;
+const IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV_VALUE: u16 = 8;
+
+const IFLA_BRIDGE_CFM_MEP_STATUS_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_MEP_STATUS_OPCODE_UNEXP_SEEN: u16 = 2;
+const IFLA_BRIDGE_CFM_MEP_STATUS_VERSION_UNEXP_SEEN: u16 = 3;
+const IFLA_BRIDGE_CFM_MEP_STATUS_RX_LEVEL_LOW_SEEN: u16 = 4;
+
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_PEER_MEPID: u16 = 2;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_CCM_DEFECT: u16 = 3;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_RDI: u16 = 4;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_PORT_TLV_VALUE: u16 = 5;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_IF_TLV_VALUE: u16 = 6;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEEN: u16 = 7;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_TLV_SEEN: u16 = 8;
+const IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEQ_UNEXP_SEEN: u16 = 9;
+
+/// Connectivity Fault Management (IEEE 802.1Q) attributes nested in
+/// `IFLA_BRIDGE_CFM`. The `*Info` variants are only found in kernel reply.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfm {
+    MepCreate(Vec<BridgeCfmMepCreate>),
+    MepDelete(Vec<BridgeCfmMepDelete>),
+    MepConfig(Vec<BridgeCfmMepConfig>),
+    CcConfig(Vec<BridgeCfmCcConfig>),
+    CcPeerMepAdd(Vec<BridgeCfmCcPeerMep>),
+    CcPeerMepRemove(Vec<BridgeCfmCcPeerMep>),
+    CcRdi(Vec<BridgeCfmCcRdi>),
+    CcCcmTx(Vec<BridgeCfmCcCcmTx>),
+    MepCreateInfo(Vec<BridgeCfmMepCreate>),
+    MepConfigInfo(Vec<BridgeCfmMepConfig>),
+    CcConfigInfo(Vec<BridgeCfmCcConfig>),
+    CcRdiInfo(Vec<BridgeCfmCcRdi>),
+    CcCcmTxInfo(Vec<BridgeCfmCcCcmTx>),
+    CcPeerMepInfo(Vec<BridgeCfmCcPeerMep>),
+    MepStatusInfo(Vec<BridgeCfmMepStatus>),
+    CcPeerStatusInfo(Vec<BridgeCfmCcPeerStatus>),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfm {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MepCreate(nlas) => nlas.as_slice().buffer_len(),
+            Self::MepDelete(nlas) => nlas.as_slice().buffer_len(),
+            Self::MepConfig(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcConfig(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcPeerMepAdd(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcPeerMepRemove(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcRdi(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcCcmTx(nlas) => nlas.as_slice().buffer_len(),
+            Self::MepCreateInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::MepConfigInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcConfigInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcRdiInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcCcmTxInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcPeerMepInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::MepStatusInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::CcPeerStatusInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::MepCreate(nlas) => nlas.as_slice().emit(buffer),
+            Self::MepDelete(nlas) => nlas.as_slice().emit(buffer),
+            Self::MepConfig(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcConfig(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcPeerMepAdd(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcPeerMepRemove(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcRdi(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcCcmTx(nlas) => nlas.as_slice().emit(buffer),
+            Self::MepCreateInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::MepConfigInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcConfigInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcRdiInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcCcmTxInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcPeerMepInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::MepStatusInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::CcPeerStatusInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MepCreate(_) => IFLA_BRIDGE_CFM_MEP_CREATE | NLA_F_NESTED,
+            Self::MepDelete(_) => IFLA_BRIDGE_CFM_MEP_DELETE | NLA_F_NESTED,
+            Self::MepConfig(_) => IFLA_BRIDGE_CFM_MEP_CONFIG | NLA_F_NESTED,
+            Self::CcConfig(_) => IFLA_BRIDGE_CFM_CC_CONFIG | NLA_F_NESTED,
+            Self::CcPeerMepAdd(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_MEP_ADD | NLA_F_NESTED
+            }
+            Self::CcPeerMepRemove(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_MEP_REMOVE | NLA_F_NESTED
+            }
+            Self::CcRdi(_) => IFLA_BRIDGE_CFM_CC_RDI | NLA_F_NESTED,
+            Self::CcCcmTx(_) => IFLA_BRIDGE_CFM_CC_CCM_TX | NLA_F_NESTED,
+            Self::MepCreateInfo(_) => {
+                IFLA_BRIDGE_CFM_MEP_CREATE_INFO | NLA_F_NESTED
+            }
+            Self::MepConfigInfo(_) => {
+                IFLA_BRIDGE_CFM_MEP_CONFIG_INFO | NLA_F_NESTED
+            }
+            Self::CcConfigInfo(_) => {
+                IFLA_BRIDGE_CFM_CC_CONFIG_INFO | NLA_F_NESTED
+            }
+            Self::CcRdiInfo(_) => IFLA_BRIDGE_CFM_CC_RDI_INFO | NLA_F_NESTED,
+            Self::CcCcmTxInfo(_) => {
+                IFLA_BRIDGE_CFM_CC_CCM_TX_INFO | NLA_F_NESTED
+            }
+            Self::CcPeerMepInfo(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_MEP_INFO | NLA_F_NESTED
+            }
+            Self::MepStatusInfo(_) => {
+                IFLA_BRIDGE_CFM_MEP_STATUS_INFO | NLA_F_NESTED
+            }
+            Self::CcPeerStatusInfo(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_STATUS_INFO | NLA_F_NESTED
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BridgeCfm {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_CREATE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmMepCreate::parse(nla)?);
+                }
+                Self::MepCreate(nlas)
+            }
+            IFLA_BRIDGE_CFM_MEP_DELETE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmMepDelete::parse(nla)?);
+                }
+                Self::MepDelete(nlas)
+            }
+            IFLA_BRIDGE_CFM_MEP_CONFIG => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmMepConfig::parse(nla)?);
+                }
+                Self::MepConfig(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_CONFIG => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcConfig::parse(nla)?);
+                }
+                Self::CcConfig(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_ADD => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcPeerMep::parse(nla)?);
+                }
+                Self::CcPeerMepAdd(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_REMOVE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcPeerMep::parse(nla)?);
+                }
+                Self::CcPeerMepRemove(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_RDI => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcRdi::parse(nla)?);
+                }
+                Self::CcRdi(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcCcmTx::parse(nla)?);
+                }
+                Self::CcCcmTx(nlas)
+            }
+            IFLA_BRIDGE_CFM_MEP_CREATE_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmMepCreate::parse(nla)?);
+                }
+                Self::MepCreateInfo(nlas)
+            }
+            IFLA_BRIDGE_CFM_MEP_CONFIG_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmMepConfig::parse(nla)?);
+                }
+                Self::MepConfigInfo(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_CONFIG_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcConfig::parse(nla)?);
+                }
+                Self::CcConfigInfo(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_RDI_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcRdi::parse(nla)?);
+                }
+                Self::CcRdiInfo(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcCcmTx::parse(nla)?);
+                }
+                Self::CcCcmTxInfo(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcPeerMep::parse(nla)?);
+                }
+                Self::CcPeerMepInfo(nlas)
+            }
+            IFLA_BRIDGE_CFM_MEP_STATUS_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmMepStatus::parse(nla)?);
+                }
+                Self::MepStatusInfo(nlas)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeCfmCcPeerStatus::parse(nla)?);
+                }
+                Self::CcPeerStatusInfo(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmMepCreate {
+    Instance(u32),
+    Domain(CfmDomain),
+    Direction(CfmMepDirection),
+    IfIndex(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmMepCreate {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_)
+            | Self::Domain(_)
+            | Self::Direction(_)
+            | Self::IfIndex(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value) | Self::IfIndex(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Domain(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Direction(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_MEP_CREATE_INSTANCE,
+            Self::Domain(_) => IFLA_BRIDGE_CFM_MEP_CREATE_DOMAIN,
+            Self::Direction(_) => IFLA_BRIDGE_CFM_MEP_CREATE_DIRECTION,
+            Self::IfIndex(_) => IFLA_BRIDGE_CFM_MEP_CREATE_IFINDEX,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmMepCreate
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_CREATE_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CREATE_DOMAIN => {
+                Self::Domain(parse_u32(payload)?.into())
+            }
+            IFLA_BRIDGE_CFM_MEP_CREATE_DIRECTION => {
+                Self::Direction(parse_u32(payload)?.into())
+            }
+            IFLA_BRIDGE_CFM_MEP_CREATE_IFINDEX => {
+                Self::IfIndex(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmMepDelete {
+    Instance(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmMepDelete {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_MEP_DELETE_INSTANCE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmMepDelete
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_DELETE_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmMepConfig {
+    Instance(u32),
+    UnicastMac([u8; 6]),
+    /// Maintenance domain level
+    MdLevel(u32),
+    MepId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmMepConfig {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::UnicastMac(_) => 6,
+            Self::Instance(_) | Self::MdLevel(_) | Self::MepId(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value)
+            | Self::MdLevel(value)
+            | Self::MepId(value) => NativeEndian::write_u32(buffer, *value),
+            Self::UnicastMac(value) => buffer.copy_from_slice(&value[..]),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_MEP_CONFIG_INSTANCE,
+            Self::UnicastMac(_) => IFLA_BRIDGE_CFM_MEP_CONFIG_UNICAST_MAC,
+            Self::MdLevel(_) => IFLA_BRIDGE_CFM_MEP_CONFIG_MDLEVEL,
+            Self::MepId(_) => IFLA_BRIDGE_CFM_MEP_CONFIG_MEPID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmMepConfig
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_CONFIG_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CONFIG_UNICAST_MAC => {
+                Self::UnicastMac(parse_mac(payload)?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CONFIG_MDLEVEL => {
+                Self::MdLevel(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_MEP_CONFIG_MEPID => {
+                Self::MepId(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmCcConfig {
+    Instance(u32),
+    Enable(u32),
+    ExpectedInterval(CfmCcmInterval),
+    /// Expected maintenance association ID, 48 bytes
+    ExpectedMaid(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcConfig {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ExpectedMaid(v) => v.len(),
+            Self::Instance(_) | Self::Enable(_) | Self::ExpectedInterval(_) => {
+                4
+            }
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value) | Self::Enable(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::ExpectedInterval(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::ExpectedMaid(value) => {
+                buffer.copy_from_slice(value.as_slice())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_CONFIG_INSTANCE,
+            Self::Enable(_) => IFLA_BRIDGE_CFM_CC_CONFIG_ENABLE,
+            Self::ExpectedInterval(_) => IFLA_BRIDGE_CFM_CC_CONFIG_EXP_INTERVAL,
+            Self::ExpectedMaid(_) => IFLA_BRIDGE_CFM_CC_CONFIG_EXP_MAID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcConfig
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_CONFIG_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CONFIG_ENABLE => {
+                Self::Enable(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CONFIG_EXP_INTERVAL => {
+                Self::ExpectedInterval(parse_u32(payload)?.into())
+            }
+            IFLA_BRIDGE_CFM_CC_CONFIG_EXP_MAID => {
+                Self::ExpectedMaid(payload.to_vec())
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmCcPeerMep {
+    Instance(u32),
+    MepId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcPeerMep {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_) | Self::MepId(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value) | Self::MepId(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_PEER_MEP_INSTANCE,
+            Self::MepId(_) => IFLA_BRIDGE_CFM_CC_PEER_MEPID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcPeerMep
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_PEER_MEP_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_MEPID => Self::MepId(parse_u32(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmCcRdi {
+    Instance(u32),
+    /// Remote defect indication
+    Rdi(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcRdi {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_) | Self::Rdi(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value) | Self::Rdi(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_RDI_INSTANCE,
+            Self::Rdi(_) => IFLA_BRIDGE_CFM_CC_RDI_RDI,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcRdi
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_RDI_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_RDI_RDI => Self::Rdi(parse_u32(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmCcCcmTx {
+    Instance(u32),
+    DestinationMac([u8; 6]),
+    SeqNoUpdate(u32),
+    /// Transmission period in seconds
+    Period(u32),
+    IfTlv(u32),
+    IfTlvValue(u8),
+    PortTlv(u32),
+    PortTlvValue(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcCcmTx {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::DestinationMac(_) => 6,
+            Self::Instance(_)
+            | Self::SeqNoUpdate(_)
+            | Self::Period(_)
+            | Self::IfTlv(_)
+            | Self::PortTlv(_) => 4,
+            Self::IfTlvValue(_) | Self::PortTlvValue(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value)
+            | Self::SeqNoUpdate(value)
+            | Self::Period(value)
+            | Self::IfTlv(value)
+            | Self::PortTlv(value) => NativeEndian::write_u32(buffer, *value),
+            Self::DestinationMac(value) => buffer.copy_from_slice(&value[..]),
+            Self::IfTlvValue(value) | Self::PortTlvValue(value) => {
+                buffer[0] = *value
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_INSTANCE,
+            Self::DestinationMac(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_DMAC,
+            Self::SeqNoUpdate(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_SEQ_NO_UPDATE,
+            Self::Period(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_PERIOD,
+            Self::IfTlv(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV,
+            Self::IfTlvValue(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV_VALUE,
+            Self::PortTlv(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV,
+            Self::PortTlvValue(_) => IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV_VALUE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcCcmTx
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_CCM_TX_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_DMAC => {
+                Self::DestinationMac(parse_mac(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_SEQ_NO_UPDATE => {
+                Self::SeqNoUpdate(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_PERIOD => {
+                Self::Period(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV => {
+                Self::IfTlv(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_IF_TLV_VALUE => {
+                Self::IfTlvValue(parse_u8(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV => {
+                Self::PortTlv(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_CCM_TX_PORT_TLV_VALUE => {
+                Self::PortTlvValue(parse_u8(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmMepStatus {
+    Instance(u32),
+    OpcodeUnexpectedSeen(u32),
+    VersionUnexpectedSeen(u32),
+    RxLevelLowSeen(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmMepStatus {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_)
+            | Self::OpcodeUnexpectedSeen(_)
+            | Self::VersionUnexpectedSeen(_)
+            | Self::RxLevelLowSeen(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value)
+            | Self::OpcodeUnexpectedSeen(value)
+            | Self::VersionUnexpectedSeen(value)
+            | Self::RxLevelLowSeen(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_MEP_STATUS_INSTANCE,
+            Self::OpcodeUnexpectedSeen(_) => {
+                IFLA_BRIDGE_CFM_MEP_STATUS_OPCODE_UNEXP_SEEN
+            }
+            Self::VersionUnexpectedSeen(_) => {
+                IFLA_BRIDGE_CFM_MEP_STATUS_VERSION_UNEXP_SEEN
+            }
+            Self::RxLevelLowSeen(_) => {
+                IFLA_BRIDGE_CFM_MEP_STATUS_RX_LEVEL_LOW_SEEN
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmMepStatus
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_MEP_STATUS_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_MEP_STATUS_OPCODE_UNEXP_SEEN => {
+                Self::OpcodeUnexpectedSeen(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_MEP_STATUS_VERSION_UNEXP_SEEN => {
+                Self::VersionUnexpectedSeen(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_MEP_STATUS_RX_LEVEL_LOW_SEEN => {
+                Self::RxLevelLowSeen(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeCfmCcPeerStatus {
+    Instance(u32),
+    PeerMepId(u32),
+    CcmDefect(u32),
+    Rdi(u32),
+    PortTlvValue(u8),
+    IfTlvValue(u8),
+    Seen(u32),
+    TlvSeen(u32),
+    SeqUnexpectedSeen(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeCfmCcPeerStatus {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(_)
+            | Self::PeerMepId(_)
+            | Self::CcmDefect(_)
+            | Self::Rdi(_)
+            | Self::Seen(_)
+            | Self::TlvSeen(_)
+            | Self::SeqUnexpectedSeen(_) => 4,
+            Self::PortTlvValue(_) | Self::IfTlvValue(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(value)
+            | Self::PeerMepId(value)
+            | Self::CcmDefect(value)
+            | Self::Rdi(value)
+            | Self::Seen(value)
+            | Self::TlvSeen(value)
+            | Self::SeqUnexpectedSeen(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::PortTlvValue(value) | Self::IfTlvValue(value) => {
+                buffer[0] = *value
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_INSTANCE,
+            Self::PeerMepId(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_PEER_MEPID,
+            Self::CcmDefect(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_CCM_DEFECT,
+            Self::Rdi(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_RDI,
+            Self::PortTlvValue(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_STATUS_PORT_TLV_VALUE
+            }
+            Self::IfTlvValue(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_IF_TLV_VALUE,
+            Self::Seen(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEEN,
+            Self::TlvSeen(_) => IFLA_BRIDGE_CFM_CC_PEER_STATUS_TLV_SEEN,
+            Self::SeqUnexpectedSeen(_) => {
+                IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEQ_UNEXP_SEEN
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeCfmCcPeerStatus
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_INSTANCE => {
+                Self::Instance(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_PEER_MEPID => {
+                Self::PeerMepId(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_CCM_DEFECT => {
+                Self::CcmDefect(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_RDI => {
+                Self::Rdi(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_PORT_TLV_VALUE => {
+                Self::PortTlvValue(parse_u8(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_IF_TLV_VALUE => {
+                Self::IfTlvValue(parse_u8(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEEN => {
+                Self::Seen(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_TLV_SEEN => {
+                Self::TlvSeen(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_CFM_CC_PEER_STATUS_SEQ_UNEXP_SEEN => {
+                Self::SeqUnexpectedSeen(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const BR_CFM_PORT: u32 = 0;
+const BR_CFM_VLAN: u32 = 1;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum CfmDomain {
+    Port,
+    Vlan,
+    Other(u32),
+}
+
+impl From<u32> for CfmDomain {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_CFM_PORT => Self::Port,
+            BR_CFM_VLAN => Self::Vlan,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<CfmDomain> for u32 {
+    fn from(v: CfmDomain) -> u32 {
+        match v {
+            CfmDomain::Port => BR_CFM_PORT,
+            CfmDomain::Vlan => BR_CFM_VLAN,
+            CfmDomain::Other(d) => d,
+        }
+    }
+}
+
+const BR_CFM_MEP_DIRECTION_DOWN: u32 = 0;
+const BR_CFM_MEP_DIRECTION_UP: u32 = 1;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum CfmMepDirection {
+    Down,
+    Up,
+    Other(u32),
+}
+
+impl From<u32> for CfmMepDirection {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_CFM_MEP_DIRECTION_DOWN => Self::Down,
+            BR_CFM_MEP_DIRECTION_UP => Self::Up,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<CfmMepDirection> for u32 {
+    fn from(v: CfmMepDirection) -> u32 {
+        match v {
+            CfmMepDirection::Down => BR_CFM_MEP_DIRECTION_DOWN,
+            CfmMepDirection::Up => BR_CFM_MEP_DIRECTION_UP,
+            CfmMepDirection::Other(d) => d,
+        }
+    }
+}
+
+const BR_CFM_CCM_INTERVAL_NONE: u32 = 0;
+const BR_CFM_CCM_INTERVAL_3_3_MS: u32 = 1;
+const BR_CFM_CCM_INTERVAL_10_MS: u32 = 2;
+const BR_CFM_CCM_INTERVAL_100_MS: u32 = 3;
+const BR_CFM_CCM_INTERVAL_1_SEC: u32 = 4;
+const BR_CFM_CCM_INTERVAL_10_SEC: u32 = 5;
+const BR_CFM_CCM_INTERVAL_1_MIN: u32 = 6;
+const BR_CFM_CCM_INTERVAL_10_MIN: u32 = 7;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum CfmCcmInterval {
+    None,
+    /// 3.3 milliseconds
+    Interval3ms,
+    Interval10ms,
+    Interval100ms,
+    Interval1s,
+    Interval10s,
+    Interval1min,
+    Interval10min,
+    Other(u32),
+}
+
+impl From<u32> for CfmCcmInterval {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_CFM_CCM_INTERVAL_NONE => Self::None,
+            BR_CFM_CCM_INTERVAL_3_3_MS => Self::Interval3ms,
+            BR_CFM_CCM_INTERVAL_10_MS => Self::Interval10ms,
+            BR_CFM_CCM_INTERVAL_100_MS => Self::Interval100ms,
+            BR_CFM_CCM_INTERVAL_1_SEC => Self::Interval1s,
+            BR_CFM_CCM_INTERVAL_10_SEC => Self::Interval10s,
+            BR_CFM_CCM_INTERVAL_1_MIN => Self::Interval1min,
+            BR_CFM_CCM_INTERVAL_10_MIN => Self::Interval10min,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<CfmCcmInterval> for u32 {
+    fn from(v: CfmCcmInterval) -> u32 {
+        match v {
+            CfmCcmInterval::None => BR_CFM_CCM_INTERVAL_NONE,
+            CfmCcmInterval::Interval3ms => BR_CFM_CCM_INTERVAL_3_3_MS,
+            CfmCcmInterval::Interval10ms => BR_CFM_CCM_INTERVAL_10_MS,
+            CfmCcmInterval::Interval100ms => BR_CFM_CCM_INTERVAL_100_MS,
+            CfmCcmInterval::Interval1s => BR_CFM_CCM_INTERVAL_1_SEC,
+            CfmCcmInterval::Interval10s => BR_CFM_CCM_INTERVAL_10_SEC,
+            CfmCcmInterval::Interval1min => BR_CFM_CCM_INTERVAL_1_MIN,
+            CfmCcmInterval::Interval10min => BR_CFM_CCM_INTERVAL_10_MIN,
+            CfmCcmInterval::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,1117 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const IFLA_BRIDGE_MRP_INSTANCE: u16 = 1;
+const IFLA_BRIDGE_MRP_PORT_STATE: u16 = 2;
+const IFLA_BRIDGE_MRP_PORT_ROLE: u16 = 3;
+const IFLA_BRIDGE_MRP_RING_STATE: u16 = 4;
+const IFLA_BRIDGE_MRP_RING_ROLE: u16 = 5;
+const IFLA_BRIDGE_MRP_START_TEST: u16 = 6;
+const IFLA_BRIDGE_MRP_INFO: u16 = 7;
+const IFLA_BRIDGE_MRP_IN_ROLE: u16 = 8;
+const IFLA_BRIDGE_MRP_IN_STATE: u16 = 9;
+const IFLA_BRIDGE_MRP_START_IN_TEST: u16 = 10;
+
+const IFLA_BRIDGE_MRP_INSTANCE_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_INSTANCE_P_IFINDEX: u16 = 2;
+const IFLA_BRIDGE_MRP_INSTANCE_S_IFINDEX: u16 = 3;
+const IFLA_BRIDGE_MRP_INSTANCE_PRIO: u16 = 4;
+
+const IFLA_BRIDGE_MRP_PORT_STATE_STATE: u16 = 1;
+
+const IFLA_BRIDGE_MRP_PORT_ROLE_ROLE: u16 = 1;
+
+const IFLA_BRIDGE_MRP_RING_STATE_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_RING_STATE_STATE: u16 = 2;
+
+const IFLA_BRIDGE_MRP_RING_ROLE_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_RING_ROLE_ROLE: u16 = 2;
+
+const IFLA_BRIDGE_MRP_START_TEST_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_START_TEST_INTERVAL: u16 = 2;
+const IFLA_BRIDGE_MRP_START_TEST_MAX_MISS: u16 = 3;
+const IFLA_BRIDGE_MRP_START_TEST_PERIOD: u16 = 4;
+const IFLA_BRIDGE_MRP_START_TEST_MONITOR: u16 = 5;
+
+const IFLA_BRIDGE_MRP_INFO_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_INFO_P_IFINDEX: u16 = 2;
+const IFLA_BRIDGE_MRP_INFO_S_IFINDEX: u16 = 3;
+const IFLA_BRIDGE_MRP_INFO_PRIO: u16 = 4;
+const IFLA_BRIDGE_MRP_INFO_RING_STATE: u16 = 5;
+const IFLA_BRIDGE_MRP_INFO_RING_ROLE: u16 = 6;
+const IFLA_BRIDGE_MRP_INFO_TEST_INTERVAL: u16 = 7;
+const IFLA_BRIDGE_MRP_INFO_TEST_MAX_MISS: u16 = 8;
+const IFLA_BRIDGE_MRP_INFO_TEST_MONITOR: u16 = 9;
+const IFLA_BRIDGE_MRP_INFO_I_IFINDEX: u16 = 10;
+const IFLA_BRIDGE_MRP_INFO_IN_STATE: u16 = 11;
+const IFLA_BRIDGE_MRP_INFO_IN_ROLE: u16 = 12;
+const IFLA_BRIDGE_MRP_INFO_IN_TEST_INTERVAL: u16 = 13;
+const IFLA_BRIDGE_MRP_INFO_IN_TEST_MAX_MISS: u16 = 14;
+
+const IFLA_BRIDGE_MRP_IN_ROLE_RING_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_IN_ROLE_IN_ID: u16 = 2;
+const IFLA_BRIDGE_MRP_IN_ROLE_ROLE: u16 = 3;
+const IFLA_BRIDGE_MRP_IN_ROLE_I_IFINDEX: u16 = 4;
+
+const IFLA_BRIDGE_MRP_IN_STATE_IN_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_IN_STATE_STATE: u16 = 2;
+
+const IFLA_BRIDGE_MRP_START_IN_TEST_IN_ID: u16 = 1;
+const IFLA_BRIDGE_MRP_START_IN_TEST_INTERVAL: u16 = 2;
+const IFLA_BRIDGE_MRP_START_IN_TEST_MAX_MISS: u16 = 3;
+const IFLA_BRIDGE_MRP_START_IN_TEST_PERIOD: u16 = 4;
+
+/// Media Redundancy Protocol (IEC 62439-2) attributes nested in
+/// `IFLA_BRIDGE_MRP`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrp {
+    /// Add or delete MRP instance
+    Instance(Vec<BridgeMrpInstance>),
+    PortState(Vec<BridgeMrpPortState>),
+    PortRole(Vec<BridgeMrpPortRole>),
+    RingState(Vec<BridgeMrpRingState>),
+    RingRole(Vec<BridgeMrpRingRole>),
+    /// Start sending MRP_Test frames
+    StartTest(Vec<BridgeMrpStartTest>),
+    /// Status of MRP instance, only in kernel reply
+    Info(Vec<BridgeMrpInfo>),
+    InRole(Vec<BridgeMrpInRole>),
+    InState(Vec<BridgeMrpInState>),
+    /// Start sending MRP_InTest frames
+    StartInTest(Vec<BridgeMrpStartInTest>),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrp {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Instance(nlas) => nlas.as_slice().buffer_len(),
+            Self::PortState(nlas) => nlas.as_slice().buffer_len(),
+            Self::PortRole(nlas) => nlas.as_slice().buffer_len(),
+            Self::RingState(nlas) => nlas.as_slice().buffer_len(),
+            Self::RingRole(nlas) => nlas.as_slice().buffer_len(),
+            Self::StartTest(nlas) => nlas.as_slice().buffer_len(),
+            Self::Info(nlas) => nlas.as_slice().buffer_len(),
+            Self::InRole(nlas) => nlas.as_slice().buffer_len(),
+            Self::InState(nlas) => nlas.as_slice().buffer_len(),
+            Self::StartInTest(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Instance(nlas) => nlas.as_slice().emit(buffer),
+            Self::PortState(nlas) => nlas.as_slice().emit(buffer),
+            Self::PortRole(nlas) => nlas.as_slice().emit(buffer),
+            Self::RingState(nlas) => nlas.as_slice().emit(buffer),
+            Self::RingRole(nlas) => nlas.as_slice().emit(buffer),
+            Self::StartTest(nlas) => nlas.as_slice().emit(buffer),
+            Self::Info(nlas) => nlas.as_slice().emit(buffer),
+            Self::InRole(nlas) => nlas.as_slice().emit(buffer),
+            Self::InState(nlas) => nlas.as_slice().emit(buffer),
+            Self::StartInTest(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Instance(_) => IFLA_BRIDGE_MRP_INSTANCE | NLA_F_NESTED,
+            Self::PortState(_) => IFLA_BRIDGE_MRP_PORT_STATE | NLA_F_NESTED,
+            Self::PortRole(_) => IFLA_BRIDGE_MRP_PORT_ROLE | NLA_F_NESTED,
+            Self::RingState(_) => IFLA_BRIDGE_MRP_RING_STATE | NLA_F_NESTED,
+            Self::RingRole(_) => IFLA_BRIDGE_MRP_RING_ROLE | NLA_F_NESTED,
+            Self::StartTest(_) => IFLA_BRIDGE_MRP_START_TEST | NLA_F_NESTED,
+            Self::Info(_) => IFLA_BRIDGE_MRP_INFO | NLA_F_NESTED,
+            Self::InRole(_) => IFLA_BRIDGE_MRP_IN_ROLE | NLA_F_NESTED,
+            Self::InState(_) => IFLA_BRIDGE_MRP_IN_STATE | NLA_F_NESTED,
+            Self::StartInTest(_) => {
+                IFLA_BRIDGE_MRP_START_IN_TEST | NLA_F_NESTED
+            }
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BridgeMrp {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_INSTANCE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpInstance::parse(nla)?);
+                }
+                Self::Instance(nlas)
+            }
+            IFLA_BRIDGE_MRP_PORT_STATE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpPortState::parse(nla)?);
+                }
+                Self::PortState(nlas)
+            }
+            IFLA_BRIDGE_MRP_PORT_ROLE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpPortRole::parse(nla)?);
+                }
+                Self::PortRole(nlas)
+            }
+            IFLA_BRIDGE_MRP_RING_STATE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpRingState::parse(nla)?);
+                }
+                Self::RingState(nlas)
+            }
+            IFLA_BRIDGE_MRP_RING_ROLE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpRingRole::parse(nla)?);
+                }
+                Self::RingRole(nlas)
+            }
+            IFLA_BRIDGE_MRP_START_TEST => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpStartTest::parse(nla)?);
+                }
+                Self::StartTest(nlas)
+            }
+            IFLA_BRIDGE_MRP_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpInfo::parse(nla)?);
+                }
+                Self::Info(nlas)
+            }
+            IFLA_BRIDGE_MRP_IN_ROLE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpInRole::parse(nla)?);
+                }
+                Self::InRole(nlas)
+            }
+            IFLA_BRIDGE_MRP_IN_STATE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpInState::parse(nla)?);
+                }
+                Self::InState(nlas)
+            }
+            IFLA_BRIDGE_MRP_START_IN_TEST => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMrpStartInTest::parse(nla)?);
+                }
+                Self::StartInTest(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpInstance {
+    RingId(u32),
+    /// Interface index of primary ring port
+    PrimaryPort(u32),
+    /// Interface index of secondary ring port
+    SecondaryPort(u32),
+    Priority(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpInstance {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_) | Self::PrimaryPort(_) | Self::SecondaryPort(_) => {
+                4
+            }
+            Self::Priority(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(value)
+            | Self::PrimaryPort(value)
+            | Self::SecondaryPort(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Priority(value) => NativeEndian::write_u16(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_INSTANCE_RING_ID,
+            Self::PrimaryPort(_) => IFLA_BRIDGE_MRP_INSTANCE_P_IFINDEX,
+            Self::SecondaryPort(_) => IFLA_BRIDGE_MRP_INSTANCE_S_IFINDEX,
+            Self::Priority(_) => IFLA_BRIDGE_MRP_INSTANCE_PRIO,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpInstance
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_INSTANCE_RING_ID => {
+                Self::RingId(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INSTANCE_P_IFINDEX => {
+                Self::PrimaryPort(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INSTANCE_S_IFINDEX => {
+                Self::SecondaryPort(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INSTANCE_PRIO => {
+                Self::Priority(parse_u16(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpPortState {
+    State(MrpPortState),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpPortState {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::State(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::State(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::State(_) => IFLA_BRIDGE_MRP_PORT_STATE_STATE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpPortState
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_PORT_STATE_STATE => {
+                Self::State(parse_u32(payload)?.into())
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpPortRole {
+    Role(MrpPortRole),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpPortRole {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Role(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Role(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Role(_) => IFLA_BRIDGE_MRP_PORT_ROLE_ROLE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpPortRole
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_PORT_ROLE_ROLE => {
+                Self::Role(parse_u32(payload)?.into())
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpRingState {
+    RingId(u32),
+    State(MrpRingState),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpRingState {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_) | Self::State(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(value) => NativeEndian::write_u32(buffer, *value),
+            Self::State(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_RING_STATE_RING_ID,
+            Self::State(_) => IFLA_BRIDGE_MRP_RING_STATE_STATE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpRingState
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_RING_STATE_RING_ID => {
+                Self::RingId(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_RING_STATE_STATE => {
+                Self::State(parse_u32(payload)?.into())
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpRingRole {
+    RingId(u32),
+    Role(MrpRingRole),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpRingRole {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_) | Self::Role(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Role(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_RING_ROLE_RING_ID,
+            Self::Role(_) => IFLA_BRIDGE_MRP_RING_ROLE_ROLE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpRingRole
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_RING_ROLE_RING_ID => {
+                Self::RingId(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_RING_ROLE_ROLE => {
+                Self::Role(parse_u32(payload)?.into())
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpStartTest {
+    RingId(u32),
+    /// Interval in microseconds
+    Interval(u32),
+    MaxMiss(u32),
+    /// Period in microseconds
+    Period(u32),
+    Monitor(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpStartTest {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_)
+            | Self::Interval(_)
+            | Self::MaxMiss(_)
+            | Self::Period(_)
+            | Self::Monitor(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(value)
+            | Self::Interval(value)
+            | Self::MaxMiss(value)
+            | Self::Period(value)
+            | Self::Monitor(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_START_TEST_RING_ID,
+            Self::Interval(_) => IFLA_BRIDGE_MRP_START_TEST_INTERVAL,
+            Self::MaxMiss(_) => IFLA_BRIDGE_MRP_START_TEST_MAX_MISS,
+            Self::Period(_) => IFLA_BRIDGE_MRP_START_TEST_PERIOD,
+            Self::Monitor(_) => IFLA_BRIDGE_MRP_START_TEST_MONITOR,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpStartTest
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_START_TEST_RING_ID => {
+                Self::RingId(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_START_TEST_INTERVAL => {
+                Self::Interval(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_START_TEST_MAX_MISS => {
+                Self::MaxMiss(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_START_TEST_PERIOD => {
+                Self::Period(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_START_TEST_MONITOR => {
+                Self::Monitor(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpInfo {
+    RingId(u32),
+    PrimaryPort(u32),
+    SecondaryPort(u32),
+    Priority(u16),
+    RingState(MrpRingState),
+    RingRole(MrpRingRole),
+    TestInterval(u32),
+    TestMaxMiss(u32),
+    TestMonitor(u32),
+    InterconnectPort(u32),
+    InState(MrpInState),
+    InRole(MrpInRole),
+    InTestInterval(u32),
+    InTestMaxMiss(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_)
+            | Self::PrimaryPort(_)
+            | Self::SecondaryPort(_)
+            | Self::RingState(_)
+            | Self::RingRole(_)
+            | Self::TestInterval(_)
+            | Self::TestMaxMiss(_)
+            | Self::TestMonitor(_)
+            | Self::InterconnectPort(_)
+            | Self::InState(_)
+            | Self::InRole(_)
+            | Self::InTestInterval(_)
+            | Self::InTestMaxMiss(_) => 4,
+            Self::Priority(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(value)
+            | Self::PrimaryPort(value)
+            | Self::SecondaryPort(value)
+            | Self::TestInterval(value)
+            | Self::TestMaxMiss(value)
+            | Self::TestMonitor(value)
+            | Self::InterconnectPort(value)
+            | Self::InTestInterval(value)
+            | Self::InTestMaxMiss(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Priority(value) => NativeEndian::write_u16(buffer, *value),
+            Self::RingState(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::RingRole(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::InState(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::InRole(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_INFO_RING_ID,
+            Self::PrimaryPort(_) => IFLA_BRIDGE_MRP_INFO_P_IFINDEX,
+            Self::SecondaryPort(_) => IFLA_BRIDGE_MRP_INFO_S_IFINDEX,
+            Self::Priority(_) => IFLA_BRIDGE_MRP_INFO_PRIO,
+            Self::RingState(_) => IFLA_BRIDGE_MRP_INFO_RING_STATE,
+            Self::RingRole(_) => IFLA_BRIDGE_MRP_INFO_RING_ROLE,
+            Self::TestInterval(_) => IFLA_BRIDGE_MRP_INFO_TEST_INTERVAL,
+            Self::TestMaxMiss(_) => IFLA_BRIDGE_MRP_INFO_TEST_MAX_MISS,
+            Self::TestMonitor(_) => IFLA_BRIDGE_MRP_INFO_TEST_MONITOR,
+            Self::InterconnectPort(_) => IFLA_BRIDGE_MRP_INFO_I_IFINDEX,
+            Self::InState(_) => IFLA_BRIDGE_MRP_INFO_IN_STATE,
+            Self::InRole(_) => IFLA_BRIDGE_MRP_INFO_IN_ROLE,
+            Self::InTestInterval(_) => IFLA_BRIDGE_MRP_INFO_IN_TEST_INTERVAL,
+            Self::InTestMaxMiss(_) => IFLA_BRIDGE_MRP_INFO_IN_TEST_MAX_MISS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpInfo
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_INFO_RING_ID => Self::RingId(parse_u32(payload)?),
+            IFLA_BRIDGE_MRP_INFO_P_IFINDEX => {
+                Self::PrimaryPort(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INFO_S_IFINDEX => {
+                Self::SecondaryPort(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INFO_PRIO => Self::Priority(parse_u16(payload)?),
+            IFLA_BRIDGE_MRP_INFO_RING_STATE => {
+                Self::RingState(parse_u32(payload)?.into())
+            }
+            IFLA_BRIDGE_MRP_INFO_RING_ROLE => {
+                Self::RingRole(parse_u32(payload)?.into())
+            }
+            IFLA_BRIDGE_MRP_INFO_TEST_INTERVAL => {
+                Self::TestInterval(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INFO_TEST_MAX_MISS => {
+                Self::TestMaxMiss(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INFO_TEST_MONITOR => {
+                Self::TestMonitor(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INFO_I_IFINDEX => {
+                Self::InterconnectPort(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INFO_IN_STATE => {
+                Self::InState(parse_u32(payload)?.into())
+            }
+            IFLA_BRIDGE_MRP_INFO_IN_ROLE => {
+                Self::InRole(parse_u32(payload)?.into())
+            }
+            IFLA_BRIDGE_MRP_INFO_IN_TEST_INTERVAL => {
+                Self::InTestInterval(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_INFO_IN_TEST_MAX_MISS => {
+                Self::InTestMaxMiss(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpInRole {
+    RingId(u32),
+    InId(u16),
+    Role(MrpInRole),
+    InterconnectPort(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpInRole {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RingId(_) | Self::Role(_) | Self::InterconnectPort(_) => 4,
+            Self::InId(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RingId(value) | Self::InterconnectPort(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::InId(value) => NativeEndian::write_u16(buffer, *value),
+            Self::Role(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RingId(_) => IFLA_BRIDGE_MRP_IN_ROLE_RING_ID,
+            Self::InId(_) => IFLA_BRIDGE_MRP_IN_ROLE_IN_ID,
+            Self::Role(_) => IFLA_BRIDGE_MRP_IN_ROLE_ROLE,
+            Self::InterconnectPort(_) => IFLA_BRIDGE_MRP_IN_ROLE_I_IFINDEX,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpInRole
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_IN_ROLE_RING_ID => {
+                Self::RingId(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_IN_ROLE_IN_ID => Self::InId(parse_u16(payload)?),
+            IFLA_BRIDGE_MRP_IN_ROLE_ROLE => {
+                Self::Role(parse_u32(payload)?.into())
+            }
+            IFLA_BRIDGE_MRP_IN_ROLE_I_IFINDEX => {
+                Self::InterconnectPort(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpInState {
+    InId(u32),
+    State(MrpInState),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpInState {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::InId(_) | Self::State(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::InId(value) => NativeEndian::write_u32(buffer, *value),
+            Self::State(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::InId(_) => IFLA_BRIDGE_MRP_IN_STATE_IN_ID,
+            Self::State(_) => IFLA_BRIDGE_MRP_IN_STATE_STATE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpInState
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_IN_STATE_IN_ID => Self::InId(parse_u32(payload)?),
+            IFLA_BRIDGE_MRP_IN_STATE_STATE => {
+                Self::State(parse_u32(payload)?.into())
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMrpStartInTest {
+    InId(u32),
+    Interval(u32),
+    MaxMiss(u32),
+    Period(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMrpStartInTest {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::InId(_)
+            | Self::Interval(_)
+            | Self::MaxMiss(_)
+            | Self::Period(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::InId(value)
+            | Self::Interval(value)
+            | Self::MaxMiss(value)
+            | Self::Period(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::InId(_) => IFLA_BRIDGE_MRP_START_IN_TEST_IN_ID,
+            Self::Interval(_) => IFLA_BRIDGE_MRP_START_IN_TEST_INTERVAL,
+            Self::MaxMiss(_) => IFLA_BRIDGE_MRP_START_IN_TEST_MAX_MISS,
+            Self::Period(_) => IFLA_BRIDGE_MRP_START_IN_TEST_PERIOD,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMrpStartInTest
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MRP_START_IN_TEST_IN_ID => {
+                Self::InId(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_START_IN_TEST_INTERVAL => {
+                Self::Interval(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_START_IN_TEST_MAX_MISS => {
+                Self::MaxMiss(parse_u32(payload)?)
+            }
+            IFLA_BRIDGE_MRP_START_IN_TEST_PERIOD => {
+                Self::Period(parse_u32(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const BR_MRP_PORT_STATE_DISABLED: u32 = 0;
+const BR_MRP_PORT_STATE_BLOCKED: u32 = 1;
+const BR_MRP_PORT_STATE_FORWARDING: u32 = 2;
+const BR_MRP_PORT_STATE_NOT_CONNECTED: u32 = 3;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MrpPortState {
+    Disabled,
+    Blocked,
+    Forwarding,
+    NotConnected,
+    Other(u32),
+}
+
+impl From<u32> for MrpPortState {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_PORT_STATE_DISABLED => Self::Disabled,
+            BR_MRP_PORT_STATE_BLOCKED => Self::Blocked,
+            BR_MRP_PORT_STATE_FORWARDING => Self::Forwarding,
+            BR_MRP_PORT_STATE_NOT_CONNECTED => Self::NotConnected,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MrpPortState> for u32 {
+    fn from(v: MrpPortState) -> u32 {
+        match v {
+            MrpPortState::Disabled => BR_MRP_PORT_STATE_DISABLED,
+            MrpPortState::Blocked => BR_MRP_PORT_STATE_BLOCKED,
+            MrpPortState::Forwarding => BR_MRP_PORT_STATE_FORWARDING,
+            MrpPortState::NotConnected => BR_MRP_PORT_STATE_NOT_CONNECTED,
+            MrpPortState::Other(d) => d,
+        }
+    }
+}
+
+const BR_MRP_PORT_ROLE_PRIMARY: u32 = 0;
+const BR_MRP_PORT_ROLE_SECONDARY: u32 = 1;
+const BR_MRP_PORT_ROLE_INTER: u32 = 2;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MrpPortRole {
+    Primary,
+    Secondary,
+    Interconnect,
+    Other(u32),
+}
+
+impl From<u32> for MrpPortRole {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_PORT_ROLE_PRIMARY => Self::Primary,
+            BR_MRP_PORT_ROLE_SECONDARY => Self::Secondary,
+            BR_MRP_PORT_ROLE_INTER => Self::Interconnect,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MrpPortRole> for u32 {
+    fn from(v: MrpPortRole) -> u32 {
+        match v {
+            MrpPortRole::Primary => BR_MRP_PORT_ROLE_PRIMARY,
+            MrpPortRole::Secondary => BR_MRP_PORT_ROLE_SECONDARY,
+            MrpPortRole::Interconnect => BR_MRP_PORT_ROLE_INTER,
+            MrpPortRole::Other(d) => d,
+        }
+    }
+}
+
+const BR_MRP_RING_STATE_OPEN: u32 = 0;
+const BR_MRP_RING_STATE_CLOSED: u32 = 1;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MrpRingState {
+    Open,
+    Closed,
+    Other(u32),
+}
+
+impl From<u32> for MrpRingState {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_RING_STATE_OPEN => Self::Open,
+            BR_MRP_RING_STATE_CLOSED => Self::Closed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MrpRingState> for u32 {
+    fn from(v: MrpRingState) -> u32 {
+        match v {
+            MrpRingState::Open => BR_MRP_RING_STATE_OPEN,
+            MrpRingState::Closed => BR_MRP_RING_STATE_CLOSED,
+            MrpRingState::Other(d) => d,
+        }
+    }
+}
+
+const BR_MRP_RING_ROLE_DISABLED: u32 = 0;
+const BR_MRP_RING_ROLE_MRC: u32 = 1;
+const BR_MRP_RING_ROLE_MRM: u32 = 2;
+const BR_MRP_RING_ROLE_MRA: u32 = 3;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MrpRingRole {
+    Disabled,
+    /// Media Redundancy Client
+    Client,
+    /// Media Redundancy Manager
+    Manager,
+    /// Media Redundancy Automanager
+    AutoManager,
+    Other(u32),
+}
+
+impl From<u32> for MrpRingRole {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_RING_ROLE_DISABLED => Self::Disabled,
+            BR_MRP_RING_ROLE_MRC => Self::Client,
+            BR_MRP_RING_ROLE_MRM => Self::Manager,
+            BR_MRP_RING_ROLE_MRA => Self::AutoManager,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MrpRingRole> for u32 {
+    fn from(v: MrpRingRole) -> u32 {
+        match v {
+            MrpRingRole::Disabled => BR_MRP_RING_ROLE_DISABLED,
+            MrpRingRole::Client => BR_MRP_RING_ROLE_MRC,
+            MrpRingRole::Manager => BR_MRP_RING_ROLE_MRM,
+            MrpRingRole::AutoManager => BR_MRP_RING_ROLE_MRA,
+            MrpRingRole::Other(d) => d,
+        }
+    }
+}
+
+const BR_MRP_IN_STATE_OPEN: u32 = 0;
+const BR_MRP_IN_STATE_CLOSED: u32 = 1;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MrpInState {
+    Open,
+    Closed,
+    Other(u32),
+}
+
+impl From<u32> for MrpInState {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_IN_STATE_OPEN => Self::Open,
+            BR_MRP_IN_STATE_CLOSED => Self::Closed,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MrpInState> for u32 {
+    fn from(v: MrpInState) -> u32 {
+        match v {
+            MrpInState::Open => BR_MRP_IN_STATE_OPEN,
+            MrpInState::Closed => BR_MRP_IN_STATE_CLOSED,
+            MrpInState::Other(d) => d,
+        }
+    }
+}
+
+const BR_MRP_IN_ROLE_DISABLED: u32 = 0;
+const BR_MRP_IN_ROLE_MIC: u32 = 1;
+const BR_MRP_IN_ROLE_MIM: u32 = 2;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MrpInRole {
+    Disabled,
+    /// Media Redundancy Interconnection Client
+    Client,
+    /// Media Redundancy Interconnection Manager
+    Manager,
+    Other(u32),
+}
+
+impl From<u32> for MrpInRole {
+    fn from(d: u32) -> Self {
+        match d {
+            BR_MRP_IN_ROLE_DISABLED => Self::Disabled,
+            BR_MRP_IN_ROLE_MIC => Self::Client,
+            BR_MRP_IN_ROLE_MIM => Self::Manager,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MrpInRole> for u32 {
+    fn from(v: MrpInRole) -> u32 {
+        match v {
+            MrpInRole::Disabled => BR_MRP_IN_ROLE_DISABLED,
+            MrpInRole::Client => BR_MRP_IN_ROLE_MIC,
+            MrpInRole::Manager => BR_MRP_IN_ROLE_MIM,
+            MrpInRole::Other(d) => d,
+        }
+    }
+}
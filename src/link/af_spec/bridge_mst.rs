@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::BridgePortState;
+
+const IFLA_BRIDGE_MST_ENTRY: u16 = 1;
+
+const IFLA_BRIDGE_MST_ENTRY_MSTI: u16 = 1;
+const IFLA_BRIDGE_MST_ENTRY_STATE: u16 = 2;
+
+/// Multiple spanning tree attributes nested in `IFLA_BRIDGE_MST`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMst {
+    Entry(Vec<BridgeMstEntry>),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMst {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => IFLA_BRIDGE_MST_ENTRY | NLA_F_NESTED,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BridgeMst {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MST_ENTRY => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeMstEntry::parse(nla)?);
+                }
+                Self::Entry(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BridgeMstEntry {
+    /// Multiple spanning tree instance ID
+    Msti(u16),
+    /// Port state in this MSTI
+    State(BridgePortState),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeMstEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Msti(_) => 2,
+            Self::State(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Msti(value) => NativeEndian::write_u16(buffer, *value),
+            Self::State(value) => buffer[0] = (*value).into(),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Msti(_) => IFLA_BRIDGE_MST_ENTRY_MSTI,
+            Self::State(_) => IFLA_BRIDGE_MST_ENTRY_STATE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeMstEntry
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_MST_ENTRY_MSTI => Self::Msti(parse_u16(payload)?),
+            IFLA_BRIDGE_MST_ENTRY_STATE => {
+                Self::State(parse_u8(payload)?.into())
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: MIT
 
 mod bridge;
+mod bridge_cfm;
+mod bridge_mrp;
+mod bridge_mst;
 mod inet;
 mod inet6;
 mod inet6_cache;
@@ -14,6 +17,20 @@ pub use self::bridge::{
     AfSpecBridge, BridgeFlag, BridgeMode, BridgeVlanInfo, BridgeVlanInfoFlags,
     BridgeVlanTunnelInfo,
 };
+pub use self::bridge_cfm::{
+    BridgeCfm, BridgeCfmCcCcmTx, BridgeCfmCcConfig, BridgeCfmCcPeerMep,
+    BridgeCfmCcPeerStatus, BridgeCfmCcRdi, BridgeCfmMepConfig,
+    BridgeCfmMepCreate, BridgeCfmMepDelete, BridgeCfmMepStatus, CfmCcmInterval,
+    CfmDomain, CfmMepDirection,
+};
+pub use self::bridge_mrp::{
+    BridgeMrp, BridgeMrpInRole, BridgeMrpInState, BridgeMrpInfo,
+    BridgeMrpInstance, BridgeMrpPortRole, BridgeMrpPortState,
+    BridgeMrpRingRole, BridgeMrpRingState, BridgeMrpStartInTest,
+    BridgeMrpStartTest, MrpInRole, MrpInState, MrpPortRole, MrpPortState,
+    MrpRingRole, MrpRingState,
+};
+pub use self::bridge_mst::{BridgeMst, BridgeMstEntry};
 pub use self::inet::{AfSpecInet, InetDevConf};
 pub use self::inet6::AfSpecInet6;
 pub use self::inet6_cache::{Inet6CacheInfo, Inet6CacheInfoBuffer};
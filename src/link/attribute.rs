@@ -32,10 +32,11 @@ use super::{
     stats::LINK_STATS_LEN,
     stats64::LINK_STATS64_LEN,
     xdp::VecLinkXdp,
-    AfSpecBridge, AfSpecUnspec, LinkEvent, LinkExtentMask, LinkInfo,
-    LinkPhysId, LinkProtoInfoBridge, LinkProtoInfoInet6,
-    LinkProtocolDownReason, LinkVfInfo, LinkVfPort, LinkWirelessEvent, LinkXdp,
-    Map, MapBuffer, Prop, State, Stats, Stats64, Stats64Buffer, StatsBuffer,
+    AfSpecBridge, AfSpecUnspec, LinkDevlinkPort, LinkDpllPin, LinkEvent,
+    LinkExtentMask, LinkInfo, LinkPhysId, LinkProtoInfoBridge,
+    LinkProtoInfoInet6, LinkProtocolDownReason, LinkVfInfo, LinkVfPort,
+    LinkWirelessEvent, LinkXdp, Map, MapBuffer, Prop, State, Stats, Stats64,
+    Stats64Buffer, StatsBuffer,
 };
 use crate::AddressFamily;
 
@@ -97,7 +98,6 @@ const IFLA_PROP_LIST: u16 = 52;
 const IFLA_PERM_ADDRESS: u16 = 54;
 const IFLA_PROTO_DOWN_REASON: u16 = 55;
 
-/* TODO:(Gris Ge)
 const IFLA_PARENT_DEV_NAME: u16 = 56;
 const IFLA_PARENT_DEV_BUS_NAME: u16 = 57;
 const IFLA_GRO_MAX_SIZE: u16 = 58;
@@ -105,7 +105,9 @@ const IFLA_TSO_MAX_SIZE: u16 = 59;
 const IFLA_TSO_MAX_SEGS: u16 = 60;
 const IFLA_ALLMULTI: u16 = 61;
 const IFLA_DEVLINK_PORT: u16 = 62;
-*/
+const IFLA_GSO_IPV4_MAX_SIZE: u16 = 63;
+const IFLA_GRO_IPV4_MAX_SIZE: u16 = 64;
+const IFLA_DPLL_PIN: u16 = 65;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -160,6 +162,24 @@ pub enum LinkAttribute {
     MinMtu(u32),
     /// The maximum MTU for the device.
     MaxMtu(u32),
+    /// Name of the parent device, e.g. PCI address `0000:00:14.3`
+    ParentDevName(String),
+    /// Bus name of the parent device, e.g. `pci`
+    ParentDevBusName(String),
+    /// Maximum size of packet aggregated by GRO for IPv6
+    GroMaxSize(u32),
+    /// Maximum size of packet the device can segment by TSO
+    TsoMaxSize(u32),
+    /// Maximum number of segments the device can produce by TSO
+    TsoMaxSegs(u32),
+    /// Reference counter of the `IFF_ALLMULTI` flag
+    AllMulti(u32),
+    DevlinkPort(Vec<LinkDevlinkPort>),
+    /// Maximum size of GSO packet for IPv4
+    GsoIpv4MaxSize(u32),
+    /// Maximum size of packet aggregated by GRO for IPv4
+    GroIpv4MaxSize(u32),
+    DpllPin(Vec<LinkDpllPin>),
     LinkNetNsId(i32),
     OperState(State),
     Stats(Stats),
@@ -195,7 +215,9 @@ impl Nla for LinkAttribute {
             Self::IfName(string)
             | Self::Qdisc(string)
             | Self::IfAlias(string)
-            | Self::PhysPortName(string) => string.len() + 1,
+            | Self::PhysPortName(string)
+            | Self::ParentDevName(string)
+            | Self::ParentDevBusName(string) => string.len() + 1,
 
             Self::Mode(_) | Self::Carrier(_) | Self::ProtoDown(_) => 1,
 
@@ -221,7 +243,13 @@ impl Nla for LinkAttribute {
             | Self::CarrierUpCount(_)
             | Self::CarrierDownCount(_)
             | Self::NewIfIndex(_)
-            | Self::MaxMtu(_) => 4,
+            | Self::MaxMtu(_)
+            | Self::GroMaxSize(_)
+            | Self::TsoMaxSize(_)
+            | Self::TsoMaxSegs(_)
+            | Self::AllMulti(_)
+            | Self::GsoIpv4MaxSize(_)
+            | Self::GroIpv4MaxSize(_) => 4,
 
             Self::OperState(_) => 1,
             Self::Stats(_) => LINK_STATS_LEN,
@@ -230,6 +258,8 @@ impl Nla for LinkAttribute {
             Self::LinkInfo(nlas) => nlas.as_slice().buffer_len(),
             Self::Xdp(nlas) => nlas.as_slice().buffer_len(),
             Self::PropList(nlas) => nlas.as_slice().buffer_len(),
+            Self::DevlinkPort(nlas) => nlas.as_slice().buffer_len(),
+            Self::DpllPin(nlas) => nlas.as_slice().buffer_len(),
             Self::AfSpecUnspec(nlas) => nlas.as_slice().buffer_len(),
             Self::AfSpecBridge(nlas) => nlas.as_slice().buffer_len(),
             Self::ProtoInfoUnknown(attr) => attr.value_len(),
@@ -259,7 +289,9 @@ impl Nla for LinkAttribute {
             Self::IfName(string)
             | Self::Qdisc(string)
             | Self::IfAlias(string)
-            | Self::PhysPortName(string) => {
+            | Self::PhysPortName(string)
+            | Self::ParentDevName(string)
+            | Self::ParentDevBusName(string) => {
                 buffer[..string.len()].copy_from_slice(string.as_bytes());
                 buffer[string.len()] = 0;
             }
@@ -284,7 +316,15 @@ impl Nla for LinkAttribute {
             | Self::GsoMaxSegs(value)
             | Self::GsoMaxSize(value)
             | Self::MinMtu(value)
-            | Self::MaxMtu(value) => NativeEndian::write_u32(buffer, *value),
+            | Self::MaxMtu(value)
+            | Self::GroMaxSize(value)
+            | Self::TsoMaxSize(value)
+            | Self::TsoMaxSegs(value)
+            | Self::AllMulti(value)
+            | Self::GsoIpv4MaxSize(value)
+            | Self::GroIpv4MaxSize(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
 
             Self::ExtMask(value) => NativeEndian::write_u32(
                 buffer,
@@ -303,6 +343,8 @@ impl Nla for LinkAttribute {
             Self::LinkInfo(nlas) => nlas.as_slice().emit(buffer),
             Self::Xdp(nlas) => nlas.as_slice().emit(buffer),
             Self::PropList(nlas) => nlas.as_slice().emit(buffer),
+            Self::DevlinkPort(nlas) => nlas.as_slice().emit(buffer),
+            Self::DpllPin(nlas) => nlas.as_slice().emit(buffer),
             Self::AfSpecUnspec(nlas) => nlas.as_slice().emit(buffer),
             Self::AfSpecBridge(nlas) => nlas.as_slice().emit(buffer),
             Self::ProtoInfoUnknown(attr) | Self::Other(attr) => {
@@ -320,7 +362,8 @@ impl Nla for LinkAttribute {
             Self::PhysSwitchId(_) => IFLA_PHYS_SWITCH_ID,
             Self::LinkInfo(_) => IFLA_LINKINFO,
             Self::Wireless(_) => IFLA_WIRELESS,
-            Self::ProtoInfoBridge(_) | Self::ProtoInfoInet6(_) => IFLA_PROTINFO,
+            Self::ProtoInfoBridge(_) => IFLA_PROTINFO | NLA_F_NESTED,
+            Self::ProtoInfoInet6(_) => IFLA_PROTINFO,
             Self::ProtoInfoUnknown(attr) => attr.kind(),
             Self::Xdp(_) => IFLA_XDP,
             Self::Event(_) => IFLA_EVENT,
@@ -358,6 +401,16 @@ impl Nla for LinkAttribute {
             Self::GsoMaxSize(_) => IFLA_GSO_MAX_SIZE,
             Self::MinMtu(_) => IFLA_MIN_MTU,
             Self::MaxMtu(_) => IFLA_MAX_MTU,
+            Self::ParentDevName(_) => IFLA_PARENT_DEV_NAME,
+            Self::ParentDevBusName(_) => IFLA_PARENT_DEV_BUS_NAME,
+            Self::GroMaxSize(_) => IFLA_GRO_MAX_SIZE,
+            Self::TsoMaxSize(_) => IFLA_TSO_MAX_SIZE,
+            Self::TsoMaxSegs(_) => IFLA_TSO_MAX_SEGS,
+            Self::AllMulti(_) => IFLA_ALLMULTI,
+            Self::DevlinkPort(_) => IFLA_DEVLINK_PORT | NLA_F_NESTED,
+            Self::GsoIpv4MaxSize(_) => IFLA_GSO_IPV4_MAX_SIZE,
+            Self::GroIpv4MaxSize(_) => IFLA_GRO_IPV4_MAX_SIZE,
+            Self::DpllPin(_) => IFLA_DPLL_PIN | NLA_F_NESTED,
             Self::LinkNetNsId(_) => IFLA_LINK_NETNSID,
             Self::OperState(_) => IFLA_OPERSTATE,
             Self::Map(_) => IFLA_MAP,
@@ -487,6 +540,34 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
             IFLA_GSO_MAX_SIZE => Self::GsoMaxSize(parse_u32(payload)?),
             IFLA_MIN_MTU => Self::MinMtu(parse_u32(payload)?),
             IFLA_MAX_MTU => Self::MaxMtu(parse_u32(payload)?),
+            IFLA_PARENT_DEV_NAME => Self::ParentDevName(parse_string(payload)?),
+            IFLA_PARENT_DEV_BUS_NAME => {
+                Self::ParentDevBusName(parse_string(payload)?)
+            }
+            IFLA_GRO_MAX_SIZE => Self::GroMaxSize(parse_u32(payload)?),
+            IFLA_TSO_MAX_SIZE => Self::TsoMaxSize(parse_u32(payload)?),
+            IFLA_TSO_MAX_SEGS => Self::TsoMaxSegs(parse_u32(payload)?),
+            IFLA_ALLMULTI => Self::AllMulti(parse_u32(payload)?),
+            IFLA_DEVLINK_PORT => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = LinkDevlinkPort::parse(nla)?;
+                    nlas.push(parsed);
+                }
+                Self::DevlinkPort(nlas)
+            }
+            IFLA_GSO_IPV4_MAX_SIZE => Self::GsoIpv4MaxSize(parse_u32(payload)?),
+            IFLA_GRO_IPV4_MAX_SIZE => Self::GroIpv4MaxSize(parse_u32(payload)?),
+            IFLA_DPLL_PIN => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = LinkDpllPin::parse(nla)?;
+                    nlas.push(parsed);
+                }
+                Self::DpllPin(nlas)
+            }
             IFLA_LINK_NETNSID => Self::LinkNetNsId(parse_i32(payload)?),
             IFLA_OPERSTATE => Self::OperState(parse_u8(payload)?.into()),
             IFLA_MAP => {
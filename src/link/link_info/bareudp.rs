@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be},
+    DecodeError, Parseable,
+};
+
+const IFLA_BAREUDP_PORT: u16 = 1;
+const IFLA_BAREUDP_ETHERTYPE: u16 = 2;
+const IFLA_BAREUDP_SRCPORT_MIN: u16 = 3;
+const IFLA_BAREUDP_MULTIPROTO_MODE: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoBareUdp {
+    /// Destination UDP port
+    Port(u16),
+    /// Ethertype of the tunnelled L3 protocol, e.g. 0x8847 for MPLS
+    EtherType(u16),
+    /// Lowest UDP source port used for flow entropy
+    SourcePortMin(u16),
+    /// Also accept the multicast (MPLS) or IPv6 (IP) variant of `EtherType`
+    MultiProtoMode,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBareUdp {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Port(_) | Self::EtherType(_) | Self::SourcePortMin(_) => 2,
+            Self::MultiProtoMode => 0,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Port(value) | Self::EtherType(value) => {
+                BigEndian::write_u16(buffer, *value)
+            }
+            Self::SourcePortMin(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            Self::MultiProtoMode => (),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Port(_) => IFLA_BAREUDP_PORT,
+            Self::EtherType(_) => IFLA_BAREUDP_ETHERTYPE,
+            Self::SourcePortMin(_) => IFLA_BAREUDP_SRCPORT_MIN,
+            Self::MultiProtoMode => IFLA_BAREUDP_MULTIPROTO_MODE,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBareUdp {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BAREUDP_PORT => Self::Port(parse_u16_be(payload)?),
+            IFLA_BAREUDP_ETHERTYPE => Self::EtherType(parse_u16_be(payload)?),
+            IFLA_BAREUDP_SRCPORT_MIN => {
+                Self::SourcePortMin(parse_u16(payload)?)
+            }
+            IFLA_BAREUDP_MULTIPROTO_MODE => Self::MultiProtoMode,
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,750 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const IFLA_CAN_BITTIMING: u16 = 1;
+const IFLA_CAN_BITTIMING_CONST: u16 = 2;
+const IFLA_CAN_CLOCK: u16 = 3;
+const IFLA_CAN_STATE: u16 = 4;
+const IFLA_CAN_CTRLMODE: u16 = 5;
+const IFLA_CAN_RESTART_MS: u16 = 6;
+const IFLA_CAN_RESTART: u16 = 7;
+const IFLA_CAN_BERR_COUNTER: u16 = 8;
+const IFLA_CAN_DATA_BITTIMING: u16 = 9;
+const IFLA_CAN_DATA_BITTIMING_CONST: u16 = 10;
+const IFLA_CAN_TERMINATION: u16 = 11;
+const IFLA_CAN_TERMINATION_CONST: u16 = 12;
+const IFLA_CAN_BITRATE_CONST: u16 = 13;
+const IFLA_CAN_DATA_BITRATE_CONST: u16 = 14;
+const IFLA_CAN_BITRATE_MAX: u16 = 15;
+const IFLA_CAN_TDC: u16 = 16;
+const IFLA_CAN_CTRLMODE_EXT: u16 = 17;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoCan {
+    BitTiming(CanBitTiming),
+    BitTimingConst(CanBitTimingConst),
+    /// CAN system clock frequency in Hz
+    Clock(u32),
+    State(CanState),
+    CtrlMode(CanCtrlMode),
+    /// Delay in milliseconds before automatic restart after bus-off,
+    /// 0 means disabled
+    RestartMs(u32),
+    /// Trigger manual restart of a bus-off device
+    Restart(u32),
+    BerrCounter(CanBerrCounter),
+    /// Bit-timing of the data phase of CAN FD
+    DataBitTiming(CanBitTiming),
+    DataBitTimingConst(CanBitTimingConst),
+    /// Termination resistor in Ohm
+    Termination(u16),
+    TerminationConst(Vec<u16>),
+    BitRateConst(Vec<u32>),
+    DataBitRateConst(Vec<u32>),
+    BitRateMax(u32),
+    /// Transmitter Delay Compensation
+    Tdc(Vec<CanTdc>),
+    CtrlModeExt(Vec<CanCtrlModeExt>),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoCan {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::BitTiming(v) | Self::DataBitTiming(v) => v.buffer_len(),
+            Self::BitTimingConst(v) | Self::DataBitTimingConst(v) => {
+                v.buffer_len()
+            }
+            Self::Clock(_)
+            | Self::State(_)
+            | Self::RestartMs(_)
+            | Self::Restart(_)
+            | Self::BitRateMax(_) => 4,
+            Self::CtrlMode(v) => v.buffer_len(),
+            Self::BerrCounter(v) => v.buffer_len(),
+            Self::Termination(_) => 2,
+            Self::TerminationConst(v) => v.len() * 2,
+            Self::BitRateConst(v) | Self::DataBitRateConst(v) => v.len() * 4,
+            Self::Tdc(nlas) => nlas.as_slice().buffer_len(),
+            Self::CtrlModeExt(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::BitTiming(v) | Self::DataBitTiming(v) => v.emit(buffer),
+            Self::BitTimingConst(v) | Self::DataBitTimingConst(v) => {
+                v.emit(buffer)
+            }
+            Self::Clock(value)
+            | Self::RestartMs(value)
+            | Self::Restart(value)
+            | Self::BitRateMax(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::State(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::CtrlMode(v) => v.emit(buffer),
+            Self::BerrCounter(v) => v.emit(buffer),
+            Self::Termination(value) => NativeEndian::write_u16(buffer, *value),
+            Self::TerminationConst(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    NativeEndian::write_u16(&mut buffer[i * 2..], *value);
+                }
+            }
+            Self::BitRateConst(values) | Self::DataBitRateConst(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    NativeEndian::write_u32(&mut buffer[i * 4..], *value);
+                }
+            }
+            Self::Tdc(nlas) => nlas.as_slice().emit(buffer),
+            Self::CtrlModeExt(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::BitTiming(_) => IFLA_CAN_BITTIMING,
+            Self::BitTimingConst(_) => IFLA_CAN_BITTIMING_CONST,
+            Self::Clock(_) => IFLA_CAN_CLOCK,
+            Self::State(_) => IFLA_CAN_STATE,
+            Self::CtrlMode(_) => IFLA_CAN_CTRLMODE,
+            Self::RestartMs(_) => IFLA_CAN_RESTART_MS,
+            Self::Restart(_) => IFLA_CAN_RESTART,
+            Self::BerrCounter(_) => IFLA_CAN_BERR_COUNTER,
+            Self::DataBitTiming(_) => IFLA_CAN_DATA_BITTIMING,
+            Self::DataBitTimingConst(_) => IFLA_CAN_DATA_BITTIMING_CONST,
+            Self::Termination(_) => IFLA_CAN_TERMINATION,
+            Self::TerminationConst(_) => IFLA_CAN_TERMINATION_CONST,
+            Self::BitRateConst(_) => IFLA_CAN_BITRATE_CONST,
+            Self::DataBitRateConst(_) => IFLA_CAN_DATA_BITRATE_CONST,
+            Self::BitRateMax(_) => IFLA_CAN_BITRATE_MAX,
+            Self::Tdc(_) => IFLA_CAN_TDC | NLA_F_NESTED,
+            Self::CtrlModeExt(_) => IFLA_CAN_CTRLMODE_EXT | NLA_F_NESTED,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoCan {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_CAN_BITTIMING => Self::BitTiming(CanBitTiming::parse(
+                &CanBitTimingBuffer::new_checked(payload)?,
+            )?),
+            IFLA_CAN_BITTIMING_CONST => {
+                Self::BitTimingConst(CanBitTimingConst::parse(
+                    &CanBitTimingConstBuffer::new_checked(payload)?,
+                )?)
+            }
+            IFLA_CAN_CLOCK => Self::Clock(parse_u32(payload)?),
+            IFLA_CAN_STATE => Self::State(parse_u32(payload)?.into()),
+            IFLA_CAN_CTRLMODE => Self::CtrlMode(CanCtrlMode::parse(
+                &CanCtrlModeBuffer::new_checked(payload)?,
+            )?),
+            IFLA_CAN_RESTART_MS => Self::RestartMs(parse_u32(payload)?),
+            IFLA_CAN_RESTART => Self::Restart(parse_u32(payload)?),
+            IFLA_CAN_BERR_COUNTER => Self::BerrCounter(CanBerrCounter::parse(
+                &CanBerrCounterBuffer::new_checked(payload)?,
+            )?),
+            IFLA_CAN_DATA_BITTIMING => {
+                Self::DataBitTiming(CanBitTiming::parse(
+                    &CanBitTimingBuffer::new_checked(payload)?,
+                )?)
+            }
+            IFLA_CAN_DATA_BITTIMING_CONST => {
+                Self::DataBitTimingConst(CanBitTimingConst::parse(
+                    &CanBitTimingConstBuffer::new_checked(payload)?,
+                )?)
+            }
+            IFLA_CAN_TERMINATION => Self::Termination(parse_u16(payload)?),
+            IFLA_CAN_TERMINATION_CONST => {
+                Self::TerminationConst(parse_u16_array(payload)?)
+            }
+            IFLA_CAN_BITRATE_CONST => {
+                Self::BitRateConst(parse_u32_array(payload)?)
+            }
+            IFLA_CAN_DATA_BITRATE_CONST => {
+                Self::DataBitRateConst(parse_u32_array(payload)?)
+            }
+            IFLA_CAN_BITRATE_MAX => Self::BitRateMax(parse_u32(payload)?),
+            IFLA_CAN_TDC => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(CanTdc::parse(nla)?);
+                }
+                Self::Tdc(nlas)
+            }
+            IFLA_CAN_CTRLMODE_EXT => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(CanCtrlModeExt::parse(nla)?);
+                }
+                Self::CtrlModeExt(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+fn parse_u16_array(payload: &[u8]) -> Result<Vec<u16>, DecodeError> {
+    let chunks = payload.chunks_exact(2);
+    if !chunks.remainder().is_empty() {
+        return Err(DecodeError::from(format!(
+            "Invalid u16 array length {}, expecting a multiple of 2 bytes, \
+             got {:?}",
+            payload.len(),
+            payload,
+        )));
+    }
+    let mut values = Vec::new();
+    for chunk in chunks {
+        values.push(parse_u16(chunk)?);
+    }
+    Ok(values)
+}
+
+fn parse_u32_array(payload: &[u8]) -> Result<Vec<u32>, DecodeError> {
+    let chunks = payload.chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return Err(DecodeError::from(format!(
+            "Invalid u32 array length {}, expecting a multiple of 4 bytes, \
+             got {:?}",
+            payload.len(),
+            payload,
+        )));
+    }
+    let mut values = Vec::new();
+    for chunk in chunks {
+        values.push(parse_u32(chunk)?);
+    }
+    Ok(values)
+}
+
+const CAN_BITTIMING_LEN: usize = 32;
+
+buffer!(CanBitTimingBuffer(CAN_BITTIMING_LEN) {
+    bitrate: (u32, 0..4),
+    sample_point: (u32, 4..8),
+    tq: (u32, 8..12),
+    prop_seg: (u32, 12..16),
+    phase_seg1: (u32, 16..20),
+    phase_seg2: (u32, 20..24),
+    sjw: (u32, 24..28),
+    brp: (u32, 28..32),
+});
+
+/// CAN bit-timing parameters (`struct can_bittiming`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct CanBitTiming {
+    /// Bit-rate in bits/second
+    pub bitrate: u32,
+    /// Sample point in one-tenth of a percent
+    pub sample_point: u32,
+    /// Time quanta in nanoseconds
+    pub tq: u32,
+    /// Propagation segment in time quanta
+    pub prop_seg: u32,
+    /// Phase buffer segment 1 in time quanta
+    pub phase_seg1: u32,
+    /// Phase buffer segment 2 in time quanta
+    pub phase_seg2: u32,
+    /// Synchronisation jump width in time quanta
+    pub sjw: u32,
+    /// Bit-rate prescaler
+    pub brp: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<CanBitTimingBuffer<T>> for CanBitTiming {
+    type Error = DecodeError;
+    fn parse(buf: &CanBitTimingBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bitrate: buf.bitrate(),
+            sample_point: buf.sample_point(),
+            tq: buf.tq(),
+            prop_seg: buf.prop_seg(),
+            phase_seg1: buf.phase_seg1(),
+            phase_seg2: buf.phase_seg2(),
+            sjw: buf.sjw(),
+            brp: buf.brp(),
+        })
+    }
+}
+
+impl Emitable for CanBitTiming {
+    fn buffer_len(&self) -> usize {
+        CAN_BITTIMING_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanBitTimingBuffer::new(buffer);
+        buffer.set_bitrate(self.bitrate);
+        buffer.set_sample_point(self.sample_point);
+        buffer.set_tq(self.tq);
+        buffer.set_prop_seg(self.prop_seg);
+        buffer.set_phase_seg1(self.phase_seg1);
+        buffer.set_phase_seg2(self.phase_seg2);
+        buffer.set_sjw(self.sjw);
+        buffer.set_brp(self.brp);
+    }
+}
+
+const CAN_BITTIMING_CONST_NAME_LEN: usize = 16;
+const CAN_BITTIMING_CONST_LEN: usize = 48;
+
+buffer!(CanBitTimingConstBuffer(CAN_BITTIMING_CONST_LEN) {
+    name: (slice, 0..CAN_BITTIMING_CONST_NAME_LEN),
+    tseg1_min: (u32, 16..20),
+    tseg1_max: (u32, 20..24),
+    tseg2_min: (u32, 24..28),
+    tseg2_max: (u32, 28..32),
+    sjw_max: (u32, 32..36),
+    brp_min: (u32, 36..40),
+    brp_max: (u32, 40..44),
+    brp_inc: (u32, 44..48),
+});
+
+/// Hardware limits of CAN bit-timing (`struct can_bittiming_const`)
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct CanBitTimingConst {
+    /// Name of the CAN controller hardware, at most 15 bytes
+    pub name: String,
+    pub tseg1_min: u32,
+    pub tseg1_max: u32,
+    pub tseg2_min: u32,
+    pub tseg2_max: u32,
+    pub sjw_max: u32,
+    pub brp_min: u32,
+    pub brp_max: u32,
+    pub brp_inc: u32,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<CanBitTimingConstBuffer<&'a T>>
+    for CanBitTimingConst
+{
+    type Error = DecodeError;
+    fn parse(
+        buf: &CanBitTimingConstBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let name = buf.name();
+        let name_len = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+        Ok(Self {
+            name: String::from_utf8(name[..name_len].to_vec())?,
+            tseg1_min: buf.tseg1_min(),
+            tseg1_max: buf.tseg1_max(),
+            tseg2_min: buf.tseg2_min(),
+            tseg2_max: buf.tseg2_max(),
+            sjw_max: buf.sjw_max(),
+            brp_min: buf.brp_min(),
+            brp_max: buf.brp_max(),
+            brp_inc: buf.brp_inc(),
+        })
+    }
+}
+
+impl Emitable for CanBitTimingConst {
+    fn buffer_len(&self) -> usize {
+        CAN_BITTIMING_CONST_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanBitTimingConstBuffer::new(buffer);
+        let name = buffer.name_mut();
+        name.fill(0);
+        // Keep the trailing NULL
+        let name_len =
+            std::cmp::min(self.name.len(), CAN_BITTIMING_CONST_NAME_LEN - 1);
+        name[..name_len].copy_from_slice(&self.name.as_bytes()[..name_len]);
+        buffer.set_tseg1_min(self.tseg1_min);
+        buffer.set_tseg1_max(self.tseg1_max);
+        buffer.set_tseg2_min(self.tseg2_min);
+        buffer.set_tseg2_max(self.tseg2_max);
+        buffer.set_sjw_max(self.sjw_max);
+        buffer.set_brp_min(self.brp_min);
+        buffer.set_brp_max(self.brp_max);
+        buffer.set_brp_inc(self.brp_inc);
+    }
+}
+
+const CAN_STATE_ERROR_ACTIVE: u32 = 0;
+const CAN_STATE_ERROR_WARNING: u32 = 1;
+const CAN_STATE_ERROR_PASSIVE: u32 = 2;
+const CAN_STATE_BUS_OFF: u32 = 3;
+const CAN_STATE_STOPPED: u32 = 4;
+const CAN_STATE_SLEEPING: u32 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum CanState {
+    /// RX/TX error count < 96
+    #[default]
+    ErrorActive,
+    /// RX/TX error count < 128
+    ErrorWarning,
+    /// RX/TX error count < 256
+    ErrorPassive,
+    /// RX/TX error count >= 256
+    BusOff,
+    /// Device is stopped
+    Stopped,
+    /// Device is sleeping
+    Sleeping,
+    Other(u32),
+}
+
+impl From<u32> for CanState {
+    fn from(d: u32) -> Self {
+        match d {
+            CAN_STATE_ERROR_ACTIVE => Self::ErrorActive,
+            CAN_STATE_ERROR_WARNING => Self::ErrorWarning,
+            CAN_STATE_ERROR_PASSIVE => Self::ErrorPassive,
+            CAN_STATE_BUS_OFF => Self::BusOff,
+            CAN_STATE_STOPPED => Self::Stopped,
+            CAN_STATE_SLEEPING => Self::Sleeping,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<CanState> for u32 {
+    fn from(d: CanState) -> Self {
+        match d {
+            CanState::ErrorActive => CAN_STATE_ERROR_ACTIVE,
+            CanState::ErrorWarning => CAN_STATE_ERROR_WARNING,
+            CanState::ErrorPassive => CAN_STATE_ERROR_PASSIVE,
+            CanState::BusOff => CAN_STATE_BUS_OFF,
+            CanState::Stopped => CAN_STATE_STOPPED,
+            CanState::Sleeping => CAN_STATE_SLEEPING,
+            CanState::Other(value) => value,
+        }
+    }
+}
+
+const CAN_CTRLMODE_LOOPBACK: u32 = 0x01;
+const CAN_CTRLMODE_LISTENONLY: u32 = 0x02;
+const CAN_CTRLMODE_3_SAMPLES: u32 = 0x04;
+const CAN_CTRLMODE_ONE_SHOT: u32 = 0x08;
+const CAN_CTRLMODE_BERR_REPORTING: u32 = 0x10;
+const CAN_CTRLMODE_FD: u32 = 0x20;
+const CAN_CTRLMODE_PRESUME_ACK: u32 = 0x40;
+const CAN_CTRLMODE_FD_NON_ISO: u32 = 0x80;
+const CAN_CTRLMODE_CC_LEN8_DLC: u32 = 0x100;
+const CAN_CTRLMODE_TDC_AUTO: u32 = 0x200;
+const CAN_CTRLMODE_TDC_MANUAL: u32 = 0x400;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+    pub struct CanCtrlModeFlags: u32 {
+        const Loopback = CAN_CTRLMODE_LOOPBACK;
+        const ListenOnly = CAN_CTRLMODE_LISTENONLY;
+        /// Triple sampling mode
+        const TripleSampling = CAN_CTRLMODE_3_SAMPLES;
+        const OneShot = CAN_CTRLMODE_ONE_SHOT;
+        /// Bus-error reporting
+        const BerrReporting = CAN_CTRLMODE_BERR_REPORTING;
+        /// CAN FD mode
+        const Fd = CAN_CTRLMODE_FD;
+        /// Ignore missing CAN ACKs
+        const PresumeAck = CAN_CTRLMODE_PRESUME_ACK;
+        /// CAN FD in non-ISO mode
+        const FdNonIso = CAN_CTRLMODE_FD_NON_ISO;
+        /// Classic CAN DLC option
+        const CcLen8Dlc = CAN_CTRLMODE_CC_LEN8_DLC;
+        /// Transmitter Delay Compensation value is computed by hardware
+        const TdcAuto = CAN_CTRLMODE_TDC_AUTO;
+        /// Transmitter Delay Compensation value is set by user
+        const TdcManual = CAN_CTRLMODE_TDC_MANUAL;
+        const _ = !0;
+    }
+}
+
+const CAN_CTRLMODE_LEN: usize = 8;
+
+buffer!(CanCtrlModeBuffer(CAN_CTRLMODE_LEN) {
+    mask: (u32, 0..4),
+    flags: (u32, 4..8),
+});
+
+/// CAN controller mode (`struct can_ctrlmode`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct CanCtrlMode {
+    /// Flags in `flags` to change, ignored when dumping
+    pub mask: CanCtrlModeFlags,
+    pub flags: CanCtrlModeFlags,
+}
+
+impl<T: AsRef<[u8]>> Parseable<CanCtrlModeBuffer<T>> for CanCtrlMode {
+    type Error = DecodeError;
+    fn parse(buf: &CanCtrlModeBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mask: CanCtrlModeFlags::from_bits_retain(buf.mask()),
+            flags: CanCtrlModeFlags::from_bits_retain(buf.flags()),
+        })
+    }
+}
+
+impl Emitable for CanCtrlMode {
+    fn buffer_len(&self) -> usize {
+        CAN_CTRLMODE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanCtrlModeBuffer::new(buffer);
+        buffer.set_mask(self.mask.bits());
+        buffer.set_flags(self.flags.bits());
+    }
+}
+
+const CAN_BERR_COUNTER_LEN: usize = 4;
+
+buffer!(CanBerrCounterBuffer(CAN_BERR_COUNTER_LEN) {
+    txerr: (u16, 0..2),
+    rxerr: (u16, 2..4),
+});
+
+/// CAN bus error counters (`struct can_berr_counter`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct CanBerrCounter {
+    pub txerr: u16,
+    pub rxerr: u16,
+}
+
+impl<T: AsRef<[u8]>> Parseable<CanBerrCounterBuffer<T>> for CanBerrCounter {
+    type Error = DecodeError;
+    fn parse(buf: &CanBerrCounterBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            txerr: buf.txerr(),
+            rxerr: buf.rxerr(),
+        })
+    }
+}
+
+impl Emitable for CanBerrCounter {
+    fn buffer_len(&self) -> usize {
+        CAN_BERR_COUNTER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanBerrCounterBuffer::new(buffer);
+        buffer.set_txerr(self.txerr);
+        buffer.set_rxerr(self.rxerr);
+    }
+}
+
+const IFLA_CAN_TDC_TDCV_MIN: u16 = 1;
+const IFLA_CAN_TDC_TDCV_MAX: u16 = 2;
+const IFLA_CAN_TDC_TDCO_MIN: u16 = 3;
+const IFLA_CAN_TDC_TDCO_MAX: u16 = 4;
+const IFLA_CAN_TDC_TDCF_MIN: u16 = 5;
+const IFLA_CAN_TDC_TDCF_MAX: u16 = 6;
+const IFLA_CAN_TDC_TDCV: u16 = 7;
+const IFLA_CAN_TDC_TDCO: u16 = 8;
+const IFLA_CAN_TDC_TDCF: u16 = 9;
+
+/// Transmitter Delay Compensation of CAN FD (`IFLA_CAN_TDC`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum CanTdc {
+    TdcvMin(u32),
+    TdcvMax(u32),
+    TdcoMin(u32),
+    TdcoMax(u32),
+    TdcfMin(u32),
+    TdcfMax(u32),
+    /// Transmitter Delay Compensation Value
+    Tdcv(u32),
+    /// Transmitter Delay Compensation Offset
+    Tdco(u32),
+    /// Transmitter Delay Compensation Filter window
+    Tdcf(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for CanTdc {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(nla) => nla.value_len(),
+            _ => 4,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::TdcvMin(value)
+            | Self::TdcvMax(value)
+            | Self::TdcoMin(value)
+            | Self::TdcoMax(value)
+            | Self::TdcfMin(value)
+            | Self::TdcfMax(value)
+            | Self::Tdcv(value)
+            | Self::Tdco(value)
+            | Self::Tdcf(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::TdcvMin(_) => IFLA_CAN_TDC_TDCV_MIN,
+            Self::TdcvMax(_) => IFLA_CAN_TDC_TDCV_MAX,
+            Self::TdcoMin(_) => IFLA_CAN_TDC_TDCO_MIN,
+            Self::TdcoMax(_) => IFLA_CAN_TDC_TDCO_MAX,
+            Self::TdcfMin(_) => IFLA_CAN_TDC_TDCF_MIN,
+            Self::TdcfMax(_) => IFLA_CAN_TDC_TDCF_MAX,
+            Self::Tdcv(_) => IFLA_CAN_TDC_TDCV,
+            Self::Tdco(_) => IFLA_CAN_TDC_TDCO,
+            Self::Tdcf(_) => IFLA_CAN_TDC_TDCF,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for CanTdc {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_CAN_TDC_TDCV_MIN => Self::TdcvMin(parse_u32(payload)?),
+            IFLA_CAN_TDC_TDCV_MAX => Self::TdcvMax(parse_u32(payload)?),
+            IFLA_CAN_TDC_TDCO_MIN => Self::TdcoMin(parse_u32(payload)?),
+            IFLA_CAN_TDC_TDCO_MAX => Self::TdcoMax(parse_u32(payload)?),
+            IFLA_CAN_TDC_TDCF_MIN => Self::TdcfMin(parse_u32(payload)?),
+            IFLA_CAN_TDC_TDCF_MAX => Self::TdcfMax(parse_u32(payload)?),
+            IFLA_CAN_TDC_TDCV => Self::Tdcv(parse_u32(payload)?),
+            IFLA_CAN_TDC_TDCO => Self::Tdco(parse_u32(payload)?),
+            IFLA_CAN_TDC_TDCF => Self::Tdcf(parse_u32(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const IFLA_CAN_CTRLMODE_SUPPORTED: u16 = 1;
+
+/// Extended CAN controller mode information (`IFLA_CAN_CTRLMODE_EXT`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum CanCtrlModeExt {
+    /// Controller modes supported by the device
+    Supported(CanCtrlModeFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for CanCtrlModeExt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Supported(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Supported(value) => {
+                NativeEndian::write_u32(buffer, value.bits())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Supported(_) => IFLA_CAN_CTRLMODE_SUPPORTED,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for CanCtrlModeExt
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_CAN_CTRLMODE_SUPPORTED => Self::Supported(
+                CanCtrlModeFlags::from_bits_retain(parse_u32(payload)?),
+            ),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const CAN_DEVICE_STATS_LEN: usize = 24;
+
+buffer!(CanDeviceStatsBuffer(CAN_DEVICE_STATS_LEN) {
+    bus_error: (u32, 0..4),
+    error_warning: (u32, 4..8),
+    error_passive: (u32, 8..12),
+    bus_off: (u32, 12..16),
+    arbitration_lost: (u32, 16..20),
+    restarts: (u32, 20..24),
+});
+
+/// CAN device statistics reported in `IFLA_INFO_XSTATS`
+/// (`struct can_device_stats`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct CanDeviceStats {
+    /// Bus errors
+    pub bus_error: u32,
+    /// Changes to error warning state
+    pub error_warning: u32,
+    /// Changes to error passive state
+    pub error_passive: u32,
+    /// Changes to bus off state
+    pub bus_off: u32,
+    /// Arbitration lost errors
+    pub arbitration_lost: u32,
+    /// CAN controller re-starts
+    pub restarts: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<CanDeviceStatsBuffer<T>> for CanDeviceStats {
+    type Error = DecodeError;
+    fn parse(buf: &CanDeviceStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bus_error: buf.bus_error(),
+            error_warning: buf.error_warning(),
+            error_passive: buf.error_passive(),
+            bus_off: buf.bus_off(),
+            arbitration_lost: buf.arbitration_lost(),
+            restarts: buf.restarts(),
+        })
+    }
+}
+
+impl Emitable for CanDeviceStats {
+    fn buffer_len(&self) -> usize {
+        CAN_DEVICE_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = CanDeviceStatsBuffer::new(buffer);
+        buffer.set_bus_error(self.bus_error);
+        buffer.set_error_warning(self.error_warning);
+        buffer.set_error_passive(self.error_passive);
+        buffer.set_bus_off(self.bus_off);
+        buffer.set_arbitration_lost(self.arbitration_lost);
+        buffer.set_restarts(self.restarts);
+    }
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+use super::gre::gre_info;
+
+gre_info! {
+    /// Attributes of the `erspan` (ERSPAN over IPv4 GRE) link kind.
+    InfoErspan, ipv4, erspan
+}
+
+const ERSPAN_DIR_INGRESS: u8 = 0;
+const ERSPAN_DIR_EGRESS: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum ErspanDirection {
+    #[default]
+    Ingress,
+    Egress,
+    Other(u8),
+}
+
+impl From<u8> for ErspanDirection {
+    fn from(d: u8) -> Self {
+        match d {
+            ERSPAN_DIR_INGRESS => Self::Ingress,
+            ERSPAN_DIR_EGRESS => Self::Egress,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<ErspanDirection> for u8 {
+    fn from(d: ErspanDirection) -> Self {
+        match d {
+            ErspanDirection::Ingress => ERSPAN_DIR_INGRESS,
+            ErspanDirection::Egress => ERSPAN_DIR_EGRESS,
+            ErspanDirection::Other(value) => value,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+
+use super::gre::gre_info;
+
+gre_info! {
+    /// Attributes of the `ip6erspan` (ERSPAN over IPv6 GRE) link kind.
+    InfoErspan6, ipv6, erspan
+}
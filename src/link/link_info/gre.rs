@@ -1,42 +1,320 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::{
-    nla::{DefaultNla, Nla, NlaBuffer},
-    DecodeError, Parseable,
-};
-
-#[derive(Debug, PartialEq, Eq, Clone)]
-#[non_exhaustive]
-pub enum InfoGreTun {
-    Other(DefaultNla),
+pub(super) const IFLA_GRE_LINK: u16 = 1;
+pub(super) const IFLA_GRE_IFLAGS: u16 = 2;
+pub(super) const IFLA_GRE_OFLAGS: u16 = 3;
+pub(super) const IFLA_GRE_IKEY: u16 = 4;
+pub(super) const IFLA_GRE_OKEY: u16 = 5;
+pub(super) const IFLA_GRE_LOCAL: u16 = 6;
+pub(super) const IFLA_GRE_REMOTE: u16 = 7;
+pub(super) const IFLA_GRE_TTL: u16 = 8;
+pub(super) const IFLA_GRE_TOS: u16 = 9;
+pub(super) const IFLA_GRE_PMTUDISC: u16 = 10;
+pub(super) const IFLA_GRE_ENCAP_LIMIT: u16 = 11;
+pub(super) const IFLA_GRE_FLOWINFO: u16 = 12;
+pub(super) const IFLA_GRE_FLAGS: u16 = 13;
+pub(super) const IFLA_GRE_ENCAP_TYPE: u16 = 14;
+pub(super) const IFLA_GRE_ENCAP_FLAGS: u16 = 15;
+pub(super) const IFLA_GRE_ENCAP_SPORT: u16 = 16;
+pub(super) const IFLA_GRE_ENCAP_DPORT: u16 = 17;
+pub(super) const IFLA_GRE_COLLECT_METADATA: u16 = 18;
+pub(super) const IFLA_GRE_IGNORE_DF: u16 = 19;
+pub(super) const IFLA_GRE_FWMARK: u16 = 20;
+pub(super) const IFLA_GRE_ERSPAN_INDEX: u16 = 21;
+pub(super) const IFLA_GRE_ERSPAN_VER: u16 = 22;
+pub(super) const IFLA_GRE_ERSPAN_DIR: u16 = 23;
+pub(super) const IFLA_GRE_ERSPAN_HWID: u16 = 24;
+
+const GRE_CSUM: u16 = 0x8000;
+const GRE_ROUTING: u16 = 0x4000;
+const GRE_KEY: u16 = 0x2000;
+const GRE_SEQ: u16 = 0x1000;
+const GRE_STRICT: u16 = 0x0800;
+const GRE_REC: u16 = 0x0700;
+const GRE_ACK: u16 = 0x0080;
+const GRE_FLAGS: u16 = 0x0078;
+const GRE_VERSION: u16 = 0x0007;
+
+bitflags! {
+    /// Flags of GRE header used for `IFLA_GRE_IFLAGS` and `IFLA_GRE_OFLAGS`.
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+    pub struct GreFlags: u16 {
+        /// Checksum present.
+        const Csum = GRE_CSUM;
+        const Routing = GRE_ROUTING;
+        /// Key present.
+        const Key = GRE_KEY;
+        /// Sequence number present.
+        const Seq = GRE_SEQ;
+        const Strict = GRE_STRICT;
+        const Rec = GRE_REC;
+        const Ack = GRE_ACK;
+        const Flags = GRE_FLAGS;
+        const Version = GRE_VERSION;
+        const _ = !0;
+    }
 }
 
-impl Nla for InfoGreTun {
-    fn value_len(&self) -> usize {
-        match self {
-            Self::Other(nla) => nla.value_len(),
+/// Generates a GRE `IFLA_INFO_DATA` attribute enum holding the attributes
+/// shared by every GRE flavor plus the ones specific to the address family:
+/// `ipv4` adds `Tos` and `PathMtuDiscovery`, `ipv6` adds `EncapLimit`,
+/// `FlowInfo` and `Flags`. A trailing `erspan` adds the ERSPAN attributes.
+macro_rules! gre_info {
+    ($(#[$meta:meta])* $name:ident, ipv4 $(, $ext:ident)?) => {
+        gre_info!(@ext
+            [
+                $(#[$meta])* $name, ::std::net::Ipv4Addr,
+                $crate::ip::parse_ipv4_addr,
+            ]
+            [
+                Tos(u8) = IFLA_GRE_TOS, 1,
+                    |buffer, value| buffer[0] = *value,
+                    |payload| parse_u8(payload)?;
+                PathMtuDiscovery(bool) = IFLA_GRE_PMTUDISC, 1,
+                    |buffer, value| buffer[0] = *value as u8,
+                    |payload| parse_u8(payload)? > 0;
+            ]
+            $($ext)?
+        );
+    };
+    ($(#[$meta:meta])* $name:ident, ipv6 $(, $ext:ident)?) => {
+        gre_info!(@ext
+            [
+                $(#[$meta])* $name, ::std::net::Ipv6Addr,
+                $crate::ip::parse_ipv6_addr,
+            ]
+            [
+                EncapLimit(u8) = IFLA_GRE_ENCAP_LIMIT, 1,
+                    |buffer, value| buffer[0] = *value,
+                    |payload| parse_u8(payload)?;
+                FlowInfo(u32) = IFLA_GRE_FLOWINFO, 4,
+                    |buffer, value| BigEndian::write_u32(buffer, *value),
+                    |payload| parse_u32_be(payload)?;
+                Flags($crate::link::Ip6TunnelFlags) = IFLA_GRE_FLAGS, 4,
+                    |buffer, value| {
+                        NativeEndian::write_u32(buffer, value.bits())
+                    },
+                    |payload| $crate::link::Ip6TunnelFlags::from_bits_retain(
+                        parse_u32(payload)?,
+                    );
+            ]
+            $($ext)?
+        );
+    };
+    (@ext [$($head:tt)*] [$($vars:tt)*] erspan) => {
+        gre_info!(@impl
+            $($head)*
+            [
+                $($vars)*
+                /// ERSPAN type II session ID
+                ErspanIndex(u32) = IFLA_GRE_ERSPAN_INDEX, 4,
+                    |buffer, value| NativeEndian::write_u32(buffer, *value),
+                    |payload| parse_u32(payload)?;
+                /// ERSPAN version, 1 for type II and 2 for type III
+                ErspanVersion(u8) = IFLA_GRE_ERSPAN_VER, 1,
+                    |buffer, value| buffer[0] = *value,
+                    |payload| parse_u8(payload)?;
+                /// ERSPAN type III mirrored traffic direction
+                ErspanDirection($crate::link::ErspanDirection) =
+                    IFLA_GRE_ERSPAN_DIR, 1,
+                    |buffer, value| buffer[0] = (*value).into(),
+                    |payload| parse_u8(payload)?.into();
+                /// ERSPAN type III hardware ID
+                ErspanHardwareId(u16) = IFLA_GRE_ERSPAN_HWID, 2,
+                    |buffer, value| NativeEndian::write_u16(buffer, *value),
+                    |payload| parse_u16(payload)?;
+            ]
+        );
+    };
+    (@ext [$($head:tt)*] [$($vars:tt)*]) => {
+        gre_info!(@impl $($head)* [$($vars)*]);
+    };
+    (@impl
+        $(#[$meta:meta])* $name:ident, $addr:ty, $parse_addr:path,
+        [$(
+            $(#[$var_meta:meta])*
+            $var:ident($ty:ty) = $kind:ident, $len:expr,
+                |$emit_buf:ident, $emit_value:ident| $emit:expr,
+                |$parse_payload:ident| $parse:expr;
+        )*]
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Clone)]
+        #[non_exhaustive]
+        pub enum $name {
+            Link(u32),
+            IFlags($crate::link::GreFlags),
+            OFlags($crate::link::GreFlags),
+            IKey(u32),
+            OKey(u32),
+            Local($addr),
+            Remote($addr),
+            Ttl(u8),
+            EncapType($crate::link::TunnelEncapType),
+            EncapFlags($crate::link::TunnelEncapFlags),
+            EncapSourcePort(u16),
+            EncapDestinationPort(u16),
+            CollectMetadata,
+            IgnoreDf(bool),
+            FwMark(u32),
+            $(
+                $(#[$var_meta])*
+                $var($ty),
+            )*
+            Other(::netlink_packet_utils::nla::DefaultNla),
         }
-    }
 
-    fn emit_value(&self, buffer: &mut [u8]) {
-        match self {
-            Self::Other(nla) => nla.emit_value(buffer),
+        impl ::netlink_packet_utils::nla::Nla for $name {
+            fn value_len(&self) -> usize {
+                match self {
+                    Self::Link(_)
+                    | Self::IKey(_)
+                    | Self::OKey(_)
+                    | Self::FwMark(_) => 4,
+                    Self::Local(value) | Self::Remote(value) => {
+                        value.octets().len()
+                    }
+                    Self::IFlags(_)
+                    | Self::OFlags(_)
+                    | Self::EncapType(_)
+                    | Self::EncapFlags(_)
+                    | Self::EncapSourcePort(_)
+                    | Self::EncapDestinationPort(_) => 2,
+                    Self::Ttl(_) | Self::IgnoreDf(_) => 1,
+                    Self::CollectMetadata => 0,
+                    $(Self::$var(_) => $len,)*
+                    Self::Other(nla) => nla.value_len(),
+                }
+            }
+
+            fn emit_value(&self, buffer: &mut [u8]) {
+                use ::byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+                match self {
+                    Self::Link(value) | Self::FwMark(value) => {
+                        NativeEndian::write_u32(buffer, *value)
+                    }
+                    Self::IFlags(value) | Self::OFlags(value) => {
+                        BigEndian::write_u16(buffer, value.bits())
+                    }
+                    Self::IKey(value) | Self::OKey(value) => {
+                        BigEndian::write_u32(buffer, *value)
+                    }
+                    Self::Local(value) | Self::Remote(value) => {
+                        buffer.copy_from_slice(&value.octets())
+                    }
+                    Self::Ttl(value) => buffer[0] = *value,
+                    Self::IgnoreDf(value) => buffer[0] = *value as u8,
+                    Self::EncapType(value) => {
+                        NativeEndian::write_u16(buffer, (*value).into())
+                    }
+                    Self::EncapFlags(value) => {
+                        NativeEndian::write_u16(buffer, value.bits())
+                    }
+                    Self::EncapSourcePort(value)
+                    | Self::EncapDestinationPort(value) => {
+                        BigEndian::write_u16(buffer, *value)
+                    }
+                    Self::CollectMetadata => (),
+                    $(
+                        Self::$var($emit_value) => {
+                            let $emit_buf = buffer;
+                            $emit
+                        }
+                    )*
+                    Self::Other(nla) => nla.emit_value(buffer),
+                }
+            }
+
+            fn kind(&self) -> u16 {
+                use $crate::link::link_info::gre::*;
+
+                match self {
+                    Self::Link(_) => IFLA_GRE_LINK,
+                    Self::IFlags(_) => IFLA_GRE_IFLAGS,
+                    Self::OFlags(_) => IFLA_GRE_OFLAGS,
+                    Self::IKey(_) => IFLA_GRE_IKEY,
+                    Self::OKey(_) => IFLA_GRE_OKEY,
+                    Self::Local(_) => IFLA_GRE_LOCAL,
+                    Self::Remote(_) => IFLA_GRE_REMOTE,
+                    Self::Ttl(_) => IFLA_GRE_TTL,
+                    Self::EncapType(_) => IFLA_GRE_ENCAP_TYPE,
+                    Self::EncapFlags(_) => IFLA_GRE_ENCAP_FLAGS,
+                    Self::EncapSourcePort(_) => IFLA_GRE_ENCAP_SPORT,
+                    Self::EncapDestinationPort(_) => IFLA_GRE_ENCAP_DPORT,
+                    Self::CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+                    Self::IgnoreDf(_) => IFLA_GRE_IGNORE_DF,
+                    Self::FwMark(_) => IFLA_GRE_FWMARK,
+                    $(Self::$var(_) => $kind,)*
+                    Self::Other(nla) => nla.kind(),
+                }
+            }
         }
-    }
 
-    fn kind(&self) -> u16 {
-        match self {
-            Self::Other(nla) => nla.kind(),
+        impl<'a, T: AsRef<[u8]> + ?Sized>
+            ::netlink_packet_utils::Parseable<
+                ::netlink_packet_utils::nla::NlaBuffer<&'a T>,
+            > for $name
+        {
+            type Error = ::netlink_packet_utils::DecodeError;
+            fn parse(
+                buf: &::netlink_packet_utils::nla::NlaBuffer<&'a T>,
+            ) -> Result<Self, ::netlink_packet_utils::DecodeError> {
+                use ::netlink_packet_utils::nla::DefaultNla;
+                use ::netlink_packet_utils::parsers::{
+                    parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u8,
+                };
+                use $crate::link::link_info::gre::*;
+                use $crate::link::TunnelEncapFlags;
+
+                let payload = buf.value();
+                Ok(match buf.kind() {
+                    IFLA_GRE_LINK => Self::Link(parse_u32(payload)?),
+                    IFLA_GRE_IFLAGS => Self::IFlags(GreFlags::from_bits_retain(
+                        parse_u16_be(payload)?,
+                    )),
+                    IFLA_GRE_OFLAGS => Self::OFlags(GreFlags::from_bits_retain(
+                        parse_u16_be(payload)?,
+                    )),
+                    IFLA_GRE_IKEY => Self::IKey(parse_u32_be(payload)?),
+                    IFLA_GRE_OKEY => Self::OKey(parse_u32_be(payload)?),
+                    IFLA_GRE_LOCAL => Self::Local($parse_addr(payload)?),
+                    IFLA_GRE_REMOTE => Self::Remote($parse_addr(payload)?),
+                    IFLA_GRE_TTL => Self::Ttl(parse_u8(payload)?),
+                    IFLA_GRE_ENCAP_TYPE => {
+                        Self::EncapType(parse_u16(payload)?.into())
+                    }
+                    IFLA_GRE_ENCAP_FLAGS => Self::EncapFlags(
+                        TunnelEncapFlags::from_bits_retain(parse_u16(payload)?),
+                    ),
+                    IFLA_GRE_ENCAP_SPORT => {
+                        Self::EncapSourcePort(parse_u16_be(payload)?)
+                    }
+                    IFLA_GRE_ENCAP_DPORT => {
+                        Self::EncapDestinationPort(parse_u16_be(payload)?)
+                    }
+                    IFLA_GRE_COLLECT_METADATA => Self::CollectMetadata,
+                    IFLA_GRE_IGNORE_DF => {
+                        Self::IgnoreDf(parse_u8(payload)? > 0)
+                    }
+                    IFLA_GRE_FWMARK => Self::FwMark(parse_u32(payload)?),
+                    $(
+                        $kind => {
+                            let $parse_payload = payload;
+                            Self::$var($parse)
+                        }
+                    )*
+                    _kind => Self::Other(DefaultNla::parse(buf)?),
+                })
+            }
         }
-    }
+    };
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun {
-    type Error = DecodeError;
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
-        Ok(match buf.kind() {
-            _kind => Self::Other(DefaultNla::parse(buf)?),
-        })
-    }
+pub(super) use gre_info;
+
+gre_info! {
+    /// Attributes of the `gre` (IPv4 GRE tunnel) link kind.
+    InfoGreTun, ipv4
 }
@@ -1,31 +1,117 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
     DecodeError, Parseable,
 };
 
+use crate::ip::{parse_ipv4_addr, parse_ipv6_addr};
+
+const IFLA_GTP_FD0: u16 = 1;
+const IFLA_GTP_FD1: u16 = 2;
+const IFLA_GTP_PDP_HASHSIZE: u16 = 3;
+const IFLA_GTP_ROLE: u16 = 4;
+const IFLA_GTP_CREATE_SOCKETS: u16 = 5;
+const IFLA_GTP_RESTART_COUNT: u16 = 6;
+const IFLA_GTP_LOCAL: u16 = 7;
+const IFLA_GTP_LOCAL6: u16 = 8;
+
+const GTP_ROLE_GGSN: u32 = 0;
+const GTP_ROLE_SGSN: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum GtpRole {
+    /// Gateway GPRS Support Node, the network side of the tunnel.
+    #[default]
+    Ggsn,
+    /// Serving GPRS Support Node, the user equipment side of the tunnel.
+    Sgsn,
+    Other(u32),
+}
+
+impl From<u32> for GtpRole {
+    fn from(d: u32) -> Self {
+        match d {
+            GTP_ROLE_GGSN => Self::Ggsn,
+            GTP_ROLE_SGSN => Self::Sgsn,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<GtpRole> for u32 {
+    fn from(d: GtpRole) -> Self {
+        match d {
+            GtpRole::Ggsn => GTP_ROLE_GGSN,
+            GtpRole::Sgsn => GTP_ROLE_SGSN,
+            GtpRole::Other(value) => value,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoGtp {
+    /// File descriptor of the UDP socket for GTPv0.
+    Fd0(u32),
+    /// File descriptor of the UDP socket for GTPv1-U.
+    Fd1(u32),
+    PdpHashsize(u32),
+    Role(GtpRole),
+    /// Let kernel create the UDP sockets instead of using
+    /// [`InfoGtp::Fd0`] and [`InfoGtp::Fd1`].
+    CreateSockets(bool),
+    RestartCount(u8),
+    Local(Ipv4Addr),
+    Local6(Ipv6Addr),
     Other(DefaultNla),
 }
 
 impl Nla for InfoGtp {
     fn value_len(&self) -> usize {
         match self {
+            Self::Fd0(_)
+            | Self::Fd1(_)
+            | Self::PdpHashsize(_)
+            | Self::Role(_)
+            | Self::Local(_) => 4,
+            Self::CreateSockets(_) | Self::RestartCount(_) => 1,
+            Self::Local6(_) => 16,
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Fd0(value) | Self::Fd1(value) | Self::PdpHashsize(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Role(value) => {
+                NativeEndian::write_u32(buffer, (*value).into())
+            }
+            Self::CreateSockets(value) => buffer[0] = *value as u8,
+            Self::RestartCount(value) => buffer[0] = *value,
+            Self::Local(value) => buffer.copy_from_slice(&value.octets()),
+            Self::Local6(value) => buffer.copy_from_slice(&value.octets()),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Fd0(_) => IFLA_GTP_FD0,
+            Self::Fd1(_) => IFLA_GTP_FD1,
+            Self::PdpHashsize(_) => IFLA_GTP_PDP_HASHSIZE,
+            Self::Role(_) => IFLA_GTP_ROLE,
+            Self::CreateSockets(_) => IFLA_GTP_CREATE_SOCKETS,
+            Self::RestartCount(_) => IFLA_GTP_RESTART_COUNT,
+            Self::Local(_) => IFLA_GTP_LOCAL,
+            Self::Local6(_) => IFLA_GTP_LOCAL6,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -34,8 +120,18 @@ impl Nla for InfoGtp {
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGtp {
     type Error = DecodeError;
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
+            IFLA_GTP_FD0 => Self::Fd0(parse_u32(payload)?),
+            IFLA_GTP_FD1 => Self::Fd1(parse_u32(payload)?),
+            IFLA_GTP_PDP_HASHSIZE => Self::PdpHashsize(parse_u32(payload)?),
+            IFLA_GTP_ROLE => Self::Role(parse_u32(payload)?.into()),
+            IFLA_GTP_CREATE_SOCKETS => {
+                Self::CreateSockets(parse_u8(payload)? > 0)
+            }
+            IFLA_GTP_RESTART_COUNT => Self::RestartCount(parse_u8(payload)?),
+            IFLA_GTP_LOCAL => Self::Local(parse_ipv4_addr(payload)?),
+            IFLA_GTP_LOCAL6 => Self::Local6(parse_ipv6_addr(payload)?),
             _kind => Self::Other(DefaultNla::parse(buf)?),
         })
     }
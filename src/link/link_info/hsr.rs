@@ -17,6 +17,7 @@ const IFLA_HSR_SUPERVISION_ADDR: u16 = 4;
 const IFLA_HSR_SEQ_NR: u16 = 5;
 const IFLA_HSR_VERSION: u16 = 6;
 const IFLA_HSR_PROTOCOL: u16 = 7;
+const IFLA_HSR_INTERLINK: u16 = 8;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -28,6 +29,8 @@ pub enum InfoHsr {
     Version(u8),
     SeqNr(u16),
     Protocol(HsrProtocol),
+    /// Interface index of the interlink port connecting to a RedBox
+    Interlink(u32),
     Other(DefaultNla),
 }
 
@@ -36,7 +39,7 @@ impl Nla for InfoHsr {
         use self::InfoHsr::*;
         match self {
             SupervisionAddr(_) => 6,
-            Port1(_) | Port2(_) => 4,
+            Port1(_) | Port2(_) | Interlink(_) => 4,
             SeqNr(_) => 2,
             MulticastSpec(_) | Version(_) | Protocol(_) => 1,
             Other(nla) => nla.value_len(),
@@ -46,7 +49,7 @@ impl Nla for InfoHsr {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::InfoHsr::*;
         match self {
-            Port1(value) | Port2(value) => {
+            Port1(value) | Port2(value) | Interlink(value) => {
                 NativeEndian::write_u32(buffer, *value)
             }
             MulticastSpec(value) | Version(value) => buffer[0] = *value,
@@ -67,6 +70,7 @@ impl Nla for InfoHsr {
             SeqNr(_) => IFLA_HSR_SEQ_NR,
             Version(_) => IFLA_HSR_VERSION,
             Protocol(_) => IFLA_HSR_PROTOCOL,
+            Interlink(_) => IFLA_HSR_INTERLINK,
             Other(nla) => nla.kind(),
         }
     }
@@ -85,6 +89,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoHsr {
             IFLA_HSR_SEQ_NR => SeqNr(parse_u16(payload)?),
             IFLA_HSR_VERSION => Version(parse_u8(payload)?),
             IFLA_HSR_PROTOCOL => Protocol(parse_u8(payload)?.into()),
+            IFLA_HSR_INTERLINK => Interlink(parse_u32(payload)?),
             _kind => Other(DefaultNla::parse(buf)?),
         })
     }
@@ -6,10 +6,12 @@ use netlink_packet_utils::{
 };
 
 use super::super::{
-    InfoBond, InfoBridge, InfoGeneve, InfoGreTap, InfoGreTap6, InfoGreTun,
-    InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan, InfoIpVtap, InfoIpoib, InfoKind,
-    InfoMacSec, InfoMacVlan, InfoMacVtap, InfoSitTun, InfoTun, InfoVeth,
-    InfoVlan, InfoVrf, InfoVti, InfoVxlan, InfoXfrm,
+    InfoAmt, InfoBareUdp, InfoBond, InfoBridge, InfoCan, InfoErspan,
+    InfoErspan6, InfoGeneve, InfoGreTap, InfoGreTap6, InfoGreTun, InfoGreTun6,
+    InfoGtp, InfoHsr, InfoIp6Tun, InfoIpTun, InfoIpVlan, InfoIpVtap, InfoIpoib,
+    InfoKind, InfoMacSec, InfoMacVlan, InfoMacVtap, InfoNetkit, InfoSitTun,
+    InfoTun, InfoVeth, InfoVlan, InfoVrf, InfoVti, InfoVti6, InfoVxcan,
+    InfoVxlan, InfoXfrm,
 };
 
 const IFLA_INFO_DATA: u16 = 2;
@@ -29,10 +31,13 @@ pub enum InfoData {
     MacVtap(Vec<InfoMacVtap>),
     GreTap(Vec<InfoGreTap>),
     GreTap6(Vec<InfoGreTap6>),
+    IpTun(Vec<InfoIpTun>),
     SitTun(Vec<InfoSitTun>),
+    Ip6Tun(Vec<InfoIp6Tun>),
     GreTun(Vec<InfoGreTun>),
     GreTun6(Vec<InfoGreTun6>),
     Vti(Vec<InfoVti>),
+    Vti6(Vec<InfoVti6>),
     Vrf(Vec<InfoVrf>),
     Gtp(Vec<InfoGtp>),
     Ipoib(Vec<InfoIpoib>),
@@ -40,6 +45,13 @@ pub enum InfoData {
     MacSec(Vec<InfoMacSec>),
     Hsr(Vec<InfoHsr>),
     Geneve(Vec<InfoGeneve>),
+    Can(Vec<InfoCan>),
+    Vxcan(InfoVxcan),
+    Netkit(Vec<InfoNetkit>),
+    BareUdp(Vec<InfoBareUdp>),
+    Amt(Vec<InfoAmt>),
+    Erspan(Vec<InfoErspan>),
+    Erspan6(Vec<InfoErspan6>),
     Other(Vec<u8>),
 }
 
@@ -63,12 +75,22 @@ impl Nla for InfoData {
             Self::Tun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTap(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTap6(nlas) => nlas.as_slice().buffer_len(),
+            Self::IpTun(nlas) => nlas.as_slice().buffer_len(),
             Self::SitTun(nlas) => nlas.as_slice().buffer_len(),
+            Self::Ip6Tun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTun6(nlas) => nlas.as_slice().buffer_len(),
             Self::Vti(nlas) => nlas.as_slice().buffer_len(),
+            Self::Vti6(nlas) => nlas.as_slice().buffer_len(),
             Self::Gtp(nlas) => nlas.as_slice().buffer_len(),
             Self::Geneve(nlas) => nlas.as_slice().buffer_len(),
+            Self::Can(nlas) => nlas.as_slice().buffer_len(),
+            Self::Vxcan(msg) => msg.buffer_len(),
+            Self::Netkit(nlas) => nlas.as_slice().buffer_len(),
+            Self::BareUdp(nlas) => nlas.as_slice().buffer_len(),
+            Self::Amt(nlas) => nlas.as_slice().buffer_len(),
+            Self::Erspan(nlas) => nlas.as_slice().buffer_len(),
+            Self::Erspan6(nlas) => nlas.as_slice().buffer_len(),
             Self::Other(v) => v.len(),
         }
     }
@@ -92,12 +114,22 @@ impl Nla for InfoData {
             Self::Tun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTap(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTap6(nlas) => nlas.as_slice().emit(buffer),
+            Self::IpTun(nlas) => nlas.as_slice().emit(buffer),
             Self::SitTun(nlas) => nlas.as_slice().emit(buffer),
+            Self::Ip6Tun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTun6(nlas) => nlas.as_slice().emit(buffer),
             Self::Vti(nlas) => nlas.as_slice().emit(buffer),
+            Self::Vti6(nlas) => nlas.as_slice().emit(buffer),
             Self::Gtp(nlas) => nlas.as_slice().emit(buffer),
             Self::Geneve(nlas) => nlas.as_slice().emit(buffer),
+            Self::Can(nlas) => nlas.as_slice().emit(buffer),
+            Self::Vxcan(msg) => msg.emit(buffer),
+            Self::Netkit(nlas) => nlas.as_slice().emit(buffer),
+            Self::BareUdp(nlas) => nlas.as_slice().emit(buffer),
+            Self::Amt(nlas) => nlas.as_slice().emit(buffer),
+            Self::Erspan(nlas) => nlas.as_slice().emit(buffer),
+            Self::Erspan6(nlas) => nlas.as_slice().emit(buffer),
             Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
         }
     }
@@ -217,6 +249,15 @@ impl InfoData {
                 }
                 InfoData::GreTap6(v)
             }
+            InfoKind::IpTun => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoIpTun::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::IpTun(v)
+            }
             InfoKind::SitTun => {
                 let mut v = Vec::new();
                 for nla in NlasIterator::new(payload) {
@@ -226,6 +267,15 @@ impl InfoData {
                 }
                 InfoData::SitTun(v)
             }
+            InfoKind::Ip6Tun => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoIp6Tun::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Ip6Tun(v)
+            }
             InfoKind::GreTun => {
                 let mut v = Vec::new();
                 for nla in NlasIterator::new(payload) {
@@ -253,6 +303,15 @@ impl InfoData {
                 }
                 InfoData::Vti(v)
             }
+            InfoKind::Vti6 => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoVti6::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Vti6(v)
+            }
             InfoKind::Vrf => {
                 let mut v = Vec::new();
                 for nla in NlasIterator::new(payload) {
@@ -316,6 +375,65 @@ impl InfoData {
                 }
                 InfoData::Geneve(v)
             }
+            InfoKind::Can => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoCan::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Can(v)
+            }
+            InfoKind::Vxcan => {
+                let nla_buf = NlaBuffer::new_checked(&payload)?;
+                let parsed = InfoVxcan::parse(&nla_buf)?;
+                InfoData::Vxcan(parsed)
+            }
+            InfoKind::Netkit => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoNetkit::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Netkit(v)
+            }
+            InfoKind::BareUdp => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoBareUdp::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::BareUdp(v)
+            }
+            InfoKind::Amt => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoAmt::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Amt(v)
+            }
+            InfoKind::Erspan => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoErspan::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Erspan(v)
+            }
+            InfoKind::Erspan6 => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoErspan6::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Erspan6(v)
+            }
             _ => InfoData::Other(payload.to_vec()),
         })
     }
@@ -8,7 +8,7 @@ use netlink_packet_utils::{
 
 use super::{
     super::{InfoBondPort, InfoBridgePort},
-    InfoVrf,
+    InfoVrfPort,
 };
 
 const BOND: &str = "bond";
@@ -89,8 +89,6 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoPortKind {
     }
 }
 
-pub type InfoVrfPort = InfoVrf;
-
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoPortData {
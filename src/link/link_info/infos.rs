@@ -31,9 +31,11 @@ const GRETAP: &str = "gretap";
 const IP6GRETAP: &str = "ip6gretap";
 const IPIP: &str = "ipip";
 const SIT: &str = "sit";
+const IP6TNL: &str = "ip6tnl";
 const GRE: &str = "gre";
 const IP6GRE: &str = "ip6gre";
 const VTI: &str = "vti";
+const VTI6: &str = "vti6";
 const VRF: &str = "vrf";
 const GTP: &str = "gtp";
 const IPOIB: &str = "ipoib";
@@ -42,6 +44,14 @@ const XFRM: &str = "xfrm";
 const MACSEC: &str = "macsec";
 const HSR: &str = "hsr";
 const GENEVE: &str = "geneve";
+const CAN: &str = "can";
+const VCAN: &str = "vcan";
+const VXCAN: &str = "vxcan";
+const NETKIT: &str = "netkit";
+const BAREUDP: &str = "bareudp";
+const AMT: &str = "amt";
+const ERSPAN: &str = "erspan";
+const IP6ERSPAN: &str = "ip6erspan";
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -186,9 +196,11 @@ pub enum InfoKind {
     GreTap6,
     IpTun,
     SitTun,
+    Ip6Tun,
     GreTun,
     GreTun6,
     Vti,
+    Vti6,
     Vrf,
     Gtp,
     Ipoib,
@@ -197,6 +209,14 @@ pub enum InfoKind {
     MacSec,
     Hsr,
     Geneve,
+    Can,
+    Vcan,
+    Vxcan,
+    Netkit,
+    BareUdp,
+    Amt,
+    Erspan,
+    Erspan6,
     Other(String),
 }
 
@@ -223,9 +243,11 @@ impl std::fmt::Display for InfoKind {
                 Self::GreTap6 => IP6GRETAP,
                 Self::IpTun => IPIP,
                 Self::SitTun => SIT,
+                Self::Ip6Tun => IP6TNL,
                 Self::GreTun => GRE,
                 Self::GreTun6 => IP6GRE,
                 Self::Vti => VTI,
+                Self::Vti6 => VTI6,
                 Self::Vrf => VRF,
                 Self::Gtp => GTP,
                 Self::Ipoib => IPOIB,
@@ -234,6 +256,14 @@ impl std::fmt::Display for InfoKind {
                 Self::MacSec => MACSEC,
                 Self::Hsr => HSR,
                 Self::Geneve => GENEVE,
+                Self::Can => CAN,
+                Self::Vcan => VCAN,
+                Self::Vxcan => VXCAN,
+                Self::Netkit => NETKIT,
+                Self::BareUdp => BAREUDP,
+                Self::Amt => AMT,
+                Self::Erspan => ERSPAN,
+                Self::Erspan6 => IP6ERSPAN,
                 Self::Other(s) => s.as_str(),
             }
         )
@@ -260,9 +290,11 @@ impl Nla for InfoKind {
             Self::GreTap6 => IP6GRETAP.len(),
             Self::IpTun => IPIP.len(),
             Self::SitTun => SIT.len(),
+            Self::Ip6Tun => IP6TNL.len(),
             Self::GreTun => GRE.len(),
             Self::GreTun6 => IP6GRE.len(),
             Self::Vti => VTI.len(),
+            Self::Vti6 => VTI6.len(),
             Self::Vrf => VRF.len(),
             Self::Gtp => GTP.len(),
             Self::Ipoib => IPOIB.len(),
@@ -271,6 +303,14 @@ impl Nla for InfoKind {
             Self::MacSec => MACSEC.len(),
             Self::Hsr => HSR.len(),
             Self::Geneve => GENEVE.len(),
+            Self::Can => CAN.len(),
+            Self::Vcan => VCAN.len(),
+            Self::Vxcan => VXCAN.len(),
+            Self::Netkit => NETKIT.len(),
+            Self::BareUdp => BAREUDP.len(),
+            Self::Amt => AMT.len(),
+            Self::Erspan => ERSPAN.len(),
+            Self::Erspan6 => IP6ERSPAN.len(),
             Self::Other(s) => s.len(),
         };
         len + 1
@@ -317,9 +357,11 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             IP6GRETAP => Self::GreTap6,
             IPIP => Self::IpTun,
             SIT => Self::SitTun,
+            IP6TNL => Self::Ip6Tun,
             GRE => Self::GreTun,
             IP6GRE => Self::GreTun6,
             VTI => Self::Vti,
+            VTI6 => Self::Vti6,
             VRF => Self::Vrf,
             GTP => Self::Gtp,
             IPOIB => Self::Ipoib,
@@ -328,6 +370,14 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             XFRM => Self::Xfrm,
             HSR => Self::Hsr,
             GENEVE => Self::Geneve,
+            CAN => Self::Can,
+            VCAN => Self::Vcan,
+            VXCAN => Self::Vxcan,
+            NETKIT => Self::Netkit,
+            BAREUDP => Self::BareUdp,
+            AMT => Self::Amt,
+            ERSPAN => Self::Erspan,
+            IP6ERSPAN => Self::Erspan6,
             _ => Self::Other(s),
         })
     }
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
+    DecodeError, Parseable,
+};
+
+use super::ipip::{
+    IFLA_IPTUN_COLLECT_METADATA, IFLA_IPTUN_ENCAP_DPORT,
+    IFLA_IPTUN_ENCAP_FLAGS, IFLA_IPTUN_ENCAP_LIMIT, IFLA_IPTUN_ENCAP_SPORT,
+    IFLA_IPTUN_ENCAP_TYPE, IFLA_IPTUN_FLAGS, IFLA_IPTUN_FLOWINFO,
+    IFLA_IPTUN_FWMARK, IFLA_IPTUN_LINK, IFLA_IPTUN_LOCAL, IFLA_IPTUN_PROTO,
+    IFLA_IPTUN_REMOTE, IFLA_IPTUN_TTL,
+};
+use super::{Ip6TunnelFlags, TunnelEncapFlags, TunnelEncapType};
+use crate::ip::parse_ipv6_addr;
+use crate::IpProtocol;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoIp6Tun {
+    Link(u32),
+    Local(Ipv6Addr),
+    Remote(Ipv6Addr),
+    /// Hop limit
+    Ttl(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    Flags(Ip6TunnelFlags),
+    Protocol(IpProtocol),
+    FwMark(u32),
+    EncapType(TunnelEncapType),
+    EncapFlags(TunnelEncapFlags),
+    EncapSourcePort(u16),
+    EncapDestinationPort(u16),
+    CollectMetadata,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoIp6Tun {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Local(_) | Self::Remote(_) => 16,
+            Self::Link(_)
+            | Self::FlowInfo(_)
+            | Self::Flags(_)
+            | Self::FwMark(_) => 4,
+            Self::EncapType(_)
+            | Self::EncapFlags(_)
+            | Self::EncapSourcePort(_)
+            | Self::EncapDestinationPort(_) => 2,
+            Self::Ttl(_) | Self::EncapLimit(_) | Self::Protocol(_) => 1,
+            Self::CollectMetadata => 0,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link(value) | Self::FwMark(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Ttl(value) | Self::EncapLimit(value) => buffer[0] = *value,
+            Self::FlowInfo(value) => BigEndian::write_u32(buffer, *value),
+            Self::Flags(value) => NativeEndian::write_u32(buffer, value.bits()),
+            Self::Protocol(value) => buffer[0] = i32::from(*value) as u8,
+            Self::EncapType(value) => {
+                NativeEndian::write_u16(buffer, (*value).into())
+            }
+            Self::EncapFlags(value) => {
+                NativeEndian::write_u16(buffer, value.bits())
+            }
+            Self::EncapSourcePort(value)
+            | Self::EncapDestinationPort(value) => {
+                BigEndian::write_u16(buffer, *value)
+            }
+            Self::CollectMetadata => (),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link(_) => IFLA_IPTUN_LINK,
+            Self::Local(_) => IFLA_IPTUN_LOCAL,
+            Self::Remote(_) => IFLA_IPTUN_REMOTE,
+            Self::Ttl(_) => IFLA_IPTUN_TTL,
+            Self::EncapLimit(_) => IFLA_IPTUN_ENCAP_LIMIT,
+            Self::FlowInfo(_) => IFLA_IPTUN_FLOWINFO,
+            Self::Flags(_) => IFLA_IPTUN_FLAGS,
+            Self::Protocol(_) => IFLA_IPTUN_PROTO,
+            Self::FwMark(_) => IFLA_IPTUN_FWMARK,
+            Self::EncapType(_) => IFLA_IPTUN_ENCAP_TYPE,
+            Self::EncapFlags(_) => IFLA_IPTUN_ENCAP_FLAGS,
+            Self::EncapSourcePort(_) => IFLA_IPTUN_ENCAP_SPORT,
+            Self::EncapDestinationPort(_) => IFLA_IPTUN_ENCAP_DPORT,
+            Self::CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIp6Tun {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPTUN_LINK => Self::Link(parse_u32(payload)?),
+            IFLA_IPTUN_LOCAL => Self::Local(parse_ipv6_addr(payload)?),
+            IFLA_IPTUN_REMOTE => Self::Remote(parse_ipv6_addr(payload)?),
+            IFLA_IPTUN_TTL => Self::Ttl(parse_u8(payload)?),
+            IFLA_IPTUN_ENCAP_LIMIT => Self::EncapLimit(parse_u8(payload)?),
+            IFLA_IPTUN_FLOWINFO => Self::FlowInfo(parse_u32_be(payload)?),
+            IFLA_IPTUN_FLAGS => Self::Flags(Ip6TunnelFlags::from_bits_retain(
+                parse_u32(payload)?,
+            )),
+            IFLA_IPTUN_PROTO => {
+                Self::Protocol(IpProtocol::from(parse_u8(payload)? as i32))
+            }
+            IFLA_IPTUN_FWMARK => Self::FwMark(parse_u32(payload)?),
+            IFLA_IPTUN_ENCAP_TYPE => {
+                Self::EncapType(parse_u16(payload)?.into())
+            }
+            IFLA_IPTUN_ENCAP_FLAGS => Self::EncapFlags(
+                TunnelEncapFlags::from_bits_retain(parse_u16(payload)?),
+            ),
+            IFLA_IPTUN_ENCAP_SPORT => {
+                Self::EncapSourcePort(parse_u16_be(payload)?)
+            }
+            IFLA_IPTUN_ENCAP_DPORT => {
+                Self::EncapDestinationPort(parse_u16_be(payload)?)
+            }
+            IFLA_IPTUN_COLLECT_METADATA => Self::CollectMetadata,
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u8},
+    DecodeError, Parseable,
+};
+
+use super::{TunnelEncapFlags, TunnelEncapType};
+use crate::ip::parse_ipv4_addr;
+use crate::IpProtocol;
+
+pub(super) const IFLA_IPTUN_LINK: u16 = 1;
+pub(super) const IFLA_IPTUN_LOCAL: u16 = 2;
+pub(super) const IFLA_IPTUN_REMOTE: u16 = 3;
+pub(super) const IFLA_IPTUN_TTL: u16 = 4;
+pub(super) const IFLA_IPTUN_TOS: u16 = 5;
+pub(super) const IFLA_IPTUN_ENCAP_LIMIT: u16 = 6;
+pub(super) const IFLA_IPTUN_FLOWINFO: u16 = 7;
+pub(super) const IFLA_IPTUN_FLAGS: u16 = 8;
+pub(super) const IFLA_IPTUN_PROTO: u16 = 9;
+pub(super) const IFLA_IPTUN_PMTUDISC: u16 = 10;
+pub(super) const IFLA_IPTUN_6RD_PREFIX: u16 = 11;
+pub(super) const IFLA_IPTUN_6RD_RELAY_PREFIX: u16 = 12;
+pub(super) const IFLA_IPTUN_6RD_PREFIXLEN: u16 = 13;
+pub(super) const IFLA_IPTUN_6RD_RELAY_PREFIXLEN: u16 = 14;
+pub(super) const IFLA_IPTUN_ENCAP_TYPE: u16 = 15;
+pub(super) const IFLA_IPTUN_ENCAP_FLAGS: u16 = 16;
+pub(super) const IFLA_IPTUN_ENCAP_SPORT: u16 = 17;
+pub(super) const IFLA_IPTUN_ENCAP_DPORT: u16 = 18;
+pub(super) const IFLA_IPTUN_COLLECT_METADATA: u16 = 19;
+pub(super) const IFLA_IPTUN_FWMARK: u16 = 20;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoIpTun {
+    Link(u32),
+    Local(Ipv4Addr),
+    Remote(Ipv4Addr),
+    Ttl(u8),
+    Tos(u8),
+    Protocol(IpProtocol),
+    PathMtuDiscovery(bool),
+    FwMark(u32),
+    EncapType(TunnelEncapType),
+    EncapFlags(TunnelEncapFlags),
+    EncapSourcePort(u16),
+    EncapDestinationPort(u16),
+    CollectMetadata,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoIpTun {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Link(_)
+            | Self::Local(_)
+            | Self::Remote(_)
+            | Self::FwMark(_) => 4,
+            Self::EncapType(_)
+            | Self::EncapFlags(_)
+            | Self::EncapSourcePort(_)
+            | Self::EncapDestinationPort(_) => 2,
+            Self::Ttl(_)
+            | Self::Tos(_)
+            | Self::Protocol(_)
+            | Self::PathMtuDiscovery(_) => 1,
+            Self::CollectMetadata => 0,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link(value) | Self::FwMark(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Ttl(value) | Self::Tos(value) => buffer[0] = *value,
+            Self::Protocol(value) => buffer[0] = i32::from(*value) as u8,
+            Self::PathMtuDiscovery(value) => buffer[0] = *value as u8,
+            Self::EncapType(value) => {
+                NativeEndian::write_u16(buffer, (*value).into())
+            }
+            Self::EncapFlags(value) => {
+                NativeEndian::write_u16(buffer, value.bits())
+            }
+            Self::EncapSourcePort(value)
+            | Self::EncapDestinationPort(value) => {
+                BigEndian::write_u16(buffer, *value)
+            }
+            Self::CollectMetadata => (),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link(_) => IFLA_IPTUN_LINK,
+            Self::Local(_) => IFLA_IPTUN_LOCAL,
+            Self::Remote(_) => IFLA_IPTUN_REMOTE,
+            Self::Ttl(_) => IFLA_IPTUN_TTL,
+            Self::Tos(_) => IFLA_IPTUN_TOS,
+            Self::Protocol(_) => IFLA_IPTUN_PROTO,
+            Self::PathMtuDiscovery(_) => IFLA_IPTUN_PMTUDISC,
+            Self::FwMark(_) => IFLA_IPTUN_FWMARK,
+            Self::EncapType(_) => IFLA_IPTUN_ENCAP_TYPE,
+            Self::EncapFlags(_) => IFLA_IPTUN_ENCAP_FLAGS,
+            Self::EncapSourcePort(_) => IFLA_IPTUN_ENCAP_SPORT,
+            Self::EncapDestinationPort(_) => IFLA_IPTUN_ENCAP_DPORT,
+            Self::CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpTun {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPTUN_LINK => Self::Link(parse_u32(payload)?),
+            IFLA_IPTUN_LOCAL => Self::Local(parse_ipv4_addr(payload)?),
+            IFLA_IPTUN_REMOTE => Self::Remote(parse_ipv4_addr(payload)?),
+            IFLA_IPTUN_TTL => Self::Ttl(parse_u8(payload)?),
+            IFLA_IPTUN_TOS => Self::Tos(parse_u8(payload)?),
+            IFLA_IPTUN_PROTO => {
+                Self::Protocol(IpProtocol::from(parse_u8(payload)? as i32))
+            }
+            IFLA_IPTUN_PMTUDISC => {
+                Self::PathMtuDiscovery(parse_u8(payload)? > 0)
+            }
+            IFLA_IPTUN_FWMARK => Self::FwMark(parse_u32(payload)?),
+            IFLA_IPTUN_ENCAP_TYPE => {
+                Self::EncapType(parse_u16(payload)?.into())
+            }
+            IFLA_IPTUN_ENCAP_FLAGS => Self::EncapFlags(
+                TunnelEncapFlags::from_bits_retain(parse_u16(payload)?),
+            ),
+            IFLA_IPTUN_ENCAP_SPORT => {
+                Self::EncapSourcePort(parse_u16_be(payload)?)
+            }
+            IFLA_IPTUN_ENCAP_DPORT => {
+                Self::EncapDestinationPort(parse_u16_be(payload)?)
+            }
+            IFLA_IPTUN_COLLECT_METADATA => Self::CollectMetadata,
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -15,8 +15,10 @@ const IFLA_IPOIB_UMCAST: u16 = 3;
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoIpoib {
+    /// InfiniBand partition key
     Pkey(u16),
-    Mode(u16),
+    Mode(IpoibMode),
+    /// Whether user space multicast is allowed in connected mode
     UmCast(u16),
     Other(DefaultNla),
 }
@@ -34,7 +36,7 @@ impl Nla for InfoIpoib {
         use self::InfoIpoib::*;
         match self {
             Pkey(value) => NativeEndian::write_u16(buffer, *value),
-            Mode(value) => NativeEndian::write_u16(buffer, *value),
+            Mode(value) => NativeEndian::write_u16(buffer, (*value).into()),
             UmCast(value) => NativeEndian::write_u16(buffer, *value),
             Other(nla) => nla.emit_value(buffer),
         }
@@ -58,9 +60,43 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpoib {
         let payload = buf.value();
         Ok(match buf.kind() {
             IFLA_IPOIB_PKEY => Pkey(parse_u16(payload)?),
-            IFLA_IPOIB_MODE => Mode(parse_u16(payload)?),
+            IFLA_IPOIB_MODE => Mode(parse_u16(payload)?.into()),
             IFLA_IPOIB_UMCAST => UmCast(parse_u16(payload)?),
             _kind => Other(DefaultNla::parse(buf)?),
         })
     }
 }
+
+const IPOIB_MODE_DATAGRAM: u16 = 0;
+const IPOIB_MODE_CONNECTED: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum IpoibMode {
+    /// Using unreliable datagram QPs
+    #[default]
+    Datagram,
+    /// Using connected QPs
+    Connected,
+    Other(u16),
+}
+
+impl From<u16> for IpoibMode {
+    fn from(d: u16) -> Self {
+        match d {
+            IPOIB_MODE_DATAGRAM => Self::Datagram,
+            IPOIB_MODE_CONNECTED => Self::Connected,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<IpoibMode> for u16 {
+    fn from(d: IpoibMode) -> Self {
+        match d {
+            IpoibMode::Datagram => IPOIB_MODE_DATAGRAM,
+            IpoibMode::Connected => IPOIB_MODE_CONNECTED,
+            IpoibMode::Other(value) => value,
+        }
+    }
+}
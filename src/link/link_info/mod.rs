@@ -1,9 +1,14 @@
 // SPDX-License-Identifier: MIT
 
+mod amt;
+mod bareudp;
 mod bond;
 mod bond_port;
 mod bridge;
 mod bridge_port;
+mod can;
+mod erspan;
+mod erspan6;
 mod geneve;
 mod gre;
 mod gre6;
@@ -14,20 +19,28 @@ mod hsr;
 mod info_data;
 mod info_port;
 mod infos;
+mod ip6tnl;
+mod ipip;
 mod ipoib;
 mod ipvlan;
 mod mac_vlan;
 mod macsec;
+mod netkit;
 mod sit;
 mod tun;
+mod tunnel;
 mod veth;
 mod vlan;
 mod vrf;
 mod vti;
+mod vti6;
+mod vxcan;
 mod vxlan;
 mod xfrm;
 mod xstats;
 
+pub use self::amt::{AmtMode, InfoAmt};
+pub use self::bareudp::InfoBareUdp;
 pub use self::bond::{
     BondAdInfo, BondArpAllTargets, BondArpValidate, BondFailOverMac, BondMode,
     BondPrimaryReselect, BondXmitHashPolicy, InfoBond,
@@ -39,17 +52,26 @@ pub use self::bridge::{
 pub use self::bridge_port::{
     BridgePortMulticastRouter, BridgePortState, InfoBridgePort,
 };
+pub use self::can::{
+    CanBerrCounter, CanBitTiming, CanBitTimingConst, CanCtrlMode,
+    CanCtrlModeExt, CanCtrlModeFlags, CanDeviceStats, CanState, CanTdc,
+    InfoCan,
+};
+pub use self::erspan::{ErspanDirection, InfoErspan};
+pub use self::erspan6::InfoErspan6;
 pub use self::geneve::{GeneveDf, InfoGeneve};
-pub use self::gre::InfoGreTun;
+pub use self::gre::{GreFlags, InfoGreTun};
 pub use self::gre6::InfoGreTun6;
 pub use self::gre_tap::InfoGreTap;
 pub use self::gre_tap6::InfoGreTap6;
-pub use self::gtp::InfoGtp;
+pub use self::gtp::{GtpRole, InfoGtp};
 pub use self::hsr::{HsrProtocol, InfoHsr};
 pub use self::info_data::InfoData;
-pub use self::info_port::{InfoPortData, InfoPortKind, InfoVrfPort};
+pub use self::info_port::{InfoPortData, InfoPortKind};
 pub use self::infos::{InfoKind, LinkInfo};
-pub use self::ipoib::InfoIpoib;
+pub use self::ip6tnl::InfoIp6Tun;
+pub use self::ipip::InfoIpTun;
+pub use self::ipoib::{InfoIpoib, IpoibMode};
 pub use self::ipvlan::{
     InfoIpVlan, InfoIpVtap, IpVlanFlags, IpVlanMode, IpVtapFlags, IpVtapMode,
 };
@@ -57,12 +79,16 @@ pub use self::mac_vlan::{InfoMacVlan, InfoMacVtap, MacVlanMode, MacVtapMode};
 pub use self::macsec::{
     InfoMacSec, MacSecCipherId, MacSecOffload, MacSecValidate,
 };
-pub use self::sit::InfoSitTun;
-pub use self::tun::InfoTun;
+pub use self::netkit::{InfoNetkit, NetkitMode, NetkitPolicy, NetkitScrub};
+pub use self::sit::{InfoSitTun, SitFlags};
+pub use self::tun::{InfoTun, TunMode};
+pub use self::tunnel::{Ip6TunnelFlags, TunnelEncapFlags, TunnelEncapType};
 pub use self::veth::InfoVeth;
 pub use self::vlan::{InfoVlan, VlanQosMapping};
-pub use self::vrf::InfoVrf;
+pub use self::vrf::{InfoVrf, InfoVrfPort};
 pub use self::vti::InfoVti;
+pub use self::vti6::InfoVti6;
+pub use self::vxcan::InfoVxcan;
 pub use self::vxlan::InfoVxlan;
 pub use self::xfrm::InfoXfrm;
 pub use self::xstats::LinkXstats;
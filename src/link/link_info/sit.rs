@@ -1,31 +1,139 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u8},
     DecodeError, Parseable,
 };
 
+use super::ipip::{
+    IFLA_IPTUN_6RD_PREFIX, IFLA_IPTUN_6RD_PREFIXLEN,
+    IFLA_IPTUN_6RD_RELAY_PREFIX, IFLA_IPTUN_6RD_RELAY_PREFIXLEN,
+    IFLA_IPTUN_ENCAP_DPORT, IFLA_IPTUN_ENCAP_FLAGS, IFLA_IPTUN_ENCAP_SPORT,
+    IFLA_IPTUN_ENCAP_TYPE, IFLA_IPTUN_FLAGS, IFLA_IPTUN_FWMARK,
+    IFLA_IPTUN_LINK, IFLA_IPTUN_LOCAL, IFLA_IPTUN_PMTUDISC, IFLA_IPTUN_PROTO,
+    IFLA_IPTUN_REMOTE, IFLA_IPTUN_TOS, IFLA_IPTUN_TTL,
+};
+use super::{TunnelEncapFlags, TunnelEncapType};
+use crate::ip::{parse_ipv4_addr, parse_ipv6_addr};
+use crate::IpProtocol;
+
+const SIT_ISATAP: u16 = 0x0001;
+
+bitflags! {
+    /// Flags of SIT tunnel (`IFLA_IPTUN_FLAGS`).
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+    pub struct SitFlags: u16 {
+        /// Intra-Site Automatic Tunnel Addressing Protocol.
+        const Isatap = SIT_ISATAP;
+        const _ = !0;
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoSitTun {
+    Link(u32),
+    Local(Ipv4Addr),
+    Remote(Ipv4Addr),
+    Ttl(u8),
+    Tos(u8),
+    PathMtuDiscovery(bool),
+    Flags(SitFlags),
+    Protocol(IpProtocol),
+    FwMark(u32),
+    /// IPv6 prefix of IPv6 Rapid Deployment (6rd).
+    Ip6RdPrefix(Ipv6Addr),
+    /// IPv4 prefix of 6rd relay.
+    Ip6RdRelayPrefix(Ipv4Addr),
+    Ip6RdPrefixLen(u16),
+    Ip6RdRelayPrefixLen(u16),
+    EncapType(TunnelEncapType),
+    EncapFlags(TunnelEncapFlags),
+    EncapSourcePort(u16),
+    EncapDestinationPort(u16),
     Other(DefaultNla),
 }
 
 impl Nla for InfoSitTun {
     fn value_len(&self) -> usize {
         match self {
+            Self::Ip6RdPrefix(_) => 16,
+            Self::Link(_)
+            | Self::Local(_)
+            | Self::Remote(_)
+            | Self::FwMark(_)
+            | Self::Ip6RdRelayPrefix(_) => 4,
+            Self::Flags(_)
+            | Self::Ip6RdPrefixLen(_)
+            | Self::Ip6RdRelayPrefixLen(_)
+            | Self::EncapType(_)
+            | Self::EncapFlags(_)
+            | Self::EncapSourcePort(_)
+            | Self::EncapDestinationPort(_) => 2,
+            Self::Ttl(_)
+            | Self::Tos(_)
+            | Self::Protocol(_)
+            | Self::PathMtuDiscovery(_) => 1,
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Link(value) | Self::FwMark(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value)
+            | Self::Remote(value)
+            | Self::Ip6RdRelayPrefix(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Ip6RdPrefix(value) => buffer.copy_from_slice(&value.octets()),
+            Self::Ttl(value) | Self::Tos(value) => buffer[0] = *value,
+            Self::Protocol(value) => buffer[0] = i32::from(*value) as u8,
+            Self::PathMtuDiscovery(value) => buffer[0] = *value as u8,
+            Self::Flags(value) => NativeEndian::write_u16(buffer, value.bits()),
+            Self::Ip6RdPrefixLen(value) | Self::Ip6RdRelayPrefixLen(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            Self::EncapType(value) => {
+                NativeEndian::write_u16(buffer, (*value).into())
+            }
+            Self::EncapFlags(value) => {
+                NativeEndian::write_u16(buffer, value.bits())
+            }
+            Self::EncapSourcePort(value)
+            | Self::EncapDestinationPort(value) => {
+                BigEndian::write_u16(buffer, *value)
+            }
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Link(_) => IFLA_IPTUN_LINK,
+            Self::Local(_) => IFLA_IPTUN_LOCAL,
+            Self::Remote(_) => IFLA_IPTUN_REMOTE,
+            Self::Ttl(_) => IFLA_IPTUN_TTL,
+            Self::Tos(_) => IFLA_IPTUN_TOS,
+            Self::PathMtuDiscovery(_) => IFLA_IPTUN_PMTUDISC,
+            Self::Flags(_) => IFLA_IPTUN_FLAGS,
+            Self::Protocol(_) => IFLA_IPTUN_PROTO,
+            Self::FwMark(_) => IFLA_IPTUN_FWMARK,
+            Self::Ip6RdPrefix(_) => IFLA_IPTUN_6RD_PREFIX,
+            Self::Ip6RdRelayPrefix(_) => IFLA_IPTUN_6RD_RELAY_PREFIX,
+            Self::Ip6RdPrefixLen(_) => IFLA_IPTUN_6RD_PREFIXLEN,
+            Self::Ip6RdRelayPrefixLen(_) => IFLA_IPTUN_6RD_RELAY_PREFIXLEN,
+            Self::EncapType(_) => IFLA_IPTUN_ENCAP_TYPE,
+            Self::EncapFlags(_) => IFLA_IPTUN_ENCAP_FLAGS,
+            Self::EncapSourcePort(_) => IFLA_IPTUN_ENCAP_SPORT,
+            Self::EncapDestinationPort(_) => IFLA_IPTUN_ENCAP_DPORT,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -34,8 +142,47 @@ impl Nla for InfoSitTun {
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoSitTun {
     type Error = DecodeError;
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
+            IFLA_IPTUN_LINK => Self::Link(parse_u32(payload)?),
+            IFLA_IPTUN_LOCAL => Self::Local(parse_ipv4_addr(payload)?),
+            IFLA_IPTUN_REMOTE => Self::Remote(parse_ipv4_addr(payload)?),
+            IFLA_IPTUN_TTL => Self::Ttl(parse_u8(payload)?),
+            IFLA_IPTUN_TOS => Self::Tos(parse_u8(payload)?),
+            IFLA_IPTUN_PMTUDISC => {
+                Self::PathMtuDiscovery(parse_u8(payload)? > 0)
+            }
+            IFLA_IPTUN_FLAGS => {
+                Self::Flags(SitFlags::from_bits_retain(parse_u16(payload)?))
+            }
+            IFLA_IPTUN_PROTO => {
+                Self::Protocol(IpProtocol::from(parse_u8(payload)? as i32))
+            }
+            IFLA_IPTUN_FWMARK => Self::FwMark(parse_u32(payload)?),
+            IFLA_IPTUN_6RD_PREFIX => {
+                Self::Ip6RdPrefix(parse_ipv6_addr(payload)?)
+            }
+            IFLA_IPTUN_6RD_RELAY_PREFIX => {
+                Self::Ip6RdRelayPrefix(parse_ipv4_addr(payload)?)
+            }
+            IFLA_IPTUN_6RD_PREFIXLEN => {
+                Self::Ip6RdPrefixLen(parse_u16(payload)?)
+            }
+            IFLA_IPTUN_6RD_RELAY_PREFIXLEN => {
+                Self::Ip6RdRelayPrefixLen(parse_u16(payload)?)
+            }
+            IFLA_IPTUN_ENCAP_TYPE => {
+                Self::EncapType(parse_u16(payload)?.into())
+            }
+            IFLA_IPTUN_ENCAP_FLAGS => Self::EncapFlags(
+                TunnelEncapFlags::from_bits_retain(parse_u16(payload)?),
+            ),
+            IFLA_IPTUN_ENCAP_SPORT => {
+                Self::EncapSourcePort(parse_u16_be(payload)?)
+            }
+            IFLA_IPTUN_ENCAP_DPORT => {
+                Self::EncapDestinationPort(parse_u16_be(payload)?)
+            }
             _kind => Self::Other(DefaultNla::parse(buf)?),
         })
     }
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: MIT
+
+const TUNNEL_ENCAP_NONE: u16 = 0;
+const TUNNEL_ENCAP_FOU: u16 = 1;
+const TUNNEL_ENCAP_GUE: u16 = 2;
+const TUNNEL_ENCAP_MPLS: u16 = 3;
+
+/// UDP encapsulation of IP tunnels (`IFLA_GRE_ENCAP_TYPE` and
+/// `IFLA_IPTUN_ENCAP_TYPE`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TunnelEncapType {
+    #[default]
+    None,
+    /// Foo over UDP
+    Fou,
+    /// Generic UDP Encapsulation
+    Gue,
+    Mpls,
+    Other(u16),
+}
+
+impl From<u16> for TunnelEncapType {
+    fn from(d: u16) -> Self {
+        match d {
+            TUNNEL_ENCAP_NONE => Self::None,
+            TUNNEL_ENCAP_FOU => Self::Fou,
+            TUNNEL_ENCAP_GUE => Self::Gue,
+            TUNNEL_ENCAP_MPLS => Self::Mpls,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TunnelEncapType> for u16 {
+    fn from(d: TunnelEncapType) -> Self {
+        match d {
+            TunnelEncapType::None => TUNNEL_ENCAP_NONE,
+            TunnelEncapType::Fou => TUNNEL_ENCAP_FOU,
+            TunnelEncapType::Gue => TUNNEL_ENCAP_GUE,
+            TunnelEncapType::Mpls => TUNNEL_ENCAP_MPLS,
+            TunnelEncapType::Other(value) => value,
+        }
+    }
+}
+
+const TUNNEL_ENCAP_FLAG_CSUM: u16 = 1 << 0;
+const TUNNEL_ENCAP_FLAG_CSUM6: u16 = 1 << 1;
+const TUNNEL_ENCAP_FLAG_REMCSUM: u16 = 1 << 2;
+
+bitflags! {
+    /// Flags of tunnel UDP encapsulation (`IFLA_GRE_ENCAP_FLAGS` and
+    /// `IFLA_IPTUN_ENCAP_FLAGS`).
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+    pub struct TunnelEncapFlags: u16 {
+        const Csum = TUNNEL_ENCAP_FLAG_CSUM;
+        const Csum6 = TUNNEL_ENCAP_FLAG_CSUM6;
+        const RemoteCsum = TUNNEL_ENCAP_FLAG_REMCSUM;
+        const _ = !0;
+    }
+}
+
+const IP6_TNL_F_IGN_ENCAP_LIMIT: u32 = 0x1;
+const IP6_TNL_F_USE_ORIG_TCLASS: u32 = 0x2;
+const IP6_TNL_F_USE_ORIG_FLOWLABEL: u32 = 0x4;
+const IP6_TNL_F_MIP6_DEV: u32 = 0x8;
+const IP6_TNL_F_RCV_DSCP_COPY: u32 = 0x10;
+const IP6_TNL_F_USE_ORIG_FWMARK: u32 = 0x20;
+const IP6_TNL_F_ALLOW_LOCAL_REMOTE: u32 = 0x40;
+
+bitflags! {
+    /// Flags of IPv6 tunnels (`IFLA_GRE_FLAGS` and `IFLA_IPTUN_FLAGS`).
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+    pub struct Ip6TunnelFlags: u32 {
+        /// Do not add encapsulation limit option.
+        const IgnoreEncapLimit = IP6_TNL_F_IGN_ENCAP_LIMIT;
+        /// Copy traffic class from inner packet.
+        const UseOrigTclass = IP6_TNL_F_USE_ORIG_TCLASS;
+        /// Copy flow label from inner packet.
+        const UseOrigFlowlabel = IP6_TNL_F_USE_ORIG_FLOWLABEL;
+        /// Mobile IPv6 tunnel.
+        const Mip6Dev = IP6_TNL_F_MIP6_DEV;
+        /// Copy DSCP from outer packet on decapsulation.
+        const RcvDscpCopy = IP6_TNL_F_RCV_DSCP_COPY;
+        /// Copy fwmark from inner packet.
+        const UseOrigFwmark = IP6_TNL_F_USE_ORIG_FWMARK;
+        /// Allow local and remote address to be the same.
+        const AllowLocalRemote = IP6_TNL_F_ALLOW_LOCAL_REMOTE;
+        const _ = !0;
+    }
+}
@@ -1,31 +1,71 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::Ipv4Addr;
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u32_be},
     DecodeError, Parseable,
 };
 
+use crate::ip::parse_ipv4_addr;
+
+pub(super) const IFLA_VTI_LINK: u16 = 1;
+pub(super) const IFLA_VTI_IKEY: u16 = 2;
+pub(super) const IFLA_VTI_OKEY: u16 = 3;
+pub(super) const IFLA_VTI_LOCAL: u16 = 4;
+pub(super) const IFLA_VTI_REMOTE: u16 = 5;
+pub(super) const IFLA_VTI_FWMARK: u16 = 6;
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoVti {
+    Link(u32),
+    IKey(u32),
+    OKey(u32),
+    Local(Ipv4Addr),
+    Remote(Ipv4Addr),
+    FwMark(u32),
     Other(DefaultNla),
 }
 
 impl Nla for InfoVti {
     fn value_len(&self) -> usize {
         match self {
+            Self::Link(_)
+            | Self::IKey(_)
+            | Self::OKey(_)
+            | Self::Local(_)
+            | Self::Remote(_)
+            | Self::FwMark(_) => 4,
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::Link(value) | Self::FwMark(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::IKey(value) | Self::OKey(value) => {
+                BigEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Link(_) => IFLA_VTI_LINK,
+            Self::IKey(_) => IFLA_VTI_IKEY,
+            Self::OKey(_) => IFLA_VTI_OKEY,
+            Self::Local(_) => IFLA_VTI_LOCAL,
+            Self::Remote(_) => IFLA_VTI_REMOTE,
+            Self::FwMark(_) => IFLA_VTI_FWMARK,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -34,8 +74,14 @@ impl Nla for InfoVti {
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVti {
     type Error = DecodeError;
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
+            IFLA_VTI_LINK => Self::Link(parse_u32(payload)?),
+            IFLA_VTI_IKEY => Self::IKey(parse_u32_be(payload)?),
+            IFLA_VTI_OKEY => Self::OKey(parse_u32_be(payload)?),
+            IFLA_VTI_LOCAL => Self::Local(parse_ipv4_addr(payload)?),
+            IFLA_VTI_REMOTE => Self::Remote(parse_ipv4_addr(payload)?),
+            IFLA_VTI_FWMARK => Self::FwMark(parse_u32(payload)?),
             _kind => Self::Other(DefaultNla::parse(buf)?),
         })
     }
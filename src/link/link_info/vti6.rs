@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u32_be},
+    DecodeError, Parseable,
+};
+
+use super::vti::{
+    IFLA_VTI_FWMARK, IFLA_VTI_IKEY, IFLA_VTI_LINK, IFLA_VTI_LOCAL,
+    IFLA_VTI_OKEY, IFLA_VTI_REMOTE,
+};
+use crate::ip::parse_ipv6_addr;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum InfoVti6 {
+    Link(u32),
+    IKey(u32),
+    OKey(u32),
+    Local(Ipv6Addr),
+    Remote(Ipv6Addr),
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoVti6 {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Local(_) | Self::Remote(_) => 16,
+            Self::Link(_) | Self::IKey(_) | Self::OKey(_) | Self::FwMark(_) => {
+                4
+            }
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link(value) | Self::FwMark(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::IKey(value) | Self::OKey(value) => {
+                BigEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link(_) => IFLA_VTI_LINK,
+            Self::IKey(_) => IFLA_VTI_IKEY,
+            Self::OKey(_) => IFLA_VTI_OKEY,
+            Self::Local(_) => IFLA_VTI_LOCAL,
+            Self::Remote(_) => IFLA_VTI_REMOTE,
+            Self::FwMark(_) => IFLA_VTI_FWMARK,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVti6 {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VTI_LINK => Self::Link(parse_u32(payload)?),
+            IFLA_VTI_IKEY => Self::IKey(parse_u32_be(payload)?),
+            IFLA_VTI_OKEY => Self::OKey(parse_u32_be(payload)?),
+            IFLA_VTI_LOCAL => Self::Local(parse_ipv6_addr(payload)?),
+            IFLA_VTI_REMOTE => Self::Remote(parse_ipv6_addr(payload)?),
+            IFLA_VTI_FWMARK => Self::FwMark(parse_u32(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::super::{LinkMessage, LinkMessageBuffer};
+
+const VXCAN_INFO_PEER: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+// Like veth, vxcan does not implement `fill_info` in kernel, this is only
+// for creating the vxcan pair.
+pub enum InfoVxcan {
+    Peer(LinkMessage),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoVxcan {
+    fn value_len(&self) -> usize {
+        use self::InfoVxcan::*;
+        match *self {
+            Peer(ref message) => message.buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoVxcan::*;
+        match *self {
+            Peer(ref message) => message.emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoVxcan::*;
+        match *self {
+            Peer(_) => VXCAN_INFO_PEER,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVxcan {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoVxcan::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VXCAN_INFO_PEER => {
+                let buffer = LinkMessageBuffer::new_checked(&payload)?;
+                Peer(LinkMessage::parse(&buffer)?)
+            }
+            _kind => Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -10,12 +10,16 @@ use netlink_packet_utils::{
 
 const IFLA_XFRM_LINK: u16 = 1;
 const IFLA_XFRM_IF_ID: u16 = 2;
+const IFLA_XFRM_COLLECT_METADATA: u16 = 3;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoXfrm {
     Link(u32),
     IfId(u32),
+    /// Metadata mode, the interface ID is set by the lightweight tunnel
+    /// state of route instead of [`InfoXfrm::IfId`].
+    CollectMetadata,
     Other(DefaultNla),
 }
 
@@ -25,6 +29,7 @@ impl Nla for InfoXfrm {
         match self {
             Link(_) => 4,
             IfId(_) => 4,
+            CollectMetadata => 0,
             Other(nla) => nla.value_len(),
         }
     }
@@ -34,6 +39,7 @@ impl Nla for InfoXfrm {
         match self {
             Link(value) => NativeEndian::write_u32(buffer, *value),
             IfId(value) => NativeEndian::write_u32(buffer, *value),
+            CollectMetadata => (),
             Other(nla) => nla.emit_value(buffer),
         }
     }
@@ -43,6 +49,7 @@ impl Nla for InfoXfrm {
         match self {
             Link(_) => IFLA_XFRM_LINK,
             IfId(_) => IFLA_XFRM_IF_ID,
+            CollectMetadata => IFLA_XFRM_COLLECT_METADATA,
             Other(nla) => nla.kind(),
         }
     }
@@ -56,6 +63,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoXfrm {
         Ok(match buf.kind() {
             IFLA_XFRM_LINK => Link(parse_u32(payload)?),
             IFLA_XFRM_IF_ID => IfId(parse_u32(payload)?),
+            IFLA_XFRM_COLLECT_METADATA => CollectMetadata,
             _kind => Other(DefaultNla::parse(buf)?),
         })
     }
@@ -1,9 +1,10 @@
 // SPDX-License-Identifier: MIT
 
 use netlink_packet_utils::{
-    nla::NlaBuffer, DecodeError, Emitable, ParseableParametrized,
+    nla::NlaBuffer, DecodeError, Emitable, Parseable, ParseableParametrized,
 };
 
+use super::can::{CanDeviceStats, CanDeviceStatsBuffer};
 use crate::link::InfoKind;
 
 // This is filled by driver via `struct rtnl_link_ops.fill_xstats`
@@ -11,18 +12,21 @@ use crate::link::InfoKind;
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum LinkXstats {
+    Can(CanDeviceStats),
     Other(Vec<u8>),
 }
 
 impl Emitable for LinkXstats {
     fn buffer_len(&self) -> usize {
         match self {
+            Self::Can(v) => v.buffer_len(),
             Self::Other(v) => v.len(),
         }
     }
 
     fn emit(&self, buffer: &mut [u8]) {
         match self {
+            Self::Can(v) => v.emit(buffer),
             Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
         }
     }
@@ -34,8 +38,13 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
     type Error = DecodeError;
     fn parse_with_param(
         buf: &NlaBuffer<&'a T>,
-        _kind: &InfoKind,
+        kind: &InfoKind,
     ) -> Result<Self, DecodeError> {
-        Ok(Self::Other(buf.value().to_vec()))
+        Ok(match kind {
+            InfoKind::Can => Self::Can(CanDeviceStats::parse(
+                &CanDeviceStatsBuffer::new_checked(buf.value())?,
+            )?),
+            _ => Self::Other(buf.value().to_vec()),
+        })
     }
 }
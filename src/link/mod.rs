@@ -2,8 +2,10 @@
 
 mod af_spec;
 mod attribute;
-mod buffer_tool;
+pub(crate) mod buffer_tool;
+mod devlink_port;
 mod down_reason;
+mod dpll_pin;
 mod event;
 pub(crate) mod ext_mask;
 mod header;
@@ -18,7 +20,7 @@ mod prop_list;
 mod proto_info;
 pub(crate) mod sriov;
 mod stats;
-mod stats64;
+pub(crate) mod stats64;
 mod vlan_protocol;
 mod wireless;
 mod xdp;
@@ -26,31 +28,46 @@ mod xdp;
 mod tests;
 
 pub use self::af_spec::{
-    AfSpecBridge, AfSpecInet, AfSpecInet6, AfSpecUnspec, BridgeFlag,
-    BridgeMode, BridgeVlanInfo, BridgeVlanInfoFlags, BridgeVlanTunnelInfo,
-    Icmp6Stats, Icmp6StatsBuffer, Inet6CacheInfo, Inet6CacheInfoBuffer,
-    Inet6DevConf, Inet6DevConfBuffer, Inet6IfaceFlags, Inet6Stats,
-    Inet6StatsBuffer, InetDevConf,
+    AfSpecBridge, AfSpecInet, AfSpecInet6, AfSpecUnspec, BridgeCfm,
+    BridgeCfmCcCcmTx, BridgeCfmCcConfig, BridgeCfmCcPeerMep,
+    BridgeCfmCcPeerStatus, BridgeCfmCcRdi, BridgeCfmMepConfig,
+    BridgeCfmMepCreate, BridgeCfmMepDelete, BridgeCfmMepStatus, BridgeFlag,
+    BridgeMode, BridgeMrp, BridgeMrpInRole, BridgeMrpInState, BridgeMrpInfo,
+    BridgeMrpInstance, BridgeMrpPortRole, BridgeMrpPortState,
+    BridgeMrpRingRole, BridgeMrpRingState, BridgeMrpStartInTest,
+    BridgeMrpStartTest, BridgeMst, BridgeMstEntry, BridgeVlanInfo,
+    BridgeVlanInfoFlags, BridgeVlanTunnelInfo, CfmCcmInterval, CfmDomain,
+    CfmMepDirection, Icmp6Stats, Icmp6StatsBuffer, Inet6CacheInfo,
+    Inet6CacheInfoBuffer, Inet6DevConf, Inet6DevConfBuffer, Inet6IfaceFlags,
+    Inet6Stats, Inet6StatsBuffer, InetDevConf, MrpInRole, MrpInState,
+    MrpPortRole, MrpPortState, MrpRingRole, MrpRingState,
 };
 pub use self::attribute::LinkAttribute;
+pub use self::devlink_port::LinkDevlinkPort;
 pub use self::down_reason::LinkProtocolDownReason;
+pub use self::dpll_pin::LinkDpllPin;
 pub use self::event::LinkEvent;
 pub use self::ext_mask::LinkExtentMask;
 pub use self::header::{LinkHeader, LinkMessageBuffer};
 pub use self::link_flag::LinkFlags;
 pub use self::link_info::{
-    BondAdInfo, BondArpAllTargets, BondArpValidate, BondFailOverMac, BondMode,
-    BondPortState, BondPrimaryReselect, BondXmitHashPolicy, BridgeId,
+    AmtMode, BondAdInfo, BondArpAllTargets, BondArpValidate, BondFailOverMac,
+    BondMode, BondPortState, BondPrimaryReselect, BondXmitHashPolicy, BridgeId,
     BridgeIdBuffer, BridgePortMulticastRouter, BridgePortState,
-    BridgeQuerierState, GeneveDf, HsrProtocol, InfoBond, InfoBondPort,
-    InfoBridge, InfoBridgePort, InfoData, InfoGeneve, InfoGreTap, InfoGreTap6,
-    InfoGreTun, InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan, InfoIpVtap,
-    InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan, InfoMacVtap, InfoPortData,
-    InfoPortKind, InfoSitTun, InfoTun, InfoVeth, InfoVlan, InfoVrf,
-    InfoVrfPort, InfoVti, InfoVxlan, InfoXfrm, IpVlanFlags, IpVlanMode,
-    IpVtapFlags, IpVtapMode, LinkInfo, LinkXstats, MacSecCipherId,
-    MacSecOffload, MacSecValidate, MacVlanMode, MacVtapMode, MiiStatus,
-    VlanQosMapping,
+    BridgeQuerierState, CanBerrCounter, CanBitTiming, CanBitTimingConst,
+    CanCtrlMode, CanCtrlModeExt, CanCtrlModeFlags, CanDeviceStats, CanState,
+    CanTdc, ErspanDirection, GeneveDf, GreFlags, GtpRole, HsrProtocol, InfoAmt,
+    InfoBareUdp, InfoBond, InfoBondPort, InfoBridge, InfoBridgePort, InfoCan,
+    InfoData, InfoErspan, InfoErspan6, InfoGeneve, InfoGreTap, InfoGreTap6,
+    InfoGreTun, InfoGreTun6, InfoGtp, InfoHsr, InfoIp6Tun, InfoIpTun,
+    InfoIpVlan, InfoIpVtap, InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan,
+    InfoMacVtap, InfoNetkit, InfoPortData, InfoPortKind, InfoSitTun, InfoTun,
+    InfoVeth, InfoVlan, InfoVrf, InfoVrfPort, InfoVti, InfoVti6, InfoVxcan,
+    InfoVxlan, InfoXfrm, Ip6TunnelFlags, IpVlanFlags, IpVlanMode, IpVtapFlags,
+    IpVtapMode, IpoibMode, LinkInfo, LinkXstats, MacSecCipherId, MacSecOffload,
+    MacSecValidate, MacVlanMode, MacVtapMode, MiiStatus, NetkitMode,
+    NetkitPolicy, NetkitScrub, SitFlags, TunMode, TunnelEncapFlags,
+    TunnelEncapType, VlanQosMapping,
 };
 pub use self::link_layer_type::LinkLayerType;
 pub use self::link_state::State;
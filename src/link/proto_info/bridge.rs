@@ -1,16 +1,16 @@
 // SPDX-License-Identifier: MIT
 
 use netlink_packet_utils::{
-    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    nla::{NlaBuffer, NlasIterator},
     traits::Parseable,
     DecodeError,
 };
 
-#[derive(Clone, Eq, PartialEq, Debug)]
-#[non_exhaustive]
-pub enum LinkProtoInfoBridge {
-    Other(DefaultNla),
-}
+use super::super::InfoBridgePort;
+
+/// The `IFLA_PROTINFO` of `AF_BRIDGE` link message holds the same
+/// `IFLA_BRPORT_*` attributes as the `IFLA_INFO_PORT_DATA` of bridge port.
+pub type LinkProtoInfoBridge = InfoBridgePort;
 
 pub(crate) struct VecLinkProtoInfoBridge(pub(crate) Vec<LinkProtoInfoBridge>);
 
@@ -27,32 +27,3 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
         Ok(Self(nlas))
     }
 }
-
-impl Nla for LinkProtoInfoBridge {
-    fn value_len(&self) -> usize {
-        match *self {
-            Self::Other(ref nla) => nla.value_len(),
-        }
-    }
-
-    fn emit_value(&self, buffer: &mut [u8]) {
-        match *self {
-            Self::Other(ref nla) => nla.emit_value(buffer),
-        }
-    }
-
-    fn kind(&self) -> u16 {
-        match *self {
-            Self::Other(ref nla) => nla.kind(),
-        }
-    }
-}
-
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
-    for LinkProtoInfoBridge
-{
-    type Error = DecodeError;
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        Ok(Self::Other(DefaultNla::parse(buf)?))
-    }
-}
@@ -1,16 +1,16 @@
 // SPDX-License-Identifier: MIT
 
 use netlink_packet_utils::{
-    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    nla::{NlaBuffer, NlasIterator},
     traits::Parseable,
     DecodeError,
 };
 
-#[derive(Clone, Eq, PartialEq, Debug)]
-#[non_exhaustive]
-pub enum LinkProtoInfoInet6 {
-    Other(DefaultNla),
-}
+use super::super::AfSpecInet6;
+
+/// The `IFLA_PROTINFO` of `AF_INET6` link message holds the same
+/// `IFLA_INET6_*` attributes as the `AF_INET6` entry of `IFLA_AF_SPEC`.
+pub type LinkProtoInfoInet6 = AfSpecInet6;
 
 pub(crate) struct VecLinkProtoInfoInet6(pub(crate) Vec<LinkProtoInfoInet6>);
 
@@ -27,32 +27,3 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
         Ok(Self(nlas))
     }
 }
-
-impl Nla for LinkProtoInfoInet6 {
-    fn value_len(&self) -> usize {
-        match *self {
-            Self::Other(ref nla) => nla.value_len(),
-        }
-    }
-
-    fn emit_value(&self, buffer: &mut [u8]) {
-        match *self {
-            Self::Other(ref nla) => nla.emit_value(buffer),
-        }
-    }
-
-    fn kind(&self) -> u16 {
-        match *self {
-            Self::Other(ref nla) => nla.kind(),
-        }
-    }
-}
-
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
-    for LinkProtoInfoInet6
-{
-    type Error = DecodeError;
-    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        Ok(Self::Other(DefaultNla::parse(buf)?))
-    }
-}
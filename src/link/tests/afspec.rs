@@ -1,12 +1,14 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::{nla::DefaultNla, Emitable, Parseable};
+use std::net::Ipv6Addr;
+
+use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::{
     link::{
-        link_flag::LinkFlags, LinkAttribute, LinkHeader, LinkLayerType,
-        LinkMessage, LinkMessageBuffer, LinkXdp, Map, State, Stats, Stats64,
-        XdpAttached,
+        link_flag::LinkFlags, Inet6CacheInfo, Inet6IfaceFlags, LinkAttribute,
+        LinkHeader, LinkLayerType, LinkMessage, LinkMessageBuffer,
+        LinkProtoInfoInet6, LinkXdp, Map, State, Stats, Stats64, XdpAttached,
     },
     AddressFamily,
 };
@@ -101,15 +103,15 @@ fn test_empty_af_spec() {
             LinkAttribute::MaxMtu(1500),
             LinkAttribute::Group(0),
             LinkAttribute::Promiscuity(0),
-            LinkAttribute::Other(DefaultNla::new(61, vec![0, 0, 0, 0])),
+            LinkAttribute::AllMulti(0),
             LinkAttribute::NumTxQueues(4),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(63, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(64, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(59, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::GsoIpv4MaxSize(65536),
+            LinkAttribute::GroIpv4MaxSize(65536),
+            LinkAttribute::TsoMaxSize(65536),
+            LinkAttribute::TsoMaxSegs(65535),
             LinkAttribute::NumRxQueues(4),
             LinkAttribute::Carrier(0),
             LinkAttribute::CarrierChanges(6),
@@ -195,3 +197,68 @@ fn test_empty_af_spec() {
 
     assert_eq!(buf, raw);
 }
+
+// Setup:
+//      RTM_NEWLINK notification of AF_INET6 sent by kernel after router
+//      advertisement with MTU option 1400 received on eth0.
+// IFLA_INET6_CONF, IFLA_INET6_STATS and IFLA_INET6_ICMP6STATS are removed
+// from IFLA_PROTINFO.
+#[test]
+fn test_parse_inet6_proto_info() {
+    let raw: Vec<u8> = vec![
+        0x0a, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x65, 0x74, 0x68, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0xdc, 0x05, 0x00, 0x00,
+        0x05, 0x00, 0x10, 0x00, 0x06, 0x00, 0x00, 0x00, 0x44, 0x00, 0x0c, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x20, 0x00, 0x00, 0x80, 0x14, 0x00, 0x05, 0x00,
+        0xff, 0xff, 0x00, 0x00, 0x87, 0xd6, 0x12, 0x00, 0x30, 0x75, 0x00, 0x00,
+        0xe8, 0x03, 0x00, 0x00, 0x14, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x09, 0x00,
+        0x78, 0x05, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Inet6,
+            index: 2,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Up
+                | LinkFlags::Broadcast
+                | LinkFlags::Running
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("eth0".into()),
+            LinkAttribute::Mtu(1500),
+            LinkAttribute::OperState(State::Up),
+            LinkAttribute::ProtoInfoInet6(vec![
+                LinkProtoInfoInet6::Flags(
+                    Inet6IfaceFlags::Ready | Inet6IfaceFlags::RaRcvd,
+                ),
+                LinkProtoInfoInet6::CacheInfo(Inet6CacheInfo {
+                    max_reasm_len: 65535,
+                    tstamp: 1234567,
+                    reachable_time: 30000,
+                    retrans_time: 1000,
+                }),
+                LinkProtoInfoInet6::Token(Ipv6Addr::UNSPECIFIED),
+                LinkProtoInfoInet6::AddrGenMode(0),
+                LinkProtoInfoInet6::RaMtu(1400),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    AmtMode, InfoAmt, InfoData, InfoKind, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip link add amt0 type amt mode gateway local 192.0.2.1 \
+//          discovery 198.51.100.1 dev eth1
+//      ip link set amt0 up
+// Only the IFLA_LINKINFO of `ip link show amt0` reply is kept.
+#[test]
+fn test_amt_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0xfe, 0xff, 0x0d, 0x00, 0x00, 0x00, 0xd1, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x48, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x61, 0x6d, 0x74, 0x00, 0x3c, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x08, 0xdc, 0x00, 0x00,
+        0x06, 0x00, 0x03, 0x00, 0x08, 0xdc, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x07, 0x00,
+        0xc6, 0x33, 0x64, 0x01,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 13,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Amt),
+            LinkInfo::Data(InfoData::Amt(vec![
+                InfoAmt::Mode(AmtMode::Gateway),
+                InfoAmt::RelayPort(2268),
+                InfoAmt::GatewayPort(2268),
+                InfoAmt::Link(2),
+                InfoAmt::MaxTunnels(128),
+                InfoAmt::LocalIp(Ipv4Addr::new(192, 0, 2, 1)),
+                InfoAmt::DiscoveryIp(Ipv4Addr::new(198, 51, 100, 1)),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
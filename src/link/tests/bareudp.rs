@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoBareUdp, InfoData, InfoKind, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip link add bareudp0 type bareudp dstport 6635 ethertype mpls_uc \
+//          srcportmin 2000 multiproto
+//      ip link set bareudp0 up
+// Only the IFLA_LINKINFO of `ip link show bareudp0` reply is kept.
+#[test]
+fn test_bareudp_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0xfe, 0xff, 0x0c, 0x00, 0x00, 0x00, 0xd1, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x12, 0x00, 0x0c, 0x00, 0x01, 0x00,
+        0x62, 0x61, 0x72, 0x65, 0x75, 0x64, 0x70, 0x00, 0x20, 0x00, 0x02, 0x00,
+        0x06, 0x00, 0x01, 0x00, 0x19, 0xeb, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x88, 0x47, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0xd0, 0x07, 0x00, 0x00,
+        0x04, 0x00, 0x04, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 12,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::BareUdp),
+            LinkInfo::Data(InfoData::BareUdp(vec![
+                InfoBareUdp::Port(6635),
+                InfoBareUdp::EtherType(0x8847),
+                InfoBareUdp::SourcePortMin(2000),
+                InfoBareUdp::MultiProtoMode,
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
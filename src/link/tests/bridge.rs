@@ -1,19 +1,19 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::{
-    nla::{DefaultNla, NlaBuffer},
-    Emitable, Parseable,
-};
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
 
 use crate::link::{
     af_spec::VecAfSpecBridge, link_flag::LinkFlags, AfSpecBridge, AfSpecInet,
-    AfSpecInet6, AfSpecUnspec, BridgeFlag, BridgeId, BridgeMode,
+    AfSpecInet6, AfSpecUnspec, BridgeCfm, BridgeCfmCcConfig,
+    BridgeCfmMepCreate, BridgeFlag, BridgeId, BridgeMode, BridgeMrp,
+    BridgeMrpInstance, BridgeMrpPortRole, BridgeMst, BridgeMstEntry,
     BridgePortMulticastRouter, BridgePortState, BridgeVlanInfo,
-    BridgeVlanInfoFlags, BridgeVlanTunnelInfo, Inet6CacheInfo, Inet6DevConf,
-    Inet6IfaceFlags, InetDevConf, InfoBridge, InfoBridgePort, InfoData,
-    InfoKind, InfoPortData, InfoPortKind, LinkAttribute, LinkHeader, LinkInfo,
-    LinkLayerType, LinkMessage, LinkMessageBuffer, LinkXdp, Map, State, Stats,
-    Stats64, XdpAttached,
+    BridgeVlanInfoFlags, BridgeVlanTunnelInfo, CfmCcmInterval, CfmDomain,
+    CfmMepDirection, Inet6CacheInfo, Inet6DevConf, Inet6IfaceFlags,
+    InetDevConf, InfoBridge, InfoBridgePort, InfoData, InfoKind, InfoPortData,
+    InfoPortKind, LinkAttribute, LinkHeader, LinkInfo, LinkLayerType,
+    LinkMessage, LinkMessageBuffer, LinkProtoInfoBridge, LinkXdp, Map,
+    MrpPortRole, State, Stats, Stats64, XdpAttached,
 };
 use crate::AddressFamily;
 
@@ -165,15 +165,15 @@ fn test_parse_link_bridge_no_extention_mask() {
             LinkAttribute::MaxMtu(65535),
             LinkAttribute::Group(0),
             LinkAttribute::Promiscuity(0),
-            LinkAttribute::Other(DefaultNla::new(61, vec![0, 0, 0, 0])),
+            LinkAttribute::AllMulti(0),
             LinkAttribute::NumTxQueues(1),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(63, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(64, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(59, vec![248, 255, 7, 0])),
-            LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::GsoIpv4MaxSize(65536),
+            LinkAttribute::GroIpv4MaxSize(65536),
+            LinkAttribute::TsoMaxSize(524280),
+            LinkAttribute::TsoMaxSegs(65535),
             LinkAttribute::NumRxQueues(1),
             LinkAttribute::Carrier(1),
             LinkAttribute::Qdisc("noqueue".to_string()),
@@ -409,7 +409,7 @@ fn test_parse_link_bridge_no_extention_mask() {
                     }),
                 ]),
             ]),
-            LinkAttribute::Other(DefaultNla::new(32830, vec![])),
+            LinkAttribute::DevlinkPort(vec![]),
         ],
     };
 
@@ -637,3 +637,223 @@ fn test_af_spec_bridge_vlan_tunnel_info() {
         expected
     );
 }
+
+// IFLA_AF_SPEC of RTM_SETLINK request sent by MRP daemon to create a MRP
+// instance on ring ports with ifindex 5 and 6, then set ifindex 5 as primary
+// ring port.
+#[test]
+fn test_af_spec_bridge_mrp() {
+    let raw: Vec<u8> = vec![
+        0x34, 0x00, 0x04, 0x80, 0x24, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x06, 0x00, 0x00, 0x00, 0x06, 0x00, 0x04, 0x00,
+        0x00, 0x80, 0x00, 0x00, 0x0c, 0x00, 0x03, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = vec![AfSpecBridge::Mrp(vec![
+        BridgeMrp::Instance(vec![
+            BridgeMrpInstance::RingId(1),
+            BridgeMrpInstance::PrimaryPort(5),
+            BridgeMrpInstance::SecondaryPort(6),
+            BridgeMrpInstance::Priority(0x8000),
+        ]),
+        BridgeMrp::PortRole(vec![BridgeMrpPortRole::Role(
+            MrpPortRole::Primary,
+        )]),
+    ])];
+
+    assert_eq!(
+        VecAfSpecBridge::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+
+    expected.as_slice().emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// IFLA_AF_SPEC of RTM_SETLINK request creating an up MEP instance 10 on port
+// with ifindex 5 and enabling continuity check with 1 second interval.
+#[test]
+fn test_af_spec_bridge_cfm() {
+    let raw: Vec<u8> = vec![
+        0x78, 0x00, 0x05, 0x80, 0x24, 0x00, 0x01, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x50, 0x00, 0x04, 0x80, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x04, 0x00, 0x00, 0x00, 0x34, 0x00, 0x04, 0x00,
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+        0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18,
+        0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20, 0x21, 0x22, 0x23, 0x24,
+        0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f, 0x30,
+    ];
+
+    let expected = vec![AfSpecBridge::Cfm(vec![
+        BridgeCfm::MepCreate(vec![
+            BridgeCfmMepCreate::Instance(10),
+            BridgeCfmMepCreate::Domain(CfmDomain::Port),
+            BridgeCfmMepCreate::Direction(CfmMepDirection::Up),
+            BridgeCfmMepCreate::IfIndex(5),
+        ]),
+        BridgeCfm::CcConfig(vec![
+            BridgeCfmCcConfig::Instance(10),
+            BridgeCfmCcConfig::Enable(1),
+            BridgeCfmCcConfig::ExpectedInterval(CfmCcmInterval::Interval1s),
+            BridgeCfmCcConfig::ExpectedMaid((1..=48).collect()),
+        ]),
+    ])];
+
+    assert_eq!(
+        VecAfSpecBridge::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+
+    expected.as_slice().emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add br0 type bridge mst_enabled 1
+//      ip link set eth1 master br0
+//      bridge mst set dev eth1 msti 1 state forwarding
+// Only the IFLA_BRIDGE_MST of `bridge mst show dev eth1` reply is kept.
+#[test]
+fn test_af_spec_bridge_mst() {
+    let raw: Vec<u8> = vec![
+        0x18, 0x00, 0x06, 0x80, 0x14, 0x00, 0x01, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00, 0x03, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = vec![AfSpecBridge::Mst(vec![BridgeMst::Entry(vec![
+        BridgeMstEntry::Msti(1),
+        BridgeMstEntry::State(BridgePortState::Forwarding),
+    ])])];
+
+    assert_eq!(
+        VecAfSpecBridge::parse(&NlaBuffer::new(&raw)).unwrap().0,
+        expected
+    );
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+
+    expected.as_slice().emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add br0 type bridge
+//      ip link set eth1 master br0
+//      bridge link set dev eth1 cost 100
+// Only IFLA_PROTINFO attributes up to IFLA_BRPORT_PROXYARP_WIFI of
+// `bridge link show dev eth1` reply are kept.
+#[test]
+fn test_parse_af_bridge_proto_info() {
+    let raw: Vec<u8> = vec![
+        0x07, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x03, 0x00, 0x65, 0x74, 0x68, 0x31,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0a, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0xdc, 0x05, 0x00, 0x00, 0x05, 0x00, 0x10, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x52, 0x54, 0x00, 0x12,
+        0x34, 0x56, 0x00, 0x00, 0x74, 0x00, 0x0c, 0x80, 0x05, 0x00, 0x01, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x03, 0x00, 0x64, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x07, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x1b, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x1e, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x0a, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Bridge,
+            index: 3,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Up
+                | LinkFlags::Broadcast
+                | LinkFlags::Running
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::IfName("eth1".into()),
+            LinkAttribute::Controller(5),
+            LinkAttribute::Mtu(1500),
+            LinkAttribute::OperState(State::Up),
+            LinkAttribute::Address(vec![0x52, 0x54, 0x00, 0x12, 0x34, 0x56]),
+            LinkAttribute::ProtoInfoBridge(vec![
+                LinkProtoInfoBridge::State(BridgePortState::Forwarding),
+                LinkProtoInfoBridge::Priority(32),
+                LinkProtoInfoBridge::Cost(100),
+                LinkProtoInfoBridge::HairpinMode(false),
+                LinkProtoInfoBridge::Guard(false),
+                LinkProtoInfoBridge::Protect(false),
+                LinkProtoInfoBridge::FastLeave(false),
+                LinkProtoInfoBridge::MulticastToUnicast(false),
+                LinkProtoInfoBridge::Learning(true),
+                LinkProtoInfoBridge::UnicastFlood(true),
+                LinkProtoInfoBridge::MulticastFlood(true),
+                LinkProtoInfoBridge::BroadcastFlood(true),
+                LinkProtoInfoBridge::ProxyARP(false),
+                LinkProtoInfoBridge::ProxyARPWifi(false),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      bridge link set dev eth1 learning off flood off
+// This is the RTM_SETLINK request sent by iproute2.
+#[test]
+fn test_emit_af_bridge_proto_info() {
+    let raw: Vec<u8> = vec![
+        0x07, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x0c, 0x80, 0x05, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Bridge,
+            index: 3,
+            ..Default::default()
+        },
+        attributes: vec![LinkAttribute::ProtoInfoBridge(vec![
+            LinkProtoInfoBridge::Learning(false),
+            LinkProtoInfoBridge::UnicastFlood(false),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
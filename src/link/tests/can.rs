@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    CanBerrCounter, CanBitTiming, CanBitTimingConst, CanCtrlMode,
+    CanCtrlModeExt, CanCtrlModeFlags, CanDeviceStats, CanState, CanTdc,
+    InfoCan, InfoData, InfoKind, InfoVxcan, LinkAttribute, LinkHeader,
+    LinkInfo, LinkLayerType, LinkMessage, LinkMessageBuffer, LinkXstats,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip link set can0 type can bitrate 500000 dbitrate 2000000 fd on \
+//          restart-ms 100
+//      ip link set can0 up
+// Only the IFLA_LINKINFO of `ip -d -s link show can0` reply is kept.
+#[test]
+fn test_can_fd_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x18, 0x01, 0x14, 0x00, 0x00, 0x00, 0xc1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x48, 0x01, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x63, 0x61, 0x6e, 0x00, 0x1c, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x20, 0x01, 0x02, 0x00,
+        0x24, 0x00, 0x01, 0x00, 0x20, 0xa1, 0x07, 0x00, 0x6b, 0x03, 0x00, 0x00,
+        0x19, 0x00, 0x00, 0x00, 0x22, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x34, 0x00, 0x02, 0x00, 0x6d, 0x63, 0x70, 0x32, 0x35, 0x31, 0x78, 0x66,
+        0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0x80, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x5a, 0x62, 0x02,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x20, 0x02, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00,
+        0x64, 0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x24, 0x00, 0x09, 0x00, 0x80, 0x84, 0x1e, 0x00, 0xee, 0x02, 0x00, 0x00,
+        0x19, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x34, 0x00, 0x0a, 0x00, 0x6d, 0x63, 0x70, 0x32, 0x35, 0x31, 0x78, 0x66,
+        0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+        0x10, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0f, 0x00, 0x00, 0x12, 0x7a, 0x00,
+        0x2c, 0x00, 0x10, 0x80, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x7f, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x08, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x11, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0xb3, 0x07, 0x00, 0x00,
+    ];
+
+    let bittiming_const = CanBitTimingConst {
+        name: "mcp251xfd".to_string(),
+        tseg1_min: 2,
+        tseg1_max: 256,
+        tseg2_min: 1,
+        tseg2_max: 128,
+        sjw_max: 128,
+        brp_min: 1,
+        brp_max: 256,
+        brp_inc: 1,
+    };
+    let data_bittiming_const = CanBitTimingConst {
+        name: "mcp251xfd".to_string(),
+        tseg1_min: 1,
+        tseg1_max: 32,
+        tseg2_min: 1,
+        tseg2_max: 16,
+        sjw_max: 16,
+        brp_min: 1,
+        brp_max: 256,
+        brp_inc: 1,
+    };
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 20,
+            link_layer_type: LinkLayerType::Can,
+            flags: LinkFlags::Up
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Can),
+            LinkInfo::Xstats(LinkXstats::Can(CanDeviceStats {
+                bus_error: 0,
+                error_warning: 1,
+                error_passive: 1,
+                bus_off: 0,
+                arbitration_lost: 0,
+                restarts: 2,
+            })),
+            LinkInfo::Data(InfoData::Can(vec![
+                InfoCan::BitTiming(CanBitTiming {
+                    bitrate: 500000,
+                    sample_point: 875,
+                    tq: 25,
+                    prop_seg: 34,
+                    phase_seg1: 35,
+                    phase_seg2: 10,
+                    sjw: 1,
+                    brp: 1,
+                }),
+                InfoCan::BitTimingConst(bittiming_const),
+                InfoCan::Clock(40000000),
+                InfoCan::State(CanState::ErrorActive),
+                InfoCan::CtrlMode(CanCtrlMode {
+                    mask: CanCtrlModeFlags::empty(),
+                    flags: CanCtrlModeFlags::Fd | CanCtrlModeFlags::TdcAuto,
+                }),
+                InfoCan::RestartMs(100),
+                InfoCan::BerrCounter(CanBerrCounter { txerr: 0, rxerr: 0 }),
+                InfoCan::DataBitTiming(CanBitTiming {
+                    bitrate: 2000000,
+                    sample_point: 750,
+                    tq: 25,
+                    prop_seg: 7,
+                    phase_seg1: 7,
+                    phase_seg2: 5,
+                    sjw: 1,
+                    brp: 1,
+                }),
+                InfoCan::DataBitTimingConst(data_bittiming_const),
+                InfoCan::BitRateMax(8000000),
+                InfoCan::Tdc(vec![
+                    CanTdc::TdcvMin(0),
+                    CanTdc::TdcvMax(0),
+                    CanTdc::TdcoMin(0),
+                    CanTdc::TdcoMax(127),
+                    CanTdc::Tdco(15),
+                ]),
+                InfoCan::CtrlModeExt(vec![CanCtrlModeExt::Supported(
+                    CanCtrlModeFlags::Loopback
+                        | CanCtrlModeFlags::ListenOnly
+                        | CanCtrlModeFlags::BerrReporting
+                        | CanCtrlModeFlags::Fd
+                        | CanCtrlModeFlags::FdNonIso
+                        | CanCtrlModeFlags::CcLen8Dlc
+                        | CanCtrlModeFlags::TdcAuto
+                        | CanCtrlModeFlags::TdcManual,
+                )]),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add vcan0 type vcan
+#[test]
+fn test_vcan_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x18, 0x01, 0x15, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x12, 0x00, 0x09, 0x00, 0x01, 0x00,
+        0x76, 0x63, 0x61, 0x6e, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 21,
+            link_layer_type: LinkLayerType::Can,
+            flags: LinkFlags::Noarp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![LinkInfo::Kind(
+            InfoKind::Vcan,
+        )])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Request of
+//      ip link add vxcan0 type vxcan peer name vxcan1
+#[test]
+fn test_create_vxcan() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x03, 0x00, 0x76, 0x78, 0x63, 0x61,
+        0x6e, 0x30, 0x00, 0x00, 0x34, 0x00, 0x12, 0x00, 0x0a, 0x00, 0x01, 0x00,
+        0x76, 0x78, 0x63, 0x61, 0x6e, 0x00, 0x00, 0x00, 0x24, 0x00, 0x02, 0x00,
+        0x20, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x03, 0x00,
+        0x76, 0x78, 0x63, 0x61, 0x6e, 0x31, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader::default(),
+        attributes: vec![
+            LinkAttribute::IfName("vxcan0".into()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Vxcan),
+                LinkInfo::Data(InfoData::Vxcan(InfoVxcan::Peer(LinkMessage {
+                    header: LinkHeader::default(),
+                    attributes: vec![LinkAttribute::IfName("vxcan1".into())],
+                }))),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// IFLA_CAN_TERMINATION_CONST holding 3 bytes and IFLA_CAN_BITRATE_CONST
+// holding 6 bytes: the trailing partial value must not be silently dropped.
+#[test]
+fn test_can_const_array_invalid_length() {
+    let raw: Vec<u8> = vec![0x07, 0x00, 0x0c, 0x00, 0x78, 0x00, 0x00, 0x00];
+    let nla = NlaBuffer::new_checked(raw.as_slice()).unwrap();
+    assert!(InfoCan::parse(&nla).is_err());
+
+    let raw: Vec<u8> = vec![
+        0x0a, 0x00, 0x0d, 0x00, 0x20, 0xa1, 0x07, 0x00, 0x40, 0x42, 0x00, 0x00,
+    ];
+    let nla = NlaBuffer::new_checked(raw.as_slice()).unwrap();
+    assert!(InfoCan::parse(&nla).is_err());
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    LinkAttribute, LinkDevlinkPort, LinkDpllPin, LinkHeader, LinkLayerType,
+    LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      PCI NIC registered as devlink port and connected to DPLL pin 7.
+// Only the IFLA_PARENT_DEV_NAME, IFLA_PARENT_DEV_BUS_NAME,
+// IFLA_DEVLINK_PORT and IFLA_DPLL_PIN of `ip link show eth1` reply is kept.
+#[test]
+fn test_parent_dev_devlink_port_dpll_pin() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x38, 0x00, 0x30, 0x30, 0x30, 0x30,
+        0x3a, 0x30, 0x33, 0x3a, 0x30, 0x30, 0x2e, 0x30, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x39, 0x00, 0x70, 0x63, 0x69, 0x00, 0x28, 0x00, 0x3e, 0x80,
+        0x08, 0x00, 0x01, 0x00, 0x70, 0x63, 0x69, 0x00, 0x11, 0x00, 0x02, 0x00,
+        0x30, 0x30, 0x30, 0x30, 0x3a, 0x30, 0x33, 0x3a, 0x30, 0x30, 0x2e, 0x30,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x41, 0x80, 0x08, 0x00, 0x01, 0x00, 0x07, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 2,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Up
+                | LinkFlags::Broadcast
+                | LinkFlags::Running
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![
+            LinkAttribute::ParentDevName("0000:03:00.0".into()),
+            LinkAttribute::ParentDevBusName("pci".into()),
+            LinkAttribute::DevlinkPort(vec![
+                LinkDevlinkPort::BusName("pci".into()),
+                LinkDevlinkPort::DevName("0000:03:00.0".into()),
+                LinkDevlinkPort::PortIndex(1),
+            ]),
+            LinkAttribute::DpllPin(vec![LinkDpllPin::Id(7)]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
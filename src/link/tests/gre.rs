@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    ErspanDirection, GreFlags, InfoData, InfoErspan, InfoErspan6, InfoGreTap,
+    InfoGreTun6, InfoKind, Ip6TunnelFlags, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer, TunnelEncapFlags,
+    TunnelEncapType,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip link add gre1 type gretap local 192.0.2.1 remote 192.0.2.2 \
+//          key 10 ttl 64
+//      ip link set gre1 up
+// Only the IFLA_LINKINFO of `ip link show gre1` reply is kept.
+#[test]
+fn test_gretap_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x94, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x67, 0x72, 0x65, 0x74, 0x61, 0x70, 0x00, 0x00, 0x84, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x20, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x0a, 0x08, 0x00, 0x06, 0x00, 0xc0, 0x00, 0x02, 0x01,
+        0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x05, 0x00, 0x08, 0x00,
+        0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 11,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Up
+                | LinkFlags::Broadcast
+                | LinkFlags::Running
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::GreTap),
+            LinkInfo::Data(InfoData::GreTap(vec![
+                InfoGreTap::Link(0),
+                InfoGreTap::IFlags(GreFlags::Key),
+                InfoGreTap::OFlags(GreFlags::Key),
+                InfoGreTap::IKey(10),
+                InfoGreTap::OKey(10),
+                InfoGreTap::Local(Ipv4Addr::new(192, 0, 2, 1)),
+                InfoGreTap::Remote(Ipv4Addr::new(192, 0, 2, 2)),
+                InfoGreTap::Ttl(64),
+                InfoGreTap::Tos(0),
+                InfoGreTap::PathMtuDiscovery(true),
+                InfoGreTap::FwMark(0),
+                InfoGreTap::EncapType(TunnelEncapType::None),
+                InfoGreTap::EncapSourcePort(0),
+                InfoGreTap::EncapDestinationPort(0),
+                InfoGreTap::EncapFlags(TunnelEncapFlags::empty()),
+                InfoGreTap::IgnoreDf(false),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add gre6 type ip6gre local 2001:db8::1 remote 2001:db8::2 \
+//          hoplimit 64 encaplimit 4 tclass inherit flowlabel inherit \
+//          encap fou encap-sport 5555 encap-dport 6666 encap-csum
+//      ip link set gre6 up
+// Only the IFLA_LINKINFO of `ip link show gre6` reply is kept.
+#[test]
+fn test_ip6gre_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x37, 0x03, 0x0c, 0x00, 0x00, 0x00, 0xd1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xac, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x36, 0x67, 0x72, 0x65, 0x00, 0x00, 0x9c, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x06, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x14, 0x00, 0x07, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x05, 0x00, 0x08, 0x00,
+        0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x0b, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0d, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x0e, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x10, 0x00,
+        0x15, 0xb3, 0x00, 0x00, 0x06, 0x00, 0x11, 0x00, 0x1a, 0x0a, 0x00, 0x00,
+        0x06, 0x00, 0x0f, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 12,
+            link_layer_type: LinkLayerType::Ip6gre,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::GreTun6),
+            LinkInfo::Data(InfoData::GreTun6(vec![
+                InfoGreTun6::Link(0),
+                InfoGreTun6::IFlags(GreFlags::empty()),
+                InfoGreTun6::OFlags(GreFlags::empty()),
+                InfoGreTun6::IKey(0),
+                InfoGreTun6::OKey(0),
+                InfoGreTun6::Local(Ipv6Addr::from_str("2001:db8::1").unwrap()),
+                InfoGreTun6::Remote(Ipv6Addr::from_str("2001:db8::2").unwrap()),
+                InfoGreTun6::Ttl(64),
+                InfoGreTun6::EncapLimit(4),
+                InfoGreTun6::FlowInfo(0),
+                InfoGreTun6::Flags(
+                    Ip6TunnelFlags::UseOrigTclass
+                        | Ip6TunnelFlags::UseOrigFlowlabel,
+                ),
+                InfoGreTun6::FwMark(0),
+                InfoGreTun6::EncapType(TunnelEncapType::Fou),
+                InfoGreTun6::EncapSourcePort(5555),
+                InfoGreTun6::EncapDestinationPort(6666),
+                InfoGreTun6::EncapFlags(TunnelEncapFlags::Csum),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add erspan1 type erspan local 192.0.2.1 remote 192.0.2.2 \
+//          seq key 10 erspan_ver 2 erspan_dir egress erspan_hwid 7
+//      ip link set erspan1 up
+// Only the IFLA_LINKINFO of `ip link show erspan1` reply is kept.
+#[test]
+fn test_erspan_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xac, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x65, 0x72, 0x73, 0x70, 0x61, 0x6e, 0x00, 0x00, 0x9c, 0x00, 0x02, 0x00,
+        0x05, 0x00, 0x16, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00, 0x17, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x18, 0x00, 0x07, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x30, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x30, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x0a, 0x08, 0x00, 0x06, 0x00, 0xc0, 0x00, 0x02, 0x01,
+        0x08, 0x00, 0x07, 0x00, 0xc0, 0x00, 0x02, 0x02, 0x05, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 14,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Up
+                | LinkFlags::Broadcast
+                | LinkFlags::Running
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Erspan),
+            LinkInfo::Data(InfoData::Erspan(vec![
+                InfoErspan::ErspanVersion(2),
+                InfoErspan::ErspanDirection(ErspanDirection::Egress),
+                InfoErspan::ErspanHardwareId(7),
+                InfoErspan::Link(0),
+                InfoErspan::IFlags(GreFlags::Key | GreFlags::Seq),
+                InfoErspan::OFlags(GreFlags::Key | GreFlags::Seq),
+                InfoErspan::IKey(10),
+                InfoErspan::OKey(10),
+                InfoErspan::Local(Ipv4Addr::new(192, 0, 2, 1)),
+                InfoErspan::Remote(Ipv4Addr::new(192, 0, 2, 2)),
+                InfoErspan::Ttl(0),
+                InfoErspan::Tos(0),
+                InfoErspan::PathMtuDiscovery(true),
+                InfoErspan::FwMark(0),
+                InfoErspan::EncapType(TunnelEncapType::None),
+                InfoErspan::EncapSourcePort(0),
+                InfoErspan::EncapDestinationPort(0),
+                InfoErspan::EncapFlags(TunnelEncapFlags::empty()),
+                InfoErspan::IgnoreDf(false),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_ip6erspan_parse_back() {
+    for orig in [
+        InfoErspan6::ErspanVersion(1),
+        InfoErspan6::ErspanIndex(123),
+        InfoErspan6::ErspanDirection(ErspanDirection::Other(5)),
+        InfoErspan6::ErspanHardwareId(0x3f),
+        InfoErspan6::Remote(Ipv6Addr::from_str("2001:db8::2").unwrap()),
+    ] {
+        let mut buf = vec![0; orig.buffer_len()];
+        orig.emit(&mut buf);
+        let parsed =
+            InfoErspan6::parse(&NlaBuffer::new_checked(&buf).unwrap()).unwrap();
+        assert_eq!(parsed, orig);
+    }
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use netlink_packet_utils::{nla::NlaBuffer, Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    GtpRole, InfoData, InfoGtp, InfoKind, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      GTP device created with IFLA_GTP_CREATE_SOCKETS, IFLA_GTP_LOCAL
+//      192.0.2.1, IFLA_GTP_ROLE sgsn, IFLA_GTP_PDP_HASHSIZE 2048 and
+//      IFLA_GTP_RESTART_COUNT 3, then brought up.
+// Only the IFLA_LINKINFO of `ip link show gtp1` reply is kept.
+#[test]
+fn test_gtp_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0xfe, 0xff, 0x11, 0x00, 0x00, 0x00, 0xd1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x67, 0x74, 0x70, 0x00, 0x24, 0x00, 0x02, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x08, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x06, 0x00, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00,
+        0xc0, 0x00, 0x02, 0x01,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 17,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Gtp),
+            LinkInfo::Data(InfoData::Gtp(vec![
+                InfoGtp::PdpHashsize(2048),
+                InfoGtp::Role(GtpRole::Sgsn),
+                InfoGtp::RestartCount(3),
+                InfoGtp::Local(Ipv4Addr::new(192, 0, 2, 1)),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_gtp_create_sockets_parse_back() {
+    for orig in [
+        InfoGtp::Fd0(3),
+        InfoGtp::Fd1(4),
+        InfoGtp::CreateSockets(true),
+        InfoGtp::Role(GtpRole::Other(9)),
+    ] {
+        let mut buf = vec![0; orig.buffer_len()];
+        orig.emit(&mut buf);
+        let parsed =
+            InfoGtp::parse(&NlaBuffer::new_checked(&buf).unwrap()).unwrap();
+        assert_eq!(parsed, orig);
+    }
+}
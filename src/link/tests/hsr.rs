@@ -56,3 +56,57 @@ fn test_parsing_link_hsr() {
 
     assert_eq!(buf, raw);
 }
+
+// IFLA_LINKINFO of `ip link show hsr1` reply, laid out in the order
+// of hsr_fill_info() for an HSR (not PRP, which rejects interlink) device:
+//      ip link add name hsr1 type hsr slave1 eth1 slave2 eth2 \
+//          interlink eth3
+//      ip link set hsr1 up
+#[test]
+fn test_parsing_link_hsr_interlink() {
+    let raw = vec![
+        0x00, 0x00, 0x01, 0x00, 0x2e, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x44, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x68, 0x73, 0x72, 0x00, 0x38, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x2c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x2a, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x08, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x04, 0x00,
+        0x01, 0x15, 0x4e, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x05, 0x00,
+        0x04, 0xfc, 0x00, 0x00, 0x05, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 46,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Broadcast
+                | LinkFlags::LowerUp
+                | LinkFlags::Multicast
+                | LinkFlags::Running
+                | LinkFlags::Up,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Hsr),
+            LinkInfo::Data(InfoData::Hsr(vec![
+                InfoHsr::Port1(44),
+                InfoHsr::Port2(42),
+                InfoHsr::Interlink(43),
+                InfoHsr::SupervisionAddr([0x01, 0x15, 0x4e, 0x00, 0x01, 0x00]),
+                InfoHsr::SeqNr(64516),
+                InfoHsr::Protocol(HsrProtocol::Hsr),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
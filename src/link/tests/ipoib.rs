@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoIpoib, InfoKind, IpoibMode, LinkAttribute, LinkHeader,
+    LinkInfo, LinkLayerType, LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip link add link ib0 name ib0.8001 type ipoib pkey 0x8001 \
+//          mode connected umcast 1
+//      ip link set ib0.8001 up
+// Only the IFLA_LINKINFO of `ip link show ib0.8001` reply is kept.
+#[test]
+fn test_ipoib_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x20, 0x00, 0x07, 0x00, 0x00, 0x00, 0x43, 0x10, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x12, 0x00, 0x0a, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x6f, 0x69, 0x62, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x02, 0x00,
+        0x06, 0x00, 0x01, 0x00, 0x01, 0x80, 0x00, 0x00, 0x06, 0x00, 0x02, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 7,
+            link_layer_type: LinkLayerType::Infiniband,
+            flags: LinkFlags::Up
+                | LinkFlags::Broadcast
+                | LinkFlags::Running
+                | LinkFlags::Multicast
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Ipoib),
+            LinkInfo::Data(InfoData::Ipoib(vec![
+                InfoIpoib::Pkey(0x8001),
+                InfoIpoib::Mode(IpoibMode::Connected),
+                InfoIpoib::UmCast(1),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
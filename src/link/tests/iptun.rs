@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoIp6Tun, InfoIpTun, InfoKind, InfoSitTun, Ip6TunnelFlags,
+    LinkAttribute, LinkHeader, LinkInfo, LinkLayerType, LinkMessage,
+    LinkMessageBuffer, SitFlags, TunnelEncapFlags, TunnelEncapType,
+};
+use crate::{AddressFamily, IpProtocol};
+
+// IFLA_LINKINFO of `ip link show ipip1` reply, laid out in the order of
+// ipip_fill_info() for:
+//      ip link add ipip1 type ipip local 192.0.2.1 remote 192.0.2.2 ttl 64
+//      ip link set ipip1 up
+#[test]
+fn test_ipip_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x03, 0x0f, 0x00, 0x00, 0x00, 0xd1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x74, 0x00, 0x12, 0x00, 0x09, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x69, 0x70, 0x00, 0x00, 0x00, 0x00, 0x64, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x03, 0x00, 0xc0, 0x00, 0x02, 0x02,
+        0x05, 0x00, 0x04, 0x00, 0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 15,
+            link_layer_type: LinkLayerType::Tunnel,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::IpTun),
+            LinkInfo::Data(InfoData::IpTun(vec![
+                InfoIpTun::Link(0),
+                InfoIpTun::Local(Ipv4Addr::new(192, 0, 2, 1)),
+                InfoIpTun::Remote(Ipv4Addr::new(192, 0, 2, 2)),
+                InfoIpTun::Ttl(64),
+                InfoIpTun::Tos(0),
+                InfoIpTun::Protocol(IpProtocol::Ipip),
+                InfoIpTun::PathMtuDiscovery(true),
+                InfoIpTun::FwMark(0),
+                InfoIpTun::EncapType(TunnelEncapType::None),
+                InfoIpTun::EncapSourcePort(0),
+                InfoIpTun::EncapDestinationPort(0),
+                InfoIpTun::EncapFlags(TunnelEncapFlags::empty()),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add sit1 type sit local 192.0.2.1 ttl 64
+//      ip tunnel 6rd dev sit1 6rd-prefix 2001:db8::/32 \
+//          6rd-relay_prefix 192.0.2.0/24
+//      ip link set sit1 up
+// Only the IFLA_LINKINFO of `ip link show sit1` reply is kept.
+#[test]
+fn test_sit_6rd_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x08, 0x03, 0x0d, 0x00, 0x00, 0x00, 0xc1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xa4, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x73, 0x69, 0x74, 0x00, 0x98, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0xc0, 0x00, 0x02, 0x01,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00,
+        0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x0a, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x29, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x0b, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0xc0, 0x00, 0x02, 0x00,
+        0x06, 0x00, 0x0d, 0x00, 0x20, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0e, 0x00,
+        0x18, 0x00, 0x00, 0x00, 0x06, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x12, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 13,
+            link_layer_type: LinkLayerType::Sit,
+            flags: LinkFlags::Up
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::SitTun),
+            LinkInfo::Data(InfoData::SitTun(vec![
+                InfoSitTun::Link(0),
+                InfoSitTun::Local(Ipv4Addr::new(192, 0, 2, 1)),
+                InfoSitTun::Remote(Ipv4Addr::UNSPECIFIED),
+                InfoSitTun::Ttl(64),
+                InfoSitTun::Tos(0),
+                InfoSitTun::PathMtuDiscovery(true),
+                InfoSitTun::Flags(SitFlags::empty()),
+                InfoSitTun::Protocol(IpProtocol::Ipv6),
+                InfoSitTun::FwMark(0),
+                InfoSitTun::Ip6RdPrefix(
+                    Ipv6Addr::from_str("2001:db8::").unwrap(),
+                ),
+                InfoSitTun::Ip6RdRelayPrefix(Ipv4Addr::new(192, 0, 2, 0)),
+                InfoSitTun::Ip6RdPrefixLen(32),
+                InfoSitTun::Ip6RdRelayPrefixLen(24),
+                InfoSitTun::EncapType(TunnelEncapType::None),
+                InfoSitTun::EncapSourcePort(0),
+                InfoSitTun::EncapDestinationPort(0),
+                InfoSitTun::EncapFlags(TunnelEncapFlags::empty()),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add ip6tnl1 type ip6tnl mode ip6ip6 local 2001:db8::1 \
+//          remote 2001:db8::2 encaplimit none
+//      ip link set ip6tnl1 up
+// Only the IFLA_LINKINFO of `ip link show ip6tnl1` reply is kept.
+#[test]
+fn test_ip6tnl_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x03, 0x0e, 0x00, 0x00, 0x00, 0xd1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x94, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x69, 0x70, 0x36, 0x74, 0x6e, 0x6c, 0x00, 0x00, 0x84, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x02, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x14, 0x00, 0x03, 0x00, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        0x05, 0x00, 0x04, 0x00, 0x40, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00,
+        0x29, 0x00, 0x00, 0x00, 0x08, 0x00, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x11, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x12, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 14,
+            link_layer_type: LinkLayerType::Tunnel6,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Ip6Tun),
+            LinkInfo::Data(InfoData::Ip6Tun(vec![
+                InfoIp6Tun::Link(0),
+                InfoIp6Tun::Local(Ipv6Addr::from_str("2001:db8::1").unwrap()),
+                InfoIp6Tun::Remote(Ipv6Addr::from_str("2001:db8::2").unwrap()),
+                InfoIp6Tun::Ttl(64),
+                InfoIp6Tun::EncapLimit(4),
+                InfoIp6Tun::FlowInfo(0),
+                InfoIp6Tun::Flags(Ip6TunnelFlags::IgnoreEncapLimit),
+                InfoIp6Tun::Protocol(IpProtocol::Ipv6),
+                InfoIp6Tun::FwMark(0),
+                InfoIp6Tun::EncapType(TunnelEncapType::None),
+                InfoIp6Tun::EncapSourcePort(0),
+                InfoIp6Tun::EncapDestinationPort(0),
+                InfoIp6Tun::EncapFlags(TunnelEncapFlags::empty()),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
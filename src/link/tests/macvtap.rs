@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: MIT
 
-use netlink_packet_utils::{nla::DefaultNla, Emitable, Parseable};
+use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::link::link_flag::LinkFlags;
 use crate::link::{
@@ -126,15 +126,15 @@ fn test_macvtap_link_info() {
             LinkAttribute::MaxMtu(2304),
             LinkAttribute::Group(0),
             LinkAttribute::Promiscuity(0),
-            LinkAttribute::Other(DefaultNla::new(61, vec![0, 0, 0, 0])),
+            LinkAttribute::AllMulti(0),
             LinkAttribute::NumTxQueues(1),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(63, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(64, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(59, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::GsoIpv4MaxSize(65536),
+            LinkAttribute::GroIpv4MaxSize(65536),
+            LinkAttribute::TsoMaxSize(65536),
+            LinkAttribute::TsoMaxSegs(65535),
             LinkAttribute::NumRxQueues(1),
             LinkAttribute::Carrier(1),
             LinkAttribute::Qdisc("fq_codel".to_string()),
@@ -326,8 +326,7 @@ fn test_macvtap_link_info() {
                     }),
                 ]),
             ]),
-            // TODO: Need to parse NLA_F_NESTED|IFLA_DEVLINK_PORT
-            LinkAttribute::Other(DefaultNla::new(32830, Vec::new())),
+            LinkAttribute::DevlinkPort(Vec::new()),
         ],
     };
 
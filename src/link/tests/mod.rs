@@ -3,14 +3,30 @@
 #[cfg(test)]
 mod afspec;
 #[cfg(test)]
+mod amt;
+#[cfg(test)]
+mod bareudp;
+#[cfg(test)]
 mod bond;
 #[cfg(test)]
 mod bridge;
 #[cfg(test)]
+mod can;
+#[cfg(test)]
+mod devlink_port;
+#[cfg(test)]
 mod geneve;
 #[cfg(test)]
+mod gre;
+#[cfg(test)]
+mod gtp;
+#[cfg(test)]
 mod hsr;
 #[cfg(test)]
+mod ipoib;
+#[cfg(test)]
+mod iptun;
+#[cfg(test)]
 mod ipvlan;
 #[cfg(test)]
 mod ipvtap;
@@ -25,22 +41,26 @@ mod macvtap;
 #[cfg(test)]
 mod message;
 #[cfg(test)]
+mod netkit;
+#[cfg(test)]
 mod prop_list;
 #[cfg(test)]
 mod sriov;
 #[cfg(test)]
 mod statistics;
 #[cfg(test)]
+mod tun;
+#[cfg(test)]
 mod veth;
 #[cfg(test)]
 mod vlan;
 #[cfg(test)]
 mod vrf;
 #[cfg(test)]
+mod vti;
+#[cfg(test)]
 mod vxlan;
 #[cfg(test)]
 mod xdp;
 #[cfg(test)]
 mod xfrm;
-
-//TODO(Gris Ge): capture netlink message for ipoib
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoKind, InfoNetkit, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer, NetkitMode, NetkitPolicy,
+    NetkitScrub,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip link add nk0 type netkit peer policy drop name nk1
+//      ip link set nk0 up
+// Only the IFLA_LINKINFO of `ip link show nk0` reply is kept.
+#[test]
+fn test_netkit_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0xfe, 0xff, 0x09, 0x00, 0x00, 0x00, 0xc1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x44, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00,
+        0x6e, 0x65, 0x74, 0x6b, 0x69, 0x74, 0x00, 0x00, 0x34, 0x00, 0x02, 0x00,
+        0x05, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x07, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 9,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::Up
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Netkit),
+            LinkInfo::Data(InfoData::Netkit(vec![
+                InfoNetkit::Primary(true),
+                InfoNetkit::Policy(NetkitPolicy::Pass),
+                InfoNetkit::Mode(NetkitMode::L3),
+                InfoNetkit::Scrub(NetkitScrub::Default),
+                InfoNetkit::PeerPolicy(NetkitPolicy::Drop),
+                InfoNetkit::PeerScrub(NetkitScrub::Default),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add nk0 type netkit mode l2 peer name nk1
+// This is the RTM_NEWLINK request sent by iproute2.
+#[test]
+fn test_create_netkit() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x6e, 0x6b, 0x30, 0x00,
+        0x38, 0x00, 0x12, 0x00, 0x0b, 0x00, 0x01, 0x00, 0x6e, 0x65, 0x74, 0x6b,
+        0x69, 0x74, 0x00, 0x00, 0x28, 0x00, 0x02, 0x00, 0x1c, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x6e, 0x6b, 0x31, 0x00,
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader::default(),
+        attributes: vec![
+            LinkAttribute::IfName("nk0".into()),
+            LinkAttribute::LinkInfo(vec![
+                LinkInfo::Kind(InfoKind::Netkit),
+                LinkInfo::Data(InfoData::Netkit(vec![
+                    InfoNetkit::PeerInfo(LinkMessage {
+                        header: LinkHeader::default(),
+                        attributes: vec![LinkAttribute::IfName("nk1".into())],
+                    }),
+                    InfoNetkit::Mode(NetkitMode::L2),
+                ])),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
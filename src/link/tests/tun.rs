@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoKind, InfoTun, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer, TunMode,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip tuntap add tap0 mode tap user 1000 group 1000 multi_queue
+// Only the IFLA_LINKINFO of `ip -d link show tap0` reply is kept.
+#[test]
+fn test_tap_multi_queue_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x00, 0x12, 0x00, 0x00, 0x00, 0x02, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x58, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x74, 0x75, 0x6e, 0x00, 0x4c, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0xe8, 0x03, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0xe8, 0x03, 0x00, 0x00,
+        0x05, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x06, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x07, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 18,
+            link_layer_type: LinkLayerType::Ether,
+            flags: LinkFlags::Broadcast | LinkFlags::Multicast,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Tun),
+            LinkInfo::Data(InfoData::Tun(vec![
+                InfoTun::Owner(1000),
+                InfoTun::Group(1000),
+                InfoTun::Type(TunMode::Tap),
+                InfoTun::Pi(false),
+                InfoTun::VnetHdr(false),
+                InfoTun::Persist(true),
+                InfoTun::MultiQueue(true),
+                InfoTun::NumQueues(0),
+                InfoTun::NumDisabledQueues(0),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip tuntap add tun0 mode tun pi vnet_hdr
+// Only the IFLA_LINKINFO of `ip -d link show tun0` reply is kept.
+#[test]
+fn test_tun_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0xfe, 0xff, 0x13, 0x00, 0x00, 0x00, 0x90, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x38, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x74, 0x75, 0x6e, 0x00, 0x2c, 0x00, 0x02, 0x00, 0x05, 0x00, 0x03, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x04, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x05, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x06, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 19,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::Pointopoint
+                | LinkFlags::Noarp
+                | LinkFlags::Multicast,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Tun),
+            LinkInfo::Data(InfoData::Tun(vec![
+                InfoTun::Type(TunMode::Tun),
+                InfoTun::Pi(true),
+                InfoTun::VnetHdr(true),
+                InfoTun::Persist(true),
+                InfoTun::MultiQueue(false),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -2,7 +2,6 @@
 
 use std::net::Ipv6Addr;
 
-use netlink_packet_utils::nla::DefaultNla;
 use netlink_packet_utils::{Emitable, Parseable};
 
 use crate::link::link_flag::LinkFlags;
@@ -254,13 +253,13 @@ fn test_link_info_with_ifla_vrf_port_table() {
             LinkAttribute::MaxMtu(65535),
             LinkAttribute::Group(0),
             LinkAttribute::Promiscuity(0),
-            LinkAttribute::Other(DefaultNla::new(61, vec![0, 0, 0, 0])),
+            LinkAttribute::AllMulti(0),
             LinkAttribute::NumTxQueues(26),
             LinkAttribute::GsoMaxSegs(65535),
             LinkAttribute::GsoMaxSize(65536),
-            LinkAttribute::Other(DefaultNla::new(58, vec![0, 0, 1, 0])),
-            LinkAttribute::Other(DefaultNla::new(59, vec![248, 255, 7, 0])),
-            LinkAttribute::Other(DefaultNla::new(60, vec![255, 255, 0, 0])),
+            LinkAttribute::GroMaxSize(65536),
+            LinkAttribute::TsoMaxSize(524280),
+            LinkAttribute::TsoMaxSegs(65535),
             LinkAttribute::NumRxQueues(26),
             LinkAttribute::Controller(33),
             LinkAttribute::Carrier(0),
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::link::link_flag::LinkFlags;
+use crate::link::{
+    InfoData, InfoKind, InfoVti, InfoVti6, LinkAttribute, LinkHeader, LinkInfo,
+    LinkLayerType, LinkMessage, LinkMessageBuffer,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      ip link add vti1 type vti local 192.0.2.1 remote 192.0.2.2 key 100
+//      ip link set vti1 up
+// Only the IFLA_LINKINFO of `ip link show vti1` reply is kept.
+#[test]
+fn test_vti_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x03, 0x0f, 0x00, 0x00, 0x00, 0xd1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x12, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x76, 0x74, 0x69, 0x00, 0x34, 0x00, 0x02, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x64,
+        0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x64, 0x08, 0x00, 0x04, 0x00,
+        0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x02, 0x02,
+        0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 15,
+            link_layer_type: LinkLayerType::Tunnel,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Vti),
+            LinkInfo::Data(InfoData::Vti(vec![
+                InfoVti::Link(0),
+                InfoVti::IKey(100),
+                InfoVti::OKey(100),
+                InfoVti::Local(Ipv4Addr::new(192, 0, 2, 1)),
+                InfoVti::Remote(Ipv4Addr::new(192, 0, 2, 2)),
+                InfoVti::FwMark(0),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add vti6 type vti6 local 2001:db8::1 remote 2001:db8::2 \
+//          key 200 fwmark 0x10
+//      ip link set vti6 up
+// Only the IFLA_LINKINFO of `ip link show vti6` reply is kept.
+#[test]
+fn test_vti6_link_info() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x03, 0x10, 0x00, 0x00, 0x00, 0xd1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x5c, 0x00, 0x12, 0x00, 0x09, 0x00, 0x01, 0x00,
+        0x76, 0x74, 0x69, 0x36, 0x00, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0xc8, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0xc8,
+        0x14, 0x00, 0x04, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x14, 0x00, 0x05, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x06, 0x00, 0x10, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 16,
+            link_layer_type: LinkLayerType::Tunnel6,
+            flags: LinkFlags::Up
+                | LinkFlags::Pointopoint
+                | LinkFlags::Running
+                | LinkFlags::Noarp
+                | LinkFlags::LowerUp,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Vti6),
+            LinkInfo::Data(InfoData::Vti6(vec![
+                InfoVti6::Link(0),
+                InfoVti6::IKey(200),
+                InfoVti6::OKey(200),
+                InfoVti6::Local(Ipv6Addr::from_str("2001:db8::1").unwrap()),
+                InfoVti6::Remote(Ipv6Addr::from_str("2001:db8::2").unwrap()),
+                InfoVti6::FwMark(0x10),
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
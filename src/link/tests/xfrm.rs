@@ -50,3 +50,49 @@ fn test_parsing_link_xfrm() {
 
     assert_eq!(buf, raw);
 }
+
+// Setup:
+//      ip link add xfrm1 type xfrm external
+//      ip link set xfrm1 up
+#[test]
+fn test_parsing_link_xfrm_collect_metadata() {
+    let raw = vec![
+        0x00, 0x00, 0xfe, 0xff, 0x29, 0x00, 0x00, 0x00, 0xc1, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x12, 0x00, 0x09, 0x00, 0x01, 0x00,
+        0x78, 0x66, 0x72, 0x6d, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x03, 0x00,
+    ];
+
+    let expected = LinkMessage {
+        header: LinkHeader {
+            interface_family: AddressFamily::Unspec,
+            index: 41,
+            link_layer_type: LinkLayerType::None,
+            flags: LinkFlags::LowerUp
+                | LinkFlags::Noarp
+                | LinkFlags::Running
+                | LinkFlags::Up,
+            change_mask: LinkFlags::empty(),
+        },
+        attributes: vec![LinkAttribute::LinkInfo(vec![
+            LinkInfo::Kind(InfoKind::Xfrm),
+            LinkInfo::Data(InfoData::Xfrm(vec![
+                InfoXfrm::Link(0),
+                InfoXfrm::IfId(0),
+                InfoXfrm::CollectMetadata,
+            ])),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        LinkMessage::parse(&LinkMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
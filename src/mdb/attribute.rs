@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{MdbGroup, MdbRouter};
+
+const MDBA_MDB: u16 = 1;
+const MDBA_ROUTER: u16 = 2;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbAttribute {
+    Mdb(Vec<MdbGroup>),
+    /// Multicast router ports of the bridge
+    Router(Vec<MdbRouter>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Mdb(nlas) => nlas.as_slice().buffer_len(),
+            Self::Router(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mdb(nlas) => nlas.as_slice().emit(buffer),
+            Self::Router(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mdb(_) => MDBA_MDB,
+            Self::Router(_) => MDBA_ROUTER,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbAttribute {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(MdbGroup::parse(nla)?);
+                }
+                Self::Mdb(nlas)
+            }
+            MDBA_ROUTER => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(MdbRouter::parse(nla)?);
+                }
+                Self::Router(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
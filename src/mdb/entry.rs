@@ -0,0 +1,550 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    ip::{emit_ip_addr, ip_addr_len, parse_ip_addr},
+    route::RouteProtocol,
+};
+
+const MDBA_MDB_ENTRY: u16 = 1;
+
+const MDBA_MDB_ENTRY_INFO: u16 = 1;
+
+const MDBA_MDB_EATTR_TIMER: u16 = 1;
+const MDBA_MDB_EATTR_SRC_LIST: u16 = 2;
+const MDBA_MDB_EATTR_GROUP_MODE: u16 = 3;
+const MDBA_MDB_EATTR_SOURCE: u16 = 4;
+const MDBA_MDB_EATTR_RTPROT: u16 = 5;
+const MDBA_MDB_EATTR_DST: u16 = 6;
+const MDBA_MDB_EATTR_DST_PORT: u16 = 7;
+const MDBA_MDB_EATTR_VNI: u16 = 8;
+const MDBA_MDB_EATTR_IFINDEX: u16 = 9;
+const MDBA_MDB_EATTR_SRC_VNI: u16 = 10;
+
+const MDBA_MDB_SRCLIST_ENTRY: u16 = 1;
+
+const MDBA_MDB_SRCATTR_ADDRESS: u16 = 1;
+const MDBA_MDB_SRCATTR_TIMER: u16 = 2;
+
+/// Attributes nested in `MDBA_MDB`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbGroup {
+    /// All ports joined to the same multicast group
+    Entry(Vec<MdbEntry>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbGroup {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => MDBA_MDB_ENTRY,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbGroup {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(MdbEntry::parse(nla)?);
+                }
+                Self::Entry(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbEntry {
+    Info(MdbEntryInfo),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Info(value) => value.buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Info(value) => value.emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Info(_) => MDBA_MDB_ENTRY_INFO,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntry {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY_INFO => Self::Info(MdbEntryInfo::parse(
+                &MdbEntryInfoBuffer::new_checked(payload)?,
+            )?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const MDB_ENTRY_INFO_LEN: usize = 28;
+
+buffer!(MdbEntryInfoBuffer(MDB_ENTRY_INFO_LEN) {
+    ifindex: (u32, 0..4),
+    state: (u8, 4),
+    flags: (u8, 5),
+    vid: (u16, 6..8),
+    address: (slice, 8..24),
+    protocol: (slice, 24..26),
+    payload: (slice, MDB_ENTRY_INFO_LEN..),
+});
+
+/// The `MDBA_MDB_ENTRY_INFO` holds the kernel `struct br_mdb_entry` followed
+/// by `MDBA_MDB_EATTR_*` attributes.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct MdbEntryInfo {
+    /// Interface index of bridge port
+    pub ifindex: u32,
+    pub state: MdbState,
+    pub flags: MdbFlags,
+    pub vid: u16,
+    pub address: MdbAddress,
+    pub attributes: Vec<MdbEntryAttribute>,
+}
+
+impl Emitable for MdbEntryInfo {
+    fn buffer_len(&self) -> usize {
+        MDB_ENTRY_INFO_LEN + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet =
+            MdbEntryInfoBuffer::new(&mut buffer[..MDB_ENTRY_INFO_LEN]);
+        packet.set_ifindex(self.ifindex);
+        packet.set_state(self.state.into());
+        packet.set_flags(self.flags.bits());
+        packet.set_vid(self.vid);
+        self.address.emit(packet.address_mut());
+        BigEndian::write_u16(packet.protocol_mut(), self.address.protocol());
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[MDB_ENTRY_INFO_LEN..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<MdbEntryInfoBuffer<&'a T>>
+    for MdbEntryInfo
+{
+    type Error = DecodeError;
+    fn parse(buf: &MdbEntryInfoBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(buf.payload()) {
+            let nla = &nla?;
+            attributes.push(MdbEntryAttribute::parse(nla)?);
+        }
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            state: buf.state().into(),
+            flags: MdbFlags::from_bits_retain(buf.flags()),
+            vid: buf.vid(),
+            address: MdbAddress::parse_with_param(
+                parse_u16_be(buf.protocol())?,
+                buf.address(),
+            ),
+            attributes,
+        })
+    }
+}
+
+const ETH_P_IP: u16 = 0x0800;
+const ETH_P_IPV6: u16 = 0x86DD;
+
+/// Multicast group address of `struct br_mdb_entry`, the variant is
+/// determined by the Ethernet protocol stored along with it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum MdbAddress {
+    Inet(Ipv4Addr),
+    Inet6(Ipv6Addr),
+    /// Layer 2 multicast MAC address, protocol is 0
+    Mac([u8; 6]),
+    Other {
+        protocol: u16,
+        address: [u8; 16],
+    },
+}
+
+impl MdbAddress {
+    fn parse_with_param(protocol: u16, payload: &[u8]) -> Self {
+        let mut address = [0u8; 16];
+        address.copy_from_slice(payload);
+        match protocol {
+            ETH_P_IP => Self::Inet(Ipv4Addr::new(
+                address[0], address[1], address[2], address[3],
+            )),
+            ETH_P_IPV6 => Self::Inet6(Ipv6Addr::from(address)),
+            0 => {
+                let mut mac = [0u8; 6];
+                mac.copy_from_slice(&address[..6]);
+                Self::Mac(mac)
+            }
+            _ => Self::Other { protocol, address },
+        }
+    }
+
+    /// Ethernet protocol of this address
+    pub fn protocol(&self) -> u16 {
+        match self {
+            Self::Inet(_) => ETH_P_IP,
+            Self::Inet6(_) => ETH_P_IPV6,
+            Self::Mac(_) => 0,
+            Self::Other { protocol, .. } => *protocol,
+        }
+    }
+}
+
+impl Emitable for MdbAddress {
+    fn buffer_len(&self) -> usize {
+        16
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[..16].fill(0);
+        match self {
+            Self::Inet(v) => buffer[..4].copy_from_slice(&v.octets()),
+            Self::Inet6(v) => buffer[..16].copy_from_slice(&v.octets()),
+            Self::Mac(v) => buffer[..6].copy_from_slice(v),
+            Self::Other { address, .. } => {
+                buffer[..16].copy_from_slice(address)
+            }
+        }
+    }
+}
+
+const MDB_TEMPORARY: u8 = 0;
+const MDB_PERMANENT: u8 = 1;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbState {
+    /// Learned by IGMP/MLD snooping
+    Temporary,
+    /// Statically configured
+    Permanent,
+    Other(u8),
+}
+
+impl From<u8> for MdbState {
+    fn from(d: u8) -> Self {
+        match d {
+            MDB_TEMPORARY => Self::Temporary,
+            MDB_PERMANENT => Self::Permanent,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MdbState> for u8 {
+    fn from(v: MdbState) -> u8 {
+        match v {
+            MdbState::Temporary => MDB_TEMPORARY,
+            MdbState::Permanent => MDB_PERMANENT,
+            MdbState::Other(d) => d,
+        }
+    }
+}
+
+const MDB_FLAGS_OFFLOAD: u8 = 1 << 0;
+const MDB_FLAGS_FAST_LEAVE: u8 = 1 << 1;
+const MDB_FLAGS_STAR_EXCL: u8 = 1 << 2;
+const MDB_FLAGS_BLOCKED: u8 = 1 << 3;
+const MDB_FLAGS_OFFLOAD_FAILED: u8 = 1 << 4;
+
+bitflags! {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct MdbFlags: u8 {
+        const Offload = MDB_FLAGS_OFFLOAD;
+        const FastLeave = MDB_FLAGS_FAST_LEAVE;
+        /// Port was added by (*, G) exclude mode entry
+        const StarExcl = MDB_FLAGS_STAR_EXCL;
+        const Blocked = MDB_FLAGS_BLOCKED;
+        const OffloadFailed = MDB_FLAGS_OFFLOAD_FAILED;
+        const _ = !0;
+    }
+}
+
+/// Attributes following `struct br_mdb_entry` in `MDBA_MDB_ENTRY_INFO`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbEntryAttribute {
+    /// Remaining time in centiseconds
+    Timer(u32),
+    SourceList(Vec<MdbSourceList>),
+    GroupMode(MdbGroupMode),
+    /// Source address of (S, G) entry
+    Source(IpAddr),
+    /// Protocol which installed this entry
+    Protocol(RouteProtocol),
+    /// Remote VTEP address of VXLAN MDB entry
+    Destination(IpAddr),
+    DestinationPort(u16),
+    Vni(u32),
+    IfIndex(u32),
+    SourceVni(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::SourceList(nlas) => nlas.as_slice().buffer_len(),
+            Self::Source(value) => ip_addr_len(value),
+            Self::Destination(value) => ip_addr_len(value),
+            Self::Timer(_)
+            | Self::Vni(_)
+            | Self::IfIndex(_)
+            | Self::SourceVni(_) => 4,
+            Self::DestinationPort(_) => 2,
+            Self::GroupMode(_) | Self::Protocol(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::SourceList(nlas) => nlas.as_slice().emit(buffer),
+            Self::Source(value) => emit_ip_addr(value, buffer),
+            Self::Destination(value) => emit_ip_addr(value, buffer),
+            Self::Timer(value)
+            | Self::Vni(value)
+            | Self::IfIndex(value)
+            | Self::SourceVni(value) => NativeEndian::write_u32(buffer, *value),
+            Self::GroupMode(value) => buffer[0] = (*value).into(),
+            Self::Protocol(value) => buffer[0] = (*value).into(),
+            Self::DestinationPort(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Timer(_) => MDBA_MDB_EATTR_TIMER,
+            Self::SourceList(_) => MDBA_MDB_EATTR_SRC_LIST,
+            Self::GroupMode(_) => MDBA_MDB_EATTR_GROUP_MODE,
+            Self::Source(_) => MDBA_MDB_EATTR_SOURCE,
+            Self::Protocol(_) => MDBA_MDB_EATTR_RTPROT,
+            Self::Destination(_) => MDBA_MDB_EATTR_DST,
+            Self::DestinationPort(_) => MDBA_MDB_EATTR_DST_PORT,
+            Self::Vni(_) => MDBA_MDB_EATTR_VNI,
+            Self::IfIndex(_) => MDBA_MDB_EATTR_IFINDEX,
+            Self::SourceVni(_) => MDBA_MDB_EATTR_SRC_VNI,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbEntryAttribute
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_EATTR_TIMER => Self::Timer(parse_u32(payload)?),
+            MDBA_MDB_EATTR_SRC_LIST => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(MdbSourceList::parse(nla)?);
+                }
+                Self::SourceList(nlas)
+            }
+            MDBA_MDB_EATTR_GROUP_MODE => {
+                Self::GroupMode(parse_u8(payload)?.into())
+            }
+            MDBA_MDB_EATTR_SOURCE => Self::Source(parse_ip_addr(payload)?),
+            MDBA_MDB_EATTR_RTPROT => Self::Protocol(parse_u8(payload)?.into()),
+            MDBA_MDB_EATTR_DST => Self::Destination(parse_ip_addr(payload)?),
+            MDBA_MDB_EATTR_DST_PORT => {
+                Self::DestinationPort(parse_u16(payload)?)
+            }
+            MDBA_MDB_EATTR_VNI => Self::Vni(parse_u32(payload)?),
+            MDBA_MDB_EATTR_IFINDEX => Self::IfIndex(parse_u32(payload)?),
+            MDBA_MDB_EATTR_SRC_VNI => Self::SourceVni(parse_u32(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbSourceList {
+    Entry(Vec<MdbSource>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSourceList {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => MDBA_MDB_SRCLIST_ENTRY,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbSourceList
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_SRCLIST_ENTRY => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(MdbSource::parse(nla)?);
+                }
+                Self::Entry(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbSource {
+    Address(IpAddr),
+    /// Remaining time in centiseconds
+    Timer(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSource {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Address(value) => ip_addr_len(value),
+            Self::Timer(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Address(value) => emit_ip_addr(value, buffer),
+            Self::Timer(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Address(_) => MDBA_MDB_SRCATTR_ADDRESS,
+            Self::Timer(_) => MDBA_MDB_SRCATTR_TIMER,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbSource {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_SRCATTR_ADDRESS => Self::Address(parse_ip_addr(payload)?),
+            MDBA_MDB_SRCATTR_TIMER => Self::Timer(parse_u32(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const MCAST_EXCLUDE: u8 = 0;
+const MCAST_INCLUDE: u8 = 1;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbGroupMode {
+    Exclude,
+    Include,
+    Other(u8),
+}
+
+impl From<u8> for MdbGroupMode {
+    fn from(d: u8) -> Self {
+        match d {
+            MCAST_EXCLUDE => Self::Exclude,
+            MCAST_INCLUDE => Self::Include,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MdbGroupMode> for u8 {
+    fn from(v: MdbGroupMode) -> u8 {
+        match v {
+            MdbGroupMode::Exclude => MCAST_EXCLUDE,
+            MdbGroupMode::Include => MCAST_INCLUDE,
+            MdbGroupMode::Other(d) => d,
+        }
+    }
+}
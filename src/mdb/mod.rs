@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod entry;
+mod header;
+mod message;
+mod router;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::MdbAttribute;
+pub use self::entry::{
+    MdbAddress, MdbEntry, MdbEntryAttribute, MdbEntryInfo, MdbEntryInfoBuffer,
+    MdbFlags, MdbGroup, MdbGroupMode, MdbSource, MdbSourceList, MdbState,
+};
+pub use self::header::{MdbHeader, MdbMessageBuffer};
+pub use self::message::MdbMessage;
+pub use self::router::{
+    MdbRouter, MdbRouterPort, MdbRouterPortAttribute, MdbRouterPortBuffer,
+};
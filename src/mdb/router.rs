@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::BridgePortMulticastRouter;
+
+const MDBA_ROUTER_PORT: u16 = 1;
+
+const MDBA_ROUTER_PATTR_TIMER: u16 = 1;
+const MDBA_ROUTER_PATTR_TYPE: u16 = 2;
+const MDBA_ROUTER_PATTR_INET_TIMER: u16 = 3;
+const MDBA_ROUTER_PATTR_INET6_TIMER: u16 = 4;
+const MDBA_ROUTER_PATTR_VID: u16 = 5;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbRouter {
+    Port(MdbRouterPort),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbRouter {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Port(value) => value.buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Port(value) => value.emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Port(_) => MDBA_ROUTER_PORT,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbRouter {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_ROUTER_PORT => Self::Port(MdbRouterPort::parse(
+                &MdbRouterPortBuffer::new_checked(payload)?,
+            )?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const MDB_ROUTER_PORT_LEN: usize = 4;
+
+buffer!(MdbRouterPortBuffer(MDB_ROUTER_PORT_LEN) {
+    ifindex: (u32, 0..4),
+    payload: (slice, MDB_ROUTER_PORT_LEN..),
+});
+
+/// The `MDBA_ROUTER_PORT` holds the interface index followed by
+/// `MDBA_ROUTER_PATTR_*` attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct MdbRouterPort {
+    pub ifindex: u32,
+    pub attributes: Vec<MdbRouterPortAttribute>,
+}
+
+impl Emitable for MdbRouterPort {
+    fn buffer_len(&self) -> usize {
+        MDB_ROUTER_PORT_LEN + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet =
+            MdbRouterPortBuffer::new(&mut buffer[..MDB_ROUTER_PORT_LEN]);
+        packet.set_ifindex(self.ifindex);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[MDB_ROUTER_PORT_LEN..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<MdbRouterPortBuffer<&'a T>>
+    for MdbRouterPort
+{
+    type Error = DecodeError;
+    fn parse(buf: &MdbRouterPortBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(buf.payload()) {
+            let nla = &nla?;
+            attributes.push(MdbRouterPortAttribute::parse(nla)?);
+        }
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            attributes,
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum MdbRouterPortAttribute {
+    /// In centiseconds
+    Timer(u32),
+    Type(BridgePortMulticastRouter),
+    /// In centiseconds
+    InetTimer(u32),
+    /// In centiseconds
+    Inet6Timer(u32),
+    Vid(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbRouterPortAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Timer(_) | Self::InetTimer(_) | Self::Inet6Timer(_) => 4,
+            Self::Vid(_) => 2,
+            Self::Type(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Timer(value)
+            | Self::InetTimer(value)
+            | Self::Inet6Timer(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Type(value) => buffer[0] = (*value).into(),
+            Self::Vid(value) => NativeEndian::write_u16(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Timer(_) => MDBA_ROUTER_PATTR_TIMER,
+            Self::Type(_) => MDBA_ROUTER_PATTR_TYPE,
+            Self::InetTimer(_) => MDBA_ROUTER_PATTR_INET_TIMER,
+            Self::Inet6Timer(_) => MDBA_ROUTER_PATTR_INET6_TIMER,
+            Self::Vid(_) => MDBA_ROUTER_PATTR_VID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbRouterPortAttribute
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_ROUTER_PATTR_TIMER => Self::Timer(parse_u32(payload)?),
+            MDBA_ROUTER_PATTR_TYPE => Self::Type(parse_u8(payload)?.into()),
+            MDBA_ROUTER_PATTR_INET_TIMER => {
+                Self::InetTimer(parse_u32(payload)?)
+            }
+            MDBA_ROUTER_PATTR_INET6_TIMER => {
+                Self::Inet6Timer(parse_u32(payload)?)
+            }
+            MDBA_ROUTER_PATTR_VID => Self::Vid(parse_u16(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
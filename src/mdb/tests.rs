@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    link::BridgePortMulticastRouter,
+    mdb::{
+        MdbAddress, MdbAttribute, MdbEntry, MdbEntryAttribute, MdbEntryInfo,
+        MdbFlags, MdbGroup, MdbGroupMode, MdbHeader, MdbMessage,
+        MdbMessageBuffer, MdbRouter, MdbRouterPort, MdbRouterPortAttribute,
+        MdbSource, MdbSourceList, MdbState,
+    },
+    route::RouteProtocol,
+    AddressFamily,
+};
+
+// Setup:
+//      # IPv6 disabled on br0, eth1 and its link partner to avoid MLD
+//      # learned entries
+//      ip link add br0 type bridge
+//      ip link set eth1 master br0
+//      ip link set br0 up
+//      ip link set eth1 type bridge_slave mcast_router 2
+//      bridge mdb add dev br0 port eth1 grp 239.1.1.1
+// Kernel reply of `bridge mdb show`, eth1 is a permanent router port.
+#[test]
+fn test_mdb_dump_with_router_port() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x38, 0x00, 0x01, 0x00,
+        0x34, 0x00, 0x01, 0x00, 0x30, 0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0xef, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0xbc, 0x64, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x02, 0x00, 0x28, 0x00, 0x01, 0x00,
+        0x05, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x05, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = MdbMessage {
+        header: MdbHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 8,
+        },
+        attributes: vec![
+            MdbAttribute::Mdb(vec![MdbGroup::Entry(vec![MdbEntry::Info(
+                MdbEntryInfo {
+                    ifindex: 5,
+                    state: MdbState::Temporary,
+                    flags: MdbFlags::empty(),
+                    vid: 0,
+                    address: MdbAddress::Inet(Ipv4Addr::new(239, 1, 1, 1)),
+                    attributes: vec![
+                        MdbEntryAttribute::Timer(25788),
+                        MdbEntryAttribute::Protocol(RouteProtocol::Static),
+                    ],
+                },
+            )])]),
+            MdbAttribute::Router(vec![MdbRouter::Port(MdbRouterPort {
+                ifindex: 5,
+                attributes: vec![
+                    MdbRouterPortAttribute::Timer(0),
+                    MdbRouterPortAttribute::Type(
+                        BridgePortMulticastRouter::Perm,
+                    ),
+                    MdbRouterPortAttribute::InetTimer(0),
+                    MdbRouterPortAttribute::Inet6Timer(0),
+                ],
+            })]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        MdbMessage::parse(&MdbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add br0 type bridge mcast_igmp_version 3 vlan_filtering 1
+//      ip link set eth1 master br0
+//      bridge vlan add vid 10 dev eth1
+//      bridge mdb add dev br0 port eth1 grp 239.1.1.1 permanent vid 10 \
+//          filter_mode include source_list 192.0.2.1 proto static
+// Kernel reply of `bridge mdb show`.
+#[test]
+fn test_mdb_dump_igmpv3_source_list() {
+    let raw: Vec<u8> = vec![
+        0x07, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x58, 0x00, 0x01, 0x00,
+        0x54, 0x00, 0x01, 0x00, 0x50, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x0a, 0x00, 0xef, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x05, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x18, 0x00, 0x02, 0x00, 0x14, 0x00, 0x01, 0x00,
+        0x08, 0x00, 0x01, 0x00, 0xc0, 0x00, 0x02, 0x01, 0x08, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = MdbMessage {
+        header: MdbHeader {
+            family: AddressFamily::Bridge,
+            ifindex: 2,
+        },
+        attributes: vec![MdbAttribute::Mdb(vec![MdbGroup::Entry(vec![
+            MdbEntry::Info(MdbEntryInfo {
+                ifindex: 3,
+                state: MdbState::Permanent,
+                flags: MdbFlags::empty(),
+                vid: 10,
+                address: MdbAddress::Inet(Ipv4Addr::new(239, 1, 1, 1)),
+                attributes: vec![
+                    MdbEntryAttribute::Timer(0),
+                    MdbEntryAttribute::Protocol(RouteProtocol::Static),
+                    MdbEntryAttribute::SourceList(vec![MdbSourceList::Entry(
+                        vec![
+                            MdbSource::Address(
+                                Ipv4Addr::new(192, 0, 2, 1).into(),
+                            ),
+                            MdbSource::Timer(0),
+                        ],
+                    )]),
+                    MdbEntryAttribute::GroupMode(MdbGroupMode::Include),
+                ],
+            }),
+        ])])],
+    };
+
+    assert_eq!(
+        expected,
+        MdbMessage::parse(&MdbMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
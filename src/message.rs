@@ -10,13 +10,20 @@ use netlink_packet_utils::{
 use crate::tc::{TcActionMessage, TcActionMessageBuffer};
 use crate::{
     address::{AddressHeader, AddressMessage, AddressMessageBuffer},
+    addrlabel::{AddressLabelMessage, AddressLabelMessageBuffer},
+    bridge_vlan::{BridgeVlanMessage, BridgeVlanMessageBuffer},
     link::{LinkMessage, LinkMessageBuffer},
+    mdb::{MdbMessage, MdbMessageBuffer},
+    nduseropt::{NdUserOptionMessage, NdUserOptionMessageBuffer},
     neighbour::{NeighbourMessage, NeighbourMessageBuffer},
     neighbour_table::{NeighbourTableMessage, NeighbourTableMessageBuffer},
+    netconf::{NetconfMessage, NetconfMessageBuffer},
+    nexthop::{NexthopMessage, NexthopMessageBuffer},
     nsid::{NsidMessage, NsidMessageBuffer},
     prefix::{PrefixMessage, PrefixMessageBuffer},
     route::{RouteHeader, RouteMessage, RouteMessageBuffer},
     rule::{RuleMessage, RuleMessageBuffer},
+    stats::{StatsMessage, StatsMessageBuffer},
     tc::{TcMessage, TcMessageBuffer},
 };
 
@@ -54,29 +61,38 @@ const RTM_NEWPREFIX: u16 = 52;
 const RTM_NEWNEIGHTBL: u16 = 64;
 const RTM_GETNEIGHTBL: u16 = 66;
 const RTM_SETNEIGHTBL: u16 = 67;
-// const RTM_NEWNDUSEROPT: u16 = 68;
-// const RTM_NEWADDRLABEL: u16 = 72;
-// const RTM_DELADDRLABEL: u16 = 73;
-// const RTM_GETADDRLABEL: u16 = 74;
+const RTM_NEWNDUSEROPT: u16 = 68;
+const RTM_NEWADDRLABEL: u16 = 72;
+const RTM_DELADDRLABEL: u16 = 73;
+const RTM_GETADDRLABEL: u16 = 74;
 // const RTM_GETDCB: u16 = 78;
 // const RTM_SETDCB: u16 = 79;
-// const RTM_NEWNETCONF: u16 = 80;
-// const RTM_DELNETCONF: u16 = 81;
-// const RTM_GETNETCONF: u16 = 82;
-// const RTM_NEWMDB: u16 = 84;
-// const RTM_DELMDB: u16 = 85;
-// const RTM_GETMDB: u16 = 86;
+const RTM_NEWNETCONF: u16 = 80;
+const RTM_DELNETCONF: u16 = 81;
+const RTM_GETNETCONF: u16 = 82;
+const RTM_NEWMDB: u16 = 84;
+const RTM_DELMDB: u16 = 85;
+const RTM_GETMDB: u16 = 86;
 const RTM_NEWNSID: u16 = 88;
 const RTM_DELNSID: u16 = 89;
 const RTM_GETNSID: u16 = 90;
-// const RTM_NEWSTATS: u16 = 92;
-// const RTM_GETSTATS: u16 = 94;
+const RTM_NEWSTATS: u16 = 92;
+const RTM_GETSTATS: u16 = 94;
 // const RTM_NEWCACHEREPORT: u16 = 96;
 const RTM_NEWCHAIN: u16 = 100;
 const RTM_DELCHAIN: u16 = 101;
 const RTM_GETCHAIN: u16 = 102;
+const RTM_NEWNEXTHOP: u16 = 104;
+const RTM_DELNEXTHOP: u16 = 105;
+const RTM_GETNEXTHOP: u16 = 106;
 const RTM_NEWLINKPROP: u16 = 108;
 const RTM_DELLINKPROP: u16 = 109;
+const RTM_NEWVLAN: u16 = 112;
+const RTM_DELVLAN: u16 = 113;
+const RTM_GETVLAN: u16 = 114;
+const RTM_NEWNEXTHOPBUCKET: u16 = 116;
+const RTM_DELNEXTHOPBUCKET: u16 = 117;
+const RTM_GETNEXTHOPBUCKET: u16 = 118;
 
 buffer!(RouteNetlinkMessageBuffer);
 
@@ -297,6 +313,107 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                 }
             }
 
+            // Bridge VLAN messages
+            RTM_NEWVLAN | RTM_DELVLAN | RTM_GETVLAN => {
+                let msg = BridgeVlanMessage::parse(
+                    &BridgeVlanMessageBuffer::new_checked(&buf.inner())?,
+                )?;
+                match message_type {
+                    RTM_NEWVLAN => RouteNetlinkMessage::NewBridgeVlan(msg),
+                    RTM_DELVLAN => RouteNetlinkMessage::DelBridgeVlan(msg),
+                    RTM_GETVLAN => RouteNetlinkMessage::GetBridgeVlan(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge multicast database messages
+            RTM_NEWMDB | RTM_DELMDB | RTM_GETMDB => {
+                let msg = MdbMessage::parse(&MdbMessageBuffer::new_checked(
+                    &buf.inner(),
+                )?)?;
+                match message_type {
+                    RTM_NEWMDB => RouteNetlinkMessage::NewMdb(msg),
+                    RTM_DELMDB => RouteNetlinkMessage::DelMdb(msg),
+                    RTM_GETMDB => RouteNetlinkMessage::GetMdb(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Link statistics messages
+            RTM_NEWSTATS | RTM_GETSTATS => {
+                let msg = StatsMessage::parse(
+                    &StatsMessageBuffer::new_checked(&buf.inner())?,
+                )?;
+                match message_type {
+                    RTM_NEWSTATS => RouteNetlinkMessage::NewStats(msg),
+                    RTM_GETSTATS => RouteNetlinkMessage::GetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Netconf messages
+            RTM_NEWNETCONF | RTM_DELNETCONF | RTM_GETNETCONF => {
+                let msg = NetconfMessage::parse(
+                    &NetconfMessageBuffer::new_checked(&buf.inner())?,
+                )?;
+                match message_type {
+                    RTM_NEWNETCONF => RouteNetlinkMessage::NewNetconf(msg),
+                    RTM_DELNETCONF => RouteNetlinkMessage::DelNetconf(msg),
+                    RTM_GETNETCONF => RouteNetlinkMessage::GetNetconf(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Nexthop messages
+            RTM_NEWNEXTHOP | RTM_DELNEXTHOP | RTM_GETNEXTHOP
+            | RTM_NEWNEXTHOPBUCKET | RTM_DELNEXTHOPBUCKET
+            | RTM_GETNEXTHOPBUCKET => {
+                let msg = NexthopMessage::parse(
+                    &NexthopMessageBuffer::new_checked(&buf.inner())?,
+                )?;
+                match message_type {
+                    RTM_NEWNEXTHOP => RouteNetlinkMessage::NewNexthop(msg),
+                    RTM_DELNEXTHOP => RouteNetlinkMessage::DelNexthop(msg),
+                    RTM_GETNEXTHOP => RouteNetlinkMessage::GetNexthop(msg),
+                    RTM_NEWNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::NewNexthopBucket(msg)
+                    }
+                    RTM_DELNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::DelNexthopBucket(msg)
+                    }
+                    RTM_GETNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::GetNexthopBucket(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // Address label messages
+            RTM_NEWADDRLABEL | RTM_DELADDRLABEL | RTM_GETADDRLABEL => {
+                let msg = AddressLabelMessage::parse(
+                    &AddressLabelMessageBuffer::new_checked(&buf.inner())?,
+                )?;
+                match message_type {
+                    RTM_NEWADDRLABEL => {
+                        RouteNetlinkMessage::NewAddressLabel(msg)
+                    }
+                    RTM_DELADDRLABEL => {
+                        RouteNetlinkMessage::DelAddressLabel(msg)
+                    }
+                    RTM_GETADDRLABEL => {
+                        RouteNetlinkMessage::GetAddressLabel(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // ND user option messages
+            RTM_NEWNDUSEROPT => RouteNetlinkMessage::NewNdUserOption(
+                NdUserOptionMessage::parse(
+                    &NdUserOptionMessageBuffer::new_checked(&buf.inner())?,
+                )?,
+            ),
+
             _ => {
                 return Err(
                     format!("Unknown message type: {message_type}").into()
@@ -350,6 +467,27 @@ pub enum RouteNetlinkMessage {
     NewRule(RuleMessage),
     DelRule(RuleMessage),
     GetRule(RuleMessage),
+    NewBridgeVlan(BridgeVlanMessage),
+    DelBridgeVlan(BridgeVlanMessage),
+    GetBridgeVlan(BridgeVlanMessage),
+    NewMdb(MdbMessage),
+    DelMdb(MdbMessage),
+    GetMdb(MdbMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
+    NewNetconf(NetconfMessage),
+    DelNetconf(NetconfMessage),
+    GetNetconf(NetconfMessage),
+    NewNexthop(NexthopMessage),
+    DelNexthop(NexthopMessage),
+    GetNexthop(NexthopMessage),
+    NewNexthopBucket(NexthopMessage),
+    DelNexthopBucket(NexthopMessage),
+    GetNexthopBucket(NexthopMessage),
+    NewAddressLabel(AddressLabelMessage),
+    DelAddressLabel(AddressLabelMessage),
+    GetAddressLabel(AddressLabelMessage),
+    NewNdUserOption(NdUserOptionMessage),
 }
 
 impl RouteNetlinkMessage {
@@ -501,6 +639,90 @@ impl RouteNetlinkMessage {
         matches!(self, RouteNetlinkMessage::DelRule(_))
     }
 
+    pub fn is_new_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewBridgeVlan(_))
+    }
+
+    pub fn is_del_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelBridgeVlan(_))
+    }
+
+    pub fn is_get_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetBridgeVlan(_))
+    }
+
+    pub fn is_new_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewMdb(_))
+    }
+
+    pub fn is_del_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelMdb(_))
+    }
+
+    pub fn is_get_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetMdb(_))
+    }
+
+    pub fn is_new_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetStats(_))
+    }
+
+    pub fn is_new_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNetconf(_))
+    }
+
+    pub fn is_del_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNetconf(_))
+    }
+
+    pub fn is_get_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNetconf(_))
+    }
+
+    pub fn is_new_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNexthop(_))
+    }
+
+    pub fn is_del_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNexthop(_))
+    }
+
+    pub fn is_get_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNexthop(_))
+    }
+
+    pub fn is_new_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNexthopBucket(_))
+    }
+
+    pub fn is_del_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNexthopBucket(_))
+    }
+
+    pub fn is_get_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNexthopBucket(_))
+    }
+
+    pub fn is_new_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewAddressLabel(_))
+    }
+
+    pub fn is_del_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelAddressLabel(_))
+    }
+
+    pub fn is_get_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetAddressLabel(_))
+    }
+
+    pub fn is_new_nd_user_option(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNdUserOption(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RouteNetlinkMessage::*;
 
@@ -545,6 +767,27 @@ impl RouteNetlinkMessage {
             GetRule(_) => RTM_GETRULE,
             NewRule(_) => RTM_NEWRULE,
             DelRule(_) => RTM_DELRULE,
+            NewBridgeVlan(_) => RTM_NEWVLAN,
+            DelBridgeVlan(_) => RTM_DELVLAN,
+            GetBridgeVlan(_) => RTM_GETVLAN,
+            NewMdb(_) => RTM_NEWMDB,
+            DelMdb(_) => RTM_DELMDB,
+            GetMdb(_) => RTM_GETMDB,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
+            NewNetconf(_) => RTM_NEWNETCONF,
+            DelNetconf(_) => RTM_DELNETCONF,
+            GetNetconf(_) => RTM_GETNETCONF,
+            NewNexthop(_) => RTM_NEWNEXTHOP,
+            DelNexthop(_) => RTM_DELNEXTHOP,
+            GetNexthop(_) => RTM_GETNEXTHOP,
+            NewNexthopBucket(_) => RTM_NEWNEXTHOPBUCKET,
+            DelNexthopBucket(_) => RTM_DELNEXTHOPBUCKET,
+            GetNexthopBucket(_) => RTM_GETNEXTHOPBUCKET,
+            NewAddressLabel(_) => RTM_NEWADDRLABEL,
+            DelAddressLabel(_) => RTM_DELADDRLABEL,
+            GetAddressLabel(_) => RTM_GETADDRLABEL,
+            NewNdUserOption(_) => RTM_NEWNDUSEROPT,
         }
     }
 }
@@ -612,6 +855,40 @@ impl Emitable for RouteNetlinkMessage {
             | DelTrafficAction(ref msg)
             | GetTrafficAction(ref msg)
             => msg.buffer_len(),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
+            => msg.buffer_len(),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.buffer_len(),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.buffer_len(),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            | NewNexthopBucket(ref msg)
+            | DelNexthopBucket(ref msg)
+            | GetNexthopBucket(ref msg)
+            => msg.buffer_len(),
+
+            | NewAddressLabel(ref msg)
+            | DelAddressLabel(ref msg)
+            | GetAddressLabel(ref msg)
+            => msg.buffer_len(),
+
+            | NewNdUserOption(ref msg) => msg.buffer_len(),
         }
     }
 
@@ -677,6 +954,40 @@ impl Emitable for RouteNetlinkMessage {
             | DelTrafficAction(ref msg)
             | GetTrafficAction(ref msg)
             => msg.emit(buffer),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
+            => msg.emit(buffer),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.emit(buffer),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.emit(buffer),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            | NewNexthopBucket(ref msg)
+            | DelNexthopBucket(ref msg)
+            | GetNexthopBucket(ref msg)
+            => msg.emit(buffer),
+
+            | NewAddressLabel(ref msg)
+            | DelAddressLabel(ref msg)
+            | GetAddressLabel(ref msg)
+            => msg.emit(buffer),
+
+            | NewNdUserOption(ref msg) => msg.emit(buffer),
         }
     }
 }
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const NDUSEROPT_SRCADDR: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NdUserOptionAttribute {
+    /// Source address of the Router Advertisement carrying the options
+    SourceAddress(Ipv6Addr),
+    Other(DefaultNla),
+}
+
+impl Nla for NdUserOptionAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::SourceAddress(_) => IPV6_ADDR_LEN,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::SourceAddress(addr) => buffer.copy_from_slice(&addr.octets()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::SourceAddress(_) => NDUSEROPT_SRCADDR,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NdUserOptionAttribute
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NDUSEROPT_SRCADDR => Self::SourceAddress(parse_ipv6_addr(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
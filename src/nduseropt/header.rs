@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+pub(crate) const ND_USER_OPTION_HEADER_LEN: usize = 16;
+
+buffer!(NdUserOptionMessageBuffer(ND_USER_OPTION_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    options_len: (u16, 2..4),
+    ifindex: (u32, 4..8),
+    icmp_type: (u8, 8),
+    icmp_code: (u8, 9),
+    pad2: (u16, 10..12),
+    pad3: (u32, 12..16),
+    payload: (slice, ND_USER_OPTION_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NdUserOptionMessageBuffer<&'a T> {
+    /// The raw ICMPv6 options following the header.
+    pub fn options(&self) -> Result<&'a [u8], DecodeError> {
+        let payload = self.payload();
+        let len = self.options_len() as usize;
+        if payload.len() < len {
+            Err(DecodeError::from(format!(
+                "Invalid nduseroptmsg, options length {len} exceeds \
+                 payload length {}",
+                payload.len()
+            )))
+        } else {
+            Ok(&payload[..len])
+        }
+    }
+
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        let payload = self.payload();
+        let offset = (self.options_len() as usize).min(payload.len());
+        NlasIterator::new(&payload[offset..]).map(|nla| {
+            nla.map_err(|e| {
+                DecodeError::from(format!(
+                    "Failed to parse nduseropt attributes {e}"
+                ))
+            })
+        })
+    }
+}
+
+// Linux kernel struct `struct nduseroptmsg`. The `nduseropt_opts_len` is
+// calculated from [super::NdUserOptionMessage::options] when emitting.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NdUserOptionHeader {
+    /// Always [AddressFamily::Inet6]
+    pub family: AddressFamily,
+    /// Interface index the Router Advertisement received on
+    pub ifindex: u32,
+    /// ICMPv6 type of the message carrying the options, `134` for
+    /// Router Advertisement
+    pub icmp_type: u8,
+    pub icmp_code: u8,
+}
+
+impl<T: AsRef<[u8]>> Parseable<NdUserOptionMessageBuffer<T>>
+    for NdUserOptionHeader
+{
+    type Error = DecodeError;
+    fn parse(buf: &NdUserOptionMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+            icmp_type: buf.icmp_type(),
+            icmp_code: buf.icmp_code(),
+        })
+    }
+}
+
+impl Emitable for NdUserOptionHeader {
+    fn buffer_len(&self) -> usize {
+        ND_USER_OPTION_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NdUserOptionMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_icmp_type(self.icmp_type);
+        packet.set_icmp_code(self.icmp_code);
+        packet.set_pad2(0);
+        packet.set_pad3(0);
+    }
+}
@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    header::ND_USER_OPTION_HEADER_LEN, option::VecNdOption, NdOption,
+    NdUserOptionAttribute, NdUserOptionHeader, NdUserOptionMessageBuffer,
+};
+
+/// The `RTM_NEWNDUSEROPT` message holding ICMPv6 Neighbor Discovery options
+/// not handled by kernel itself.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdUserOptionMessage {
+    pub header: NdUserOptionHeader,
+    pub options: Vec<NdOption>,
+    pub attributes: Vec<NdUserOptionAttribute>,
+}
+
+impl NdUserOptionMessage {
+    fn options_len(&self) -> usize {
+        self.options.iter().map(|o| o.buffer_len()).sum()
+    }
+}
+
+impl Emitable for NdUserOptionMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len()
+            + self.options_len()
+            + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        let options_len = self.options_len();
+        NdUserOptionMessageBuffer::new(&mut buffer[..])
+            .set_options_len(options_len as u16);
+        let mut offset = ND_USER_OPTION_HEADER_LEN;
+        for option in self.options.iter() {
+            let len = option.buffer_len();
+            option.emit(&mut buffer[offset..offset + len]);
+            offset += len;
+        }
+        self.attributes.as_slice().emit(&mut buffer[offset..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NdUserOptionMessageBuffer<&'a T>>
+    for NdUserOptionMessage
+{
+    type Error = DecodeError;
+    fn parse(
+        buf: &NdUserOptionMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: NdUserOptionHeader::parse(buf)?,
+            options: VecNdOption::parse(buf.options()?)?.0,
+            attributes: Vec::<NdUserOptionAttribute>::parse(buf)?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NdUserOptionMessageBuffer<&'a T>>
+    for Vec<NdUserOptionAttribute>
+{
+    type Error = DecodeError;
+    fn parse(
+        buf: &NdUserOptionMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(NdUserOptionAttribute::parse(&nla_buf?)?);
+        }
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,360 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const ND_OPT_RDNSS: u8 = 25;
+const ND_OPT_DNSSL: u8 = 31;
+const ND_OPT_CAPTIVE_PORTAL: u8 = 37;
+const ND_OPT_PREF64: u8 = 38;
+
+// The ND option length is in units of 8 octets including the type and
+// length fields.
+const ND_OPT_UNIT: usize = 8;
+const ND_OPT_HEADER_LEN: usize = 2;
+// The length field is a single byte
+const ND_OPT_MAX_LEN: usize = u8::MAX as usize * ND_OPT_UNIT;
+const ND_OPT_MAX_PAYLOAD_LEN: usize = ND_OPT_MAX_LEN - ND_OPT_HEADER_LEN;
+// Type, length, reserved and lifetime of RDNSS and DNSSL options
+const ND_OPT_LIFETIME_HEADER_LEN: usize = 8;
+const ND_OPT_PREF64_LEN: usize = 16;
+const ND_OPT_PREF64_PREFIX_LEN: usize = 12;
+const ND_OPT_PREF64_PLC_MASK: u16 = 0x7;
+const DNS_LABEL_MAX_LEN: usize = 63;
+
+/// ICMPv6 Neighbor Discovery options forwarded by kernel in
+/// `RTM_NEWNDUSEROPT`.
+///
+/// An option cannot exceed 2040 bytes on the wire, content not fitting in
+/// it is dropped when emitting, see the documentation of each variant.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NdOption {
+    /// Recursive DNS Server option defined in RFC 8106
+    Rdnss(NdOptionRdnss),
+    /// DNS Search List option defined in RFC 8106
+    Dnssl(NdOptionDnssl),
+    /// Captive-Portal URI defined in RFC 8910, truncated to 2038 bytes when
+    /// emitting
+    CaptivePortal(String),
+    /// NAT64 prefix option defined in RFC 8781
+    Pref64(NdOptionPref64),
+    /// Other option, the payload does not include the type and length
+    /// fields. It is truncated to 2038 bytes when emitting.
+    Other { kind: u8, payload: Vec<u8> },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdOptionRdnss {
+    /// Lifetime in seconds, `u32::MAX` means infinity
+    pub lifetime: u32,
+    /// Only the first 127 addresses fit in one option, the others are not
+    /// emitted
+    pub addresses: Vec<Ipv6Addr>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdOptionDnssl {
+    /// Lifetime in seconds, `u32::MAX` means infinity
+    pub lifetime: u32,
+    /// Domain names without trailing dot. Labels longer than 63 bytes are
+    /// truncated when emitting, and domains not fitting in one option are
+    /// not emitted.
+    pub domains: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub struct NdOptionPref64 {
+    /// Lifetime in seconds, only multiple of 8 is supported by the wire
+    /// format
+    pub lifetime: u16,
+    pub prefix: Ipv6Addr,
+    /// One of 96, 64, 56, 48, 40 and 32, any other value is emitted as 96
+    pub prefix_len: u8,
+}
+
+impl Default for NdOptionPref64 {
+    fn default() -> Self {
+        Self {
+            lifetime: 0,
+            prefix: Ipv6Addr::UNSPECIFIED,
+            prefix_len: 96,
+        }
+    }
+}
+
+fn pref64_plc_to_prefix_len(plc: u16) -> Option<u8> {
+    match plc {
+        0 => Some(96),
+        1 => Some(64),
+        2 => Some(56),
+        3 => Some(48),
+        4 => Some(40),
+        5 => Some(32),
+        _ => None,
+    }
+}
+
+fn pref64_prefix_len_to_plc(prefix_len: u8) -> u16 {
+    match prefix_len {
+        64 => 1,
+        56 => 2,
+        48 => 3,
+        40 => 4,
+        32 => 5,
+        _ => 0,
+    }
+}
+
+impl NdOptionRdnss {
+    fn emit_addresses(&self) -> &[Ipv6Addr] {
+        let max = (ND_OPT_MAX_LEN - ND_OPT_LIFETIME_HEADER_LEN) / IPV6_ADDR_LEN;
+        &self.addresses[..self.addresses.len().min(max)]
+    }
+}
+
+impl NdOptionDnssl {
+    fn emit_domains(&self) -> &[String] {
+        let mut len = ND_OPT_LIFETIME_HEADER_LEN;
+        let count = self
+            .domains
+            .iter()
+            .take_while(|d| {
+                len += dns_name_len(d);
+                len <= ND_OPT_MAX_LEN
+            })
+            .count();
+        &self.domains[..count]
+    }
+}
+
+fn pad_to_unit(len: usize) -> usize {
+    len.div_ceil(ND_OPT_UNIT) * ND_OPT_UNIT
+}
+
+fn dns_name_len(name: &str) -> usize {
+    name.split('.')
+        .filter(|l| !l.is_empty())
+        .map(|l| l.len().min(DNS_LABEL_MAX_LEN) + 1)
+        .sum::<usize>()
+        + 1
+}
+
+fn emit_dns_name(name: &str, buffer: &mut [u8]) -> usize {
+    let mut offset = 0;
+    for label in name.split('.').filter(|l| !l.is_empty()) {
+        let label = &label.as_bytes()[..label.len().min(DNS_LABEL_MAX_LEN)];
+        buffer[offset] = label.len() as u8;
+        offset += 1;
+        buffer[offset..offset + label.len()].copy_from_slice(label);
+        offset += label.len();
+    }
+    buffer[offset] = 0;
+    offset + 1
+}
+
+// Domain names are stored in DNS wire format without compression, the
+// remaining space is filled with zeros.
+fn parse_dns_names(payload: &[u8]) -> Result<Vec<String>, DecodeError> {
+    let mut domains = Vec::new();
+    let mut offset = 0;
+    while offset < payload.len() && payload[offset] != 0 {
+        let mut labels = Vec::new();
+        loop {
+            let len = *payload.get(offset).ok_or_else(|| {
+                DecodeError::from("Invalid DNSSL option, truncated domain")
+            })? as usize;
+            offset += 1;
+            if len == 0 {
+                break;
+            }
+            let label = payload.get(offset..offset + len).ok_or_else(|| {
+                DecodeError::from("Invalid DNSSL option, truncated label")
+            })?;
+            labels.push(String::from_utf8(label.to_vec())?);
+            offset += len;
+        }
+        domains.push(labels.join("."));
+    }
+    Ok(domains)
+}
+
+impl NdOption {
+    fn kind(&self) -> u8 {
+        match self {
+            Self::Rdnss(_) => ND_OPT_RDNSS,
+            Self::Dnssl(_) => ND_OPT_DNSSL,
+            Self::CaptivePortal(_) => ND_OPT_CAPTIVE_PORTAL,
+            Self::Pref64(_) => ND_OPT_PREF64,
+            Self::Other { kind, .. } => *kind,
+        }
+    }
+
+    // Raw payload of the options carrying unstructured data
+    fn emit_data(&self) -> &[u8] {
+        let data = match self {
+            Self::CaptivePortal(v) => v.as_bytes(),
+            Self::Other { payload, .. } => payload.as_slice(),
+            _ => &[],
+        };
+        &data[..data.len().min(ND_OPT_MAX_PAYLOAD_LEN)]
+    }
+
+    fn parse(kind: u8, payload: &[u8]) -> Result<Self, DecodeError> {
+        Ok(match kind {
+            ND_OPT_RDNSS | ND_OPT_DNSSL => {
+                if payload.len()
+                    < ND_OPT_LIFETIME_HEADER_LEN - ND_OPT_HEADER_LEN
+                {
+                    return Err(DecodeError::from(format!(
+                        "Invalid ND option {kind}, payload too short: \
+                         {payload:?}"
+                    )));
+                }
+                let lifetime = BigEndian::read_u32(&payload[2..6]);
+                let payload = &payload[6..];
+                if kind == ND_OPT_RDNSS {
+                    let mut addresses = Vec::new();
+                    for chunk in payload.chunks(IPV6_ADDR_LEN) {
+                        addresses.push(parse_ipv6_addr(chunk)?);
+                    }
+                    Self::Rdnss(NdOptionRdnss {
+                        lifetime,
+                        addresses,
+                    })
+                } else {
+                    Self::Dnssl(NdOptionDnssl {
+                        lifetime,
+                        domains: parse_dns_names(payload)?,
+                    })
+                }
+            }
+            ND_OPT_CAPTIVE_PORTAL => {
+                let len = payload
+                    .iter()
+                    .position(|b| *b == 0)
+                    .unwrap_or(payload.len());
+                Self::CaptivePortal(String::from_utf8(payload[..len].to_vec())?)
+            }
+            ND_OPT_PREF64
+                if payload.len() == ND_OPT_PREF64_LEN - ND_OPT_HEADER_LEN =>
+            {
+                let value = BigEndian::read_u16(&payload[..2]);
+                match pref64_plc_to_prefix_len(value & ND_OPT_PREF64_PLC_MASK) {
+                    Some(prefix_len) => {
+                        let mut octets = [0u8; IPV6_ADDR_LEN];
+                        octets[..ND_OPT_PREF64_PREFIX_LEN]
+                            .copy_from_slice(&payload[2..]);
+                        Self::Pref64(NdOptionPref64 {
+                            lifetime: value & !ND_OPT_PREF64_PLC_MASK,
+                            prefix: Ipv6Addr::from(octets),
+                            prefix_len,
+                        })
+                    }
+                    None => Self::Other {
+                        kind,
+                        payload: payload.to_vec(),
+                    },
+                }
+            }
+            _ => Self::Other {
+                kind,
+                payload: payload.to_vec(),
+            },
+        })
+    }
+}
+
+impl Emitable for NdOption {
+    fn buffer_len(&self) -> usize {
+        pad_to_unit(match self {
+            Self::Rdnss(v) => {
+                ND_OPT_LIFETIME_HEADER_LEN
+                    + v.emit_addresses().len() * IPV6_ADDR_LEN
+            }
+            Self::Dnssl(v) => {
+                ND_OPT_LIFETIME_HEADER_LEN
+                    + v.emit_domains()
+                        .iter()
+                        .map(|d| dns_name_len(d))
+                        .sum::<usize>()
+            }
+            Self::CaptivePortal(_) | Self::Other { .. } => {
+                ND_OPT_HEADER_LEN + self.emit_data().len()
+            }
+            Self::Pref64(_) => ND_OPT_PREF64_LEN,
+        })
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        let buffer = &mut buffer[..len];
+        buffer.fill(0);
+        buffer[0] = self.kind();
+        buffer[1] = (len / ND_OPT_UNIT) as u8;
+        let payload = &mut buffer[ND_OPT_HEADER_LEN..];
+        match self {
+            Self::Rdnss(v) => {
+                BigEndian::write_u32(&mut payload[2..6], v.lifetime);
+                for (i, addr) in v.emit_addresses().iter().enumerate() {
+                    let offset = 6 + i * IPV6_ADDR_LEN;
+                    payload[offset..offset + IPV6_ADDR_LEN]
+                        .copy_from_slice(&addr.octets());
+                }
+            }
+            Self::Dnssl(v) => {
+                BigEndian::write_u32(&mut payload[2..6], v.lifetime);
+                let mut offset = 6;
+                for domain in v.emit_domains() {
+                    offset += emit_dns_name(domain, &mut payload[offset..]);
+                }
+            }
+            Self::CaptivePortal(_) | Self::Other { .. } => {
+                let data = self.emit_data();
+                payload[..data.len()].copy_from_slice(data)
+            }
+            Self::Pref64(v) => {
+                BigEndian::write_u16(
+                    &mut payload[..2],
+                    (v.lifetime & !ND_OPT_PREF64_PLC_MASK)
+                        | pref64_prefix_len_to_plc(v.prefix_len),
+                );
+                payload[2..].copy_from_slice(
+                    &v.prefix.octets()[..ND_OPT_PREF64_PREFIX_LEN],
+                );
+            }
+        }
+    }
+}
+
+pub(crate) struct VecNdOption(pub(crate) Vec<NdOption>);
+
+impl VecNdOption {
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut options = Vec::new();
+        let mut offset = 0;
+        while offset + ND_OPT_HEADER_LEN <= payload.len() {
+            let kind = payload[offset];
+            let len = payload[offset + 1] as usize * ND_OPT_UNIT;
+            if len == 0 || offset + len > payload.len() {
+                return Err(DecodeError::from(format!(
+                    "Invalid ND option {kind} with length {len} at offset \
+                     {offset}: {payload:?}"
+                )));
+            }
+            options.push(NdOption::parse(
+                kind,
+                &payload[offset + ND_OPT_HEADER_LEN..offset + len],
+            )?);
+            offset += len;
+        }
+        Ok(Self(options))
+    }
+}
@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    nduseropt::{
+        NdOption, NdOptionDnssl, NdOptionPref64, NdOptionRdnss,
+        NdUserOptionAttribute, NdUserOptionHeader, NdUserOptionMessage,
+        NdUserOptionMessageBuffer,
+    },
+    AddressFamily,
+};
+
+fn expected_message(option: NdOption) -> NdUserOptionMessage {
+    NdUserOptionMessage {
+        header: NdUserOptionHeader {
+            family: AddressFamily::Inet6,
+            ifindex: 2,
+            icmp_type: 134,
+            icmp_code: 0,
+        },
+        options: vec![option],
+        attributes: vec![NdUserOptionAttribute::SourceAddress(
+            Ipv6Addr::from_str("fe80::1").unwrap(),
+        )],
+    }
+}
+
+// RTM_NEWNDUSEROPT for Router Advertisement received on eth0 with
+// RDNSS option of 2001:db8::53 and 2001:db8::1:53, lifetime 1800
+#[test]
+fn test_nduseropt_rdnss() {
+    let raw: Vec<u8> = vec![
+        0x0a, 0x00, 0x28, 0x00, 0x02, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x19, 0x05, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x53, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x53, 0x14, 0x00, 0x01, 0x00,
+        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let expected = expected_message(NdOption::Rdnss(NdOptionRdnss {
+        lifetime: 1800,
+        addresses: vec![
+            Ipv6Addr::from_str("2001:db8::53").unwrap(),
+            Ipv6Addr::from_str("2001:db8::1:53").unwrap(),
+        ],
+    }));
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// RTM_NEWNDUSEROPT for Router Advertisement received on eth0 with
+// DNSSL option of example.com and corp.example.org, lifetime 1800
+#[test]
+fn test_nduseropt_dnssl() {
+    let raw: Vec<u8> = vec![
+        0x0a, 0x00, 0x28, 0x00, 0x02, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x1f, 0x05, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08,
+        0x07, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x03, 0x63, 0x6f, 0x6d,
+        0x00, 0x04, 0x63, 0x6f, 0x72, 0x70, 0x07, 0x65, 0x78, 0x61, 0x6d, 0x70,
+        0x6c, 0x65, 0x03, 0x6f, 0x72, 0x67, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00,
+        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let expected = expected_message(NdOption::Dnssl(NdOptionDnssl {
+        lifetime: 1800,
+        domains: vec!["example.com".into(), "corp.example.org".into()],
+    }));
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// RTM_NEWNDUSEROPT for Router Advertisement received on eth0 with
+// PREF64 option of 64:ff9b::/96, lifetime 600
+#[test]
+fn test_nduseropt_pref64() {
+    let raw: Vec<u8> = vec![
+        0x0a, 0x00, 0x10, 0x00, 0x02, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x26, 0x02, 0x02, 0x58, 0x00, 0x64, 0xff, 0x9b,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x01, 0x00,
+        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let expected = expected_message(NdOption::Pref64(NdOptionPref64 {
+        lifetime: 600,
+        prefix: Ipv6Addr::from_str("64:ff9b::").unwrap(),
+        prefix_len: 96,
+    }));
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// RTM_NEWNDUSEROPT for Router Advertisement received on eth0 with
+// captive portal option of https://portal.example.net/
+#[test]
+fn test_nduseropt_captive_portal() {
+    let raw: Vec<u8> = vec![
+        0x0a, 0x00, 0x20, 0x00, 0x02, 0x00, 0x00, 0x00, 0x86, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x25, 0x04, 0x68, 0x74, 0x74, 0x70, 0x73, 0x3a,
+        0x2f, 0x2f, 0x70, 0x6f, 0x72, 0x74, 0x61, 0x6c, 0x2e, 0x65, 0x78, 0x61,
+        0x6d, 0x70, 0x6c, 0x65, 0x2e, 0x6e, 0x65, 0x74, 0x2f, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x01, 0x00, 0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    let expected = expected_message(NdOption::CaptivePortal(
+        "https://portal.example.net/".into(),
+    ));
+
+    assert_eq!(
+        expected,
+        NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&raw))
+            .unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+fn emit_and_parse(option: NdOption) -> Vec<NdOption> {
+    let message = expected_message(option);
+    let mut buf = vec![0; message.buffer_len()];
+    message.emit(&mut buf);
+    NdUserOptionMessage::parse(&NdUserOptionMessageBuffer::new(&buf))
+        .unwrap()
+        .options
+}
+
+// The option length field counts 8 bytes units in a single byte, so an
+// option cannot exceed 2040 bytes: longer content is dropped on emit
+// instead of wrapping the length.
+#[test]
+fn test_nduseropt_option_len_limit() {
+    let option = NdOption::Other {
+        kind: 200,
+        payload: vec![0xab; 3000],
+    };
+    assert_eq!(option.buffer_len(), 2040);
+    assert_eq!(
+        emit_and_parse(option),
+        vec![NdOption::Other {
+            kind: 200,
+            payload: vec![0xab; 2038],
+        }]
+    );
+
+    let option = NdOption::Rdnss(NdOptionRdnss {
+        lifetime: 600,
+        addresses: vec![Ipv6Addr::from_str("2001:db8::53").unwrap(); 200],
+    });
+    assert_eq!(option.buffer_len(), 2040);
+    assert_eq!(
+        emit_and_parse(option),
+        vec![NdOption::Rdnss(NdOptionRdnss {
+            lifetime: 600,
+            addresses: vec![Ipv6Addr::from_str("2001:db8::53").unwrap(); 127],
+        })]
+    );
+
+    // Each domain takes 255 bytes in wire format, only 7 of them fit, the
+    // 1793 bytes option is padded to 1800
+    let domain = format!(
+        "{}.{}.{}.{}",
+        "a".repeat(63),
+        "b".repeat(63),
+        "c".repeat(63),
+        "d".repeat(61)
+    );
+    let option = NdOption::Dnssl(NdOptionDnssl {
+        lifetime: 1800,
+        domains: vec![domain.clone(); 10],
+    });
+    assert_eq!(option.buffer_len(), 1800);
+    assert_eq!(
+        emit_and_parse(option),
+        vec![NdOption::Dnssl(NdOptionDnssl {
+            lifetime: 1800,
+            domains: vec![domain; 7],
+        })]
+    );
+}
+
+// DNS labels are limited to 63 bytes
+#[test]
+fn test_nduseropt_dnssl_label_len_limit() {
+    let option = NdOption::Dnssl(NdOptionDnssl {
+        lifetime: 1800,
+        domains: vec![format!("{}.example", "a".repeat(70))],
+    });
+    assert_eq!(
+        emit_and_parse(option),
+        vec![NdOption::Dnssl(NdOptionDnssl {
+            lifetime: 1800,
+            domains: vec![format!("{}.example", "a".repeat(63))],
+        })]
+    );
+}
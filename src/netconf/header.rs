@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::AddressFamily;
+
+const NETCONF_HEADER_LEN: usize = 4;
+
+buffer!(NetconfMessageBuffer(NETCONF_HEADER_LEN) {
+    family: (u8, 0),
+    payload: (slice, NETCONF_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NetconfMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload()).map(|nla| {
+            nla.map_err(|e| {
+                DecodeError::from(format!(
+                    "Failed to parse netconf attributes {e}"
+                ))
+            })
+        })
+    }
+}
+
+// Linux kernel `struct netconfmsg` only holds the family, attributes start
+// after NLMSG_ALIGN() padding.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetconfHeader {
+    pub family: AddressFamily,
+}
+
+impl Emitable for NetconfHeader {
+    fn buffer_len(&self) -> usize {
+        NETCONF_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NetconfMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NetconfMessageBuffer<T>> for NetconfHeader {
+    type Error = DecodeError;
+    fn parse(buf: &NetconfMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NetconfHeader {
+            family: buf.family().into(),
+        })
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::{NetconfAttribute, NetconfIfIndex};
+pub use self::header::{NetconfHeader, NetconfMessageBuffer};
+pub use self::message::NetconfMessage;
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    netconf::{
+        NetconfAttribute, NetconfHeader, NetconfIfIndex, NetconfMessage,
+        NetconfMessageBuffer,
+    },
+    AddressFamily,
+};
+
+// Setup:
+//      sysctl -w net.ipv4.conf.eth0.forwarding=1
+//      sysctl -w net.ipv4.conf.eth0.rp_filter=2
+// Kernel reply of `ip -4 netconf show dev eth0`
+#[test]
+fn test_ipv4_netconf() {
+    let raw: Vec<u8> = vec![
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = NetconfMessage {
+        header: NetconfHeader {
+            family: AddressFamily::Inet,
+        },
+        attributes: vec![
+            NetconfAttribute::IfIndex(NetconfIfIndex::Index(2)),
+            NetconfAttribute::Forwarding(1),
+            NetconfAttribute::RpFilter(2),
+            NetconfAttribute::McForwarding(0),
+            NetconfAttribute::BcForwarding(0),
+            NetconfAttribute::ProxyNeigh(0),
+            NetconfAttribute::IgnoreRoutesWithLinkdown(0),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NetconfMessage::parse(&NetconfMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Kernel reply of `ip -6 netconf show dev all`
+#[test]
+fn test_ipv6_netconf_all() {
+    let raw: Vec<u8> = vec![
+        0x0a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0xff, 0xff, 0xff, 0xff,
+        0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = NetconfMessage {
+        header: NetconfHeader {
+            family: AddressFamily::Inet6,
+        },
+        attributes: vec![
+            NetconfAttribute::IfIndex(NetconfIfIndex::All),
+            NetconfAttribute::Forwarding(0),
+            NetconfAttribute::McForwarding(0),
+            NetconfAttribute::ProxyNeigh(0),
+            NetconfAttribute::IgnoreRoutesWithLinkdown(0),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NetconfMessage::parse(&NetconfMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      modprobe mpls_router
+//      sysctl -w net.mpls.conf.eth1.input=1
+// Kernel RTM_NEWNETCONF notification captured by `ip monitor netconf`
+#[test]
+fn test_mpls_netconf_input() {
+    let raw: Vec<u8> = vec![
+        0x1c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x07, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = NetconfMessage {
+        header: NetconfHeader {
+            family: AddressFamily::Mpls,
+        },
+        attributes: vec![
+            NetconfAttribute::IfIndex(NetconfIfIndex::Index(3)),
+            NetconfAttribute::Input(1),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NetconfMessage::parse(&NetconfMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{
+    group::NEXTHOP_GROUP_LEN, NexthopGroup, NexthopGroupBuffer,
+    NexthopGroupStats, NexthopGroupType, NexthopResBucket, NexthopResGroup,
+};
+use crate::{
+    route::{
+        lwtunnel::VecRouteLwTunnelEncap, RouteAddress, RouteLwEnCapType,
+        RouteLwTunnelEncap,
+    },
+    AddressFamily,
+};
+
+const NHA_ID: u16 = 1;
+const NHA_GROUP: u16 = 2;
+const NHA_GROUP_TYPE: u16 = 3;
+const NHA_BLACKHOLE: u16 = 4;
+const NHA_OIF: u16 = 5;
+const NHA_GATEWAY: u16 = 6;
+pub(crate) const NHA_ENCAP_TYPE: u16 = 7;
+const NHA_ENCAP: u16 = 8;
+const NHA_GROUPS: u16 = 9;
+const NHA_MASTER: u16 = 10;
+const NHA_FDB: u16 = 11;
+const NHA_RES_GROUP: u16 = 12;
+const NHA_RES_BUCKET: u16 = 13;
+const NHA_OP_FLAGS: u16 = 14;
+const NHA_GROUP_STATS: u16 = 15;
+const NHA_HW_STATS_ENABLE: u16 = 16;
+const NHA_HW_STATS_USED: u16 = 17;
+
+const NHA_OP_FLAG_DUMP_STATS: u32 = 1 << 0;
+const NHA_OP_FLAG_DUMP_HW_STATS: u32 = 1 << 1;
+const NHA_OP_FLAG_RESP_GRP_RESVD_0: u32 = 1 << 31;
+
+bitflags! {
+    /// Flags of `NHA_OP_FLAGS` requesting extra information in
+    /// `RTM_GETNEXTHOP` reply, or describing the reply.
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct NexthopOpFlags: u32 {
+        const DumpStats = NHA_OP_FLAG_DUMP_STATS;
+        const DumpHwStats = NHA_OP_FLAG_DUMP_HW_STATS;
+        /// Set by kernel in group replies to tell the reserved fields of
+        /// `NHA_GROUP` entries are zeroed.
+        const RespGrpResvd0 = NHA_OP_FLAG_RESP_GRP_RESVD_0;
+        const _ = !0;
+    }
+}
+
+/// Netlink attributes for `RTM_NEWNEXTHOP`, `RTM_DELNEXTHOP`,
+/// `RTM_GETNEXTHOP` and the `RTM_*NEXTHOPBUCKET` netlink messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NexthopAttribute {
+    Id(u32),
+    /// Members of nexthop group
+    Group(Vec<NexthopGroup>),
+    GroupType(NexthopGroupType),
+    Blackhole,
+    Oif(u32),
+    Gateway(RouteAddress),
+    EncapType(RouteLwEnCapType),
+    Encap(Vec<RouteLwTunnelEncap>),
+    /// Only dump nexthop groups
+    Groups,
+    /// Only dump nexthops using specified master device
+    Master(u32),
+    Fdb,
+    ResGroup(Vec<NexthopResGroup>),
+    ResBucket(Vec<NexthopResBucket>),
+    OpFlags(NexthopOpFlags),
+    GroupStats(Vec<NexthopGroupStats>),
+    HwStatsEnable(bool),
+    HwStatsUsed(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Group(v) => v.len() * NEXTHOP_GROUP_LEN,
+            Self::Gateway(v) => v.buffer_len(),
+            Self::Encap(v) => v.as_slice().buffer_len(),
+            Self::ResGroup(v) => v.as_slice().buffer_len(),
+            Self::ResBucket(v) => v.as_slice().buffer_len(),
+            Self::GroupStats(v) => v.as_slice().buffer_len(),
+            Self::Id(_)
+            | Self::Oif(_)
+            | Self::Master(_)
+            | Self::OpFlags(_)
+            | Self::HwStatsEnable(_)
+            | Self::HwStatsUsed(_) => 4,
+            Self::GroupType(_) | Self::EncapType(_) => 2,
+            Self::Blackhole | Self::Groups | Self::Fdb => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Group(entries) => {
+                for (i, entry) in entries.iter().enumerate() {
+                    entry.emit(&mut buffer[i * NEXTHOP_GROUP_LEN..]);
+                }
+            }
+            Self::Gateway(v) => v.emit(buffer),
+            Self::Encap(v) => v.as_slice().emit(buffer),
+            Self::ResGroup(v) => v.as_slice().emit(buffer),
+            Self::ResBucket(v) => v.as_slice().emit(buffer),
+            Self::GroupStats(v) => v.as_slice().emit(buffer),
+            Self::Id(v) | Self::Oif(v) | Self::Master(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::OpFlags(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::HwStatsEnable(v) | Self::HwStatsUsed(v) => {
+                NativeEndian::write_u32(buffer, (*v).into())
+            }
+            Self::GroupType(v) => NativeEndian::write_u16(buffer, (*v).into()),
+            Self::EncapType(v) => v.emit(buffer),
+            Self::Blackhole | Self::Groups | Self::Fdb => (),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => NHA_ID,
+            Self::Group(_) => NHA_GROUP,
+            Self::GroupType(_) => NHA_GROUP_TYPE,
+            Self::Blackhole => NHA_BLACKHOLE,
+            Self::Oif(_) => NHA_OIF,
+            Self::Gateway(_) => NHA_GATEWAY,
+            Self::EncapType(_) => NHA_ENCAP_TYPE,
+            Self::Encap(_) => NHA_ENCAP,
+            Self::Groups => NHA_GROUPS,
+            Self::Master(_) => NHA_MASTER,
+            Self::Fdb => NHA_FDB,
+            Self::ResGroup(_) => NHA_RES_GROUP | NLA_F_NESTED,
+            Self::ResBucket(_) => NHA_RES_BUCKET | NLA_F_NESTED,
+            Self::OpFlags(_) => NHA_OP_FLAGS,
+            Self::GroupStats(_) => NHA_GROUP_STATS | NLA_F_NESTED,
+            Self::HwStatsEnable(_) => NHA_HW_STATS_ENABLE,
+            Self::HwStatsUsed(_) => NHA_HW_STATS_USED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn is_nested(&self) -> bool {
+        if let Self::Encap(encap) = self {
+            encap
+                .iter()
+                .any(|e| matches!(e, RouteLwTunnelEncap::Seg6(_)))
+        } else {
+            (self.kind() & NLA_F_NESTED) != 0
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, (AddressFamily, RouteLwEnCapType)>
+    for NexthopAttribute
+{
+    type Error = DecodeError;
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        (address_family, encap_type): (AddressFamily, RouteLwEnCapType),
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_ID => Self::Id(parse_u32(payload)?),
+            NHA_GROUP => {
+                let mut entries = Vec::new();
+                for chunk in payload.chunks(NEXTHOP_GROUP_LEN) {
+                    entries.push(NexthopGroup::parse(
+                        &NexthopGroupBuffer::new_checked(chunk)?,
+                    )?);
+                }
+                Self::Group(entries)
+            }
+            NHA_GROUP_TYPE => Self::GroupType(parse_u16(payload)?.into()),
+            NHA_BLACKHOLE => Self::Blackhole,
+            NHA_OIF => Self::Oif(parse_u32(payload)?),
+            NHA_GATEWAY => {
+                Self::Gateway(RouteAddress::parse(address_family, payload)?)
+            }
+            NHA_ENCAP_TYPE => {
+                Self::EncapType(RouteLwEnCapType::from(parse_u16(payload)?))
+            }
+            NHA_ENCAP => Self::Encap(
+                VecRouteLwTunnelEncap::parse_with_param(buf, encap_type)?.0,
+            ),
+            NHA_GROUPS => Self::Groups,
+            NHA_MASTER => Self::Master(parse_u32(payload)?),
+            NHA_FDB => Self::Fdb,
+            NHA_RES_GROUP => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(NexthopResGroup::parse(&nla?)?);
+                }
+                Self::ResGroup(nlas)
+            }
+            NHA_RES_BUCKET => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(NexthopResBucket::parse(&nla?)?);
+                }
+                Self::ResBucket(nlas)
+            }
+            NHA_OP_FLAGS => Self::OpFlags(NexthopOpFlags::from_bits_retain(
+                parse_u32(payload)?,
+            )),
+            NHA_GROUP_STATS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(NexthopGroupStats::parse(&nla?)?);
+                }
+                Self::GroupStats(nlas)
+            }
+            NHA_HW_STATS_ENABLE => Self::HwStatsEnable(parse_u32(payload)? > 0),
+            NHA_HW_STATS_USED => Self::HwStatsUsed(parse_u32(payload)? > 0),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub(crate) const NEXTHOP_GROUP_LEN: usize = 8;
+
+buffer!(NexthopGroupBuffer(NEXTHOP_GROUP_LEN) {
+    id: (u32, 0..4),
+    weight: (u8, 4),
+    weight_high: (u8, 5),
+    reserved: (u16, 6..8),
+});
+
+/// Member of nexthop group.
+// Linux kernel struct `struct nexthop_grp`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct NexthopGroup {
+    /// Nexthop ID of the group member
+    pub id: u32,
+    /// Lower 8 bits of weight minus one, i.e. `0` stands for weight `1`
+    pub weight: u8,
+    /// Higher 8 bits of weight minus one
+    pub weight_high: u8,
+}
+
+impl NexthopGroup {
+    pub fn new(id: u32, weight: u16) -> Self {
+        let weight = weight.saturating_sub(1);
+        Self {
+            id,
+            weight: (weight & 0xff) as u8,
+            weight_high: (weight >> 8) as u8,
+        }
+    }
+
+    /// The weight of this member as shown by `ip nexthop show`.
+    pub fn weight(&self) -> u32 {
+        (((self.weight_high as u32) << 8) | self.weight as u32) + 1
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NexthopGroupBuffer<T>> for NexthopGroup {
+    type Error = DecodeError;
+    fn parse(buf: &NexthopGroupBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            id: buf.id(),
+            weight: buf.weight(),
+            weight_high: buf.weight_high(),
+        })
+    }
+}
+
+impl Emitable for NexthopGroup {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_GROUP_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NexthopGroupBuffer::new(buffer);
+        buffer.set_id(self.id);
+        buffer.set_weight(self.weight);
+        buffer.set_weight_high(self.weight_high);
+        buffer.set_reserved(0);
+    }
+}
+
+const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum NexthopGroupType {
+    /// Hash-threshold multipath group
+    Mpath,
+    /// Resilient hashing group
+    Resilient,
+    Other(u16),
+}
+
+impl From<u16> for NexthopGroupType {
+    fn from(d: u16) -> Self {
+        match d {
+            NEXTHOP_GRP_TYPE_MPATH => Self::Mpath,
+            NEXTHOP_GRP_TYPE_RES => Self::Resilient,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<NexthopGroupType> for u16 {
+    fn from(v: NexthopGroupType) -> u16 {
+        match v {
+            NexthopGroupType::Mpath => NEXTHOP_GRP_TYPE_MPATH,
+            NexthopGroupType::Resilient => NEXTHOP_GRP_TYPE_RES,
+            NexthopGroupType::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    route::{RouteProtocol, RouteScope},
+    AddressFamily,
+};
+
+const NEXTHOP_HEADER_LEN: usize = 8;
+
+buffer!(NexthopMessageBuffer(NEXTHOP_HEADER_LEN) {
+    family: (u8, 0),
+    scope: (u8, 1),
+    protocol: (u8, 2),
+    reserved: (u8, 3),
+    flags: (u32, 4..NEXTHOP_HEADER_LEN),
+    payload: (slice, NEXTHOP_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NexthopMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload()).map(|nla| {
+            nla.map_err(|e| {
+                DecodeError::from(format!(
+                    "Failed to parse nexthop attributes {e}"
+                ))
+            })
+        })
+    }
+}
+
+const RTNH_F_DEAD: u32 = 1 << 0;
+const RTNH_F_PERVASIVE: u32 = 1 << 1;
+const RTNH_F_ONLINK: u32 = 1 << 2;
+const RTNH_F_OFFLOAD: u32 = 1 << 3;
+const RTNH_F_LINKDOWN: u32 = 1 << 4;
+const RTNH_F_UNRESOLVED: u32 = 1 << 5;
+const RTNH_F_TRAP: u32 = 1 << 6;
+
+bitflags! {
+    /// The `RTNH_F_*` flags in `struct nhmsg`, which is `u32` while
+    /// [crate::route::RouteNextHopFlags] only holds the lower 8 bits.
+    #[derive(Clone, Eq, PartialEq, Debug, Copy, Default)]
+    #[non_exhaustive]
+    pub struct NexthopFlags: u32 {
+        const Dead = RTNH_F_DEAD;
+        const Pervasive = RTNH_F_PERVASIVE;
+        const Onlink = RTNH_F_ONLINK;
+        const Offload = RTNH_F_OFFLOAD;
+        const Linkdown = RTNH_F_LINKDOWN;
+        const Unresolved = RTNH_F_UNRESOLVED;
+        const Trap = RTNH_F_TRAP;
+        const _ = !0;
+    }
+}
+
+// Linux kernel struct `struct nhmsg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NexthopHeader {
+    /// [AddressFamily::Inet] or [AddressFamily::Inet6] for nexthop with
+    /// gateway, [AddressFamily::Unspec] for group, blackhole or device only
+    /// nexthop.
+    pub family: AddressFamily,
+    /// Kernel will always set this to [RouteScope::Universe] or
+    /// [RouteScope::Link].
+    pub scope: RouteScope,
+    pub protocol: RouteProtocol,
+    pub flags: NexthopFlags,
+}
+
+impl<T: AsRef<[u8]>> Parseable<NexthopMessageBuffer<T>> for NexthopHeader {
+    type Error = DecodeError;
+    fn parse(buf: &NexthopMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            scope: buf.scope().into(),
+            protocol: buf.protocol().into(),
+            flags: NexthopFlags::from_bits_retain(buf.flags()),
+        })
+    }
+}
+
+impl Emitable for NexthopHeader {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NexthopMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_scope(self.scope.into());
+        packet.set_protocol(self.protocol.into());
+        packet.set_reserved(0);
+        packet.set_flags(self.flags.bits());
+    }
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod group;
+mod header;
+mod message;
+mod resilient;
+mod stats;
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::{NexthopAttribute, NexthopOpFlags};
+pub use self::group::{NexthopGroup, NexthopGroupBuffer, NexthopGroupType};
+pub use self::header::{NexthopFlags, NexthopHeader, NexthopMessageBuffer};
+pub use self::message::NexthopMessage;
+pub use self::resilient::{NexthopResBucket, NexthopResGroup};
+pub use self::stats::{NexthopGroupStats, NexthopGroupStatsEntry};
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+// const NHA_RES_GROUP_PAD: u16 = 0;
+const NHA_RES_GROUP_BUCKETS: u16 = 1;
+const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+const NHA_RES_GROUP_UNBALANCED_TIME: u16 = 4;
+
+/// Attributes of the `NHA_RES_GROUP` nested attribute.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum NexthopResGroup {
+    /// Number of buckets in the resilient group
+    Buckets(u16),
+    /// Idle timer in clock ticks (`clock_t`)
+    IdleTimer(u32),
+    /// Unbalanced timer in clock ticks (`clock_t`)
+    UnbalancedTimer(u32),
+    /// Time since the group has been unbalanced, in clock ticks
+    UnbalancedTime(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopResGroup {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::UnbalancedTime(_) => 8,
+            Self::IdleTimer(_) | Self::UnbalancedTimer(_) => 4,
+            Self::Buckets(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Buckets(value) => NativeEndian::write_u16(buffer, *value),
+            Self::IdleTimer(value) | Self::UnbalancedTimer(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::UnbalancedTime(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Buckets(_) => NHA_RES_GROUP_BUCKETS,
+            Self::IdleTimer(_) => NHA_RES_GROUP_IDLE_TIMER,
+            Self::UnbalancedTimer(_) => NHA_RES_GROUP_UNBALANCED_TIMER,
+            Self::UnbalancedTime(_) => NHA_RES_GROUP_UNBALANCED_TIME,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopResGroup
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_GROUP_BUCKETS => Self::Buckets(parse_u16(payload)?),
+            NHA_RES_GROUP_IDLE_TIMER => Self::IdleTimer(parse_u32(payload)?),
+            NHA_RES_GROUP_UNBALANCED_TIMER => {
+                Self::UnbalancedTimer(parse_u32(payload)?)
+            }
+            NHA_RES_GROUP_UNBALANCED_TIME => {
+                Self::UnbalancedTime(parse_u64(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+// const NHA_RES_BUCKET_PAD: u16 = 0;
+const NHA_RES_BUCKET_INDEX: u16 = 1;
+const NHA_RES_BUCKET_IDLE_TIME: u16 = 2;
+const NHA_RES_BUCKET_NH_ID: u16 = 3;
+
+/// Attributes of the `NHA_RES_BUCKET` nested attribute.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum NexthopResBucket {
+    Index(u16),
+    /// Time since last use of this bucket, in clock ticks
+    IdleTime(u64),
+    /// Nexthop ID this bucket is assigned to
+    NexthopId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopResBucket {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IdleTime(_) => 8,
+            Self::NexthopId(_) => 4,
+            Self::Index(_) => 2,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Index(value) => NativeEndian::write_u16(buffer, *value),
+            Self::IdleTime(value) => NativeEndian::write_u64(buffer, *value),
+            Self::NexthopId(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Index(_) => NHA_RES_BUCKET_INDEX,
+            Self::IdleTime(_) => NHA_RES_BUCKET_IDLE_TIME,
+            Self::NexthopId(_) => NHA_RES_BUCKET_NH_ID,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopResBucket
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_BUCKET_INDEX => Self::Index(parse_u16(payload)?),
+            NHA_RES_BUCKET_IDLE_TIME => Self::IdleTime(parse_u64(payload)?),
+            NHA_RES_BUCKET_NH_ID => Self::NexthopId(parse_u32(payload)?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const NHA_GROUP_STATS_ENTRY: u16 = 1;
+
+/// Attributes of the `NHA_GROUP_STATS` nested attribute.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum NexthopGroupStats {
+    Entry(Vec<NexthopGroupStatsEntry>),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopGroupStats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => NHA_GROUP_STATS_ENTRY | NLA_F_NESTED,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopGroupStats
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_GROUP_STATS_ENTRY => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(NexthopGroupStatsEntry::parse(nla)?);
+                }
+                Self::Entry(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const NHA_GROUP_STATS_ENTRY_ID: u16 = 1;
+const NHA_GROUP_STATS_ENTRY_PACKETS: u16 = 2;
+const NHA_GROUP_STATS_ENTRY_PACKETS_HW: u16 = 3;
+
+/// Statistics of a single member of nexthop group.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum NexthopGroupStatsEntry {
+    /// Nexthop ID of the group member
+    Id(u32),
+    /// Packets forwarded via this member
+    Packets(u64),
+    /// Packets forwarded by hardware via this member
+    PacketsHw(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopGroupStatsEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Packets(value) | Self::PacketsHw(value) => uint_len(*value),
+            Self::Id(_) => 4,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Packets(value) | Self::PacketsHw(value) => {
+                emit_uint(buffer, *value)
+            }
+            Self::Id(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => NHA_GROUP_STATS_ENTRY_ID,
+            Self::Packets(_) => NHA_GROUP_STATS_ENTRY_PACKETS,
+            Self::PacketsHw(_) => NHA_GROUP_STATS_ENTRY_PACKETS_HW,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopGroupStatsEntry
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_GROUP_STATS_ENTRY_ID => Self::Id(parse_u32(payload)?),
+            NHA_GROUP_STATS_ENTRY_PACKETS => {
+                Self::Packets(parse_uint(payload)?)
+            }
+            NHA_GROUP_STATS_ENTRY_PACKETS_HW => {
+                Self::PacketsHw(parse_uint(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+// The kernel `nla_put_uint()` only uses 8 bytes when value does not fit
+// into `u32`.
+fn uint_len(value: u64) -> usize {
+    if value > u32::MAX as u64 {
+        8
+    } else {
+        4
+    }
+}
+
+fn emit_uint(buffer: &mut [u8], value: u64) {
+    if value > u32::MAX as u64 {
+        NativeEndian::write_u64(buffer, value)
+    } else {
+        NativeEndian::write_u32(buffer, value as u32)
+    }
+}
+
+fn parse_uint(payload: &[u8]) -> Result<u64, DecodeError> {
+    if payload.len() == 4 {
+        Ok(parse_u32(payload)?.into())
+    } else {
+        parse_u64(payload)
+    }
+}
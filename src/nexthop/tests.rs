@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    nexthop::{
+        NexthopAttribute, NexthopFlags, NexthopGroup, NexthopGroupType,
+        NexthopHeader, NexthopMessage, NexthopMessageBuffer, NexthopOpFlags,
+        NexthopResBucket, NexthopResGroup,
+    },
+    route::{RouteProtocol, RouteScope},
+    AddressFamily,
+};
+
+// Setup:
+//      ip nexthop add id 1 via 192.168.1.1 dev eth0 proto zebra
+// Kernel resolves the scope of gateway nexthop to link.
+// Kernel reply of `ip nexthop show id 1`
+#[test]
+fn test_nexthop_gateway() {
+    let raw: Vec<u8> = vec![
+        0x02, 0xfd, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x08, 0x00, 0x05, 0x00, 0x03, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x06, 0x00, 0xc0, 0xa8, 0x01, 0x01,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            family: AddressFamily::Inet,
+            scope: RouteScope::Link,
+            protocol: RouteProtocol::Zebra,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(1),
+            NexthopAttribute::Oif(3),
+            NexthopAttribute::Gateway(
+                Ipv4Addr::from_str("192.168.1.1").unwrap().into(),
+            ),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip nexthop add id 1 via 192.168.1.1 dev eth0
+//      ip nexthop add id 2 via 192.168.1.2 dev eth0
+//      ip nexthop add id 10 group 1/2,5 type resilient buckets 32 \
+//          idle_timer 120 proto zebra
+// Kernel reply of `ip nexthop show id 10`
+#[test]
+fn test_nexthop_resilient_group() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x06, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x14, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x28, 0x00, 0x0c, 0x80,
+        0x06, 0x00, 0x01, 0x00, 0x20, 0x00, 0x00, 0x00, 0x08, 0x00, 0x02, 0x00,
+        0xe0, 0x2e, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0e, 0x00, 0x00, 0x00, 0x00, 0x80,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            family: AddressFamily::Unspec,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Zebra,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(10),
+            NexthopAttribute::GroupType(NexthopGroupType::Resilient),
+            NexthopAttribute::Group(vec![
+                NexthopGroup::new(1, 1),
+                NexthopGroup::new(2, 5),
+            ]),
+            NexthopAttribute::ResGroup(vec![
+                NexthopResGroup::Buckets(32),
+                NexthopResGroup::IdleTimer(12000),
+                NexthopResGroup::UnbalancedTimer(0),
+                NexthopResGroup::UnbalancedTime(0),
+            ]),
+            NexthopAttribute::OpFlags(NexthopOpFlags::RespGrpResvd0),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Using the resilient group created by `test_nexthop_resilient_group`,
+// kernel RTM_NEWNEXTHOPBUCKET reply of `ip nexthop bucket get id 10 index 3`
+#[test]
+fn test_nexthop_bucket() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x20, 0x00, 0x0d, 0x80, 0x06, 0x00, 0x01, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0xae, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = NexthopMessage {
+        header: NexthopHeader {
+            family: AddressFamily::Unspec,
+            scope: RouteScope::Universe,
+            protocol: RouteProtocol::Zebra,
+            flags: NexthopFlags::empty(),
+        },
+        attributes: vec![
+            NexthopAttribute::Id(10),
+            NexthopAttribute::ResBucket(vec![
+                NexthopResBucket::Index(3),
+                NexthopResBucket::NexthopId(2),
+                NexthopResBucket::IdleTime(942),
+            ]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        NexthopMessage::parse(&NexthopMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_nexthop_group_weight() {
+    let member = NexthopGroup::new(1, 300);
+    assert_eq!(member.weight, 43);
+    assert_eq!(member.weight_high, 1);
+    assert_eq!(member.weight(), 300);
+}
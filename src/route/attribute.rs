@@ -46,7 +46,7 @@ const RTA_TTL_PROPAGATE: u16 = 26;
 // const RTA_IP_PROTO:u16 = 27;
 // const RTA_SPORT:u16 = 28;
 // const RTA_DPORT:u16 = 29;
-// const RTA_NH_ID:u16 = 30;
+const RTA_NH_ID: u16 = 30;
 
 /// Netlink attributes for `RTM_NEWROUTE`, `RTM_DELROUTE`,
 /// `RTM_GETROUTE` netlink messages.
@@ -83,6 +83,9 @@ pub enum RouteAttribute {
     Realm(RouteRealm),
     Table(u32),
     Mark(u32),
+    /// ID of the nexthop object used by this route, see
+    /// [crate::nexthop::NexthopMessage]
+    NhId(u32),
     Other(DefaultNla),
 }
 
@@ -112,7 +115,8 @@ impl Nla for RouteAttribute {
             | Self::Oif(_)
             | Self::Priority(_)
             | Self::Table(_)
-            | Self::Mark(_) => 4,
+            | Self::Mark(_)
+            | Self::NhId(_) => 4,
             Self::MulticastExpires(_) => 8,
             Self::Other(attr) => attr.value_len(),
         }
@@ -148,7 +152,8 @@ impl Nla for RouteAttribute {
             | Self::Oif(value)
             | Self::Priority(value)
             | Self::Table(value)
-            | Self::Mark(value) => NativeEndian::write_u32(buffer, *value),
+            | Self::Mark(value)
+            | Self::NhId(value) => NativeEndian::write_u32(buffer, *value),
             Self::Realm(v) => v.emit(buffer),
             Self::MulticastExpires(value) => {
                 NativeEndian::write_u64(buffer, *value)
@@ -182,6 +187,7 @@ impl Nla for RouteAttribute {
             Self::MulticastExpires(_) => RTA_EXPIRES,
             Self::Uid(_) => RTA_UID,
             Self::TtlPropagate(_) => RTA_TTL_PROPAGATE,
+            Self::NhId(_) => RTA_NH_ID,
             Self::Other(ref attr) => attr.kind(),
         }
     }
@@ -255,6 +261,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
             RTA_FLOW => Self::Realm(RouteRealm::parse(payload)?),
             RTA_TABLE => Self::Table(parse_u32(payload)?),
             RTA_MARK => Self::Mark(parse_u32(payload)?),
+            RTA_NH_ID => Self::NhId(parse_u32(payload)?),
 
             RTA_CACHEINFO => Self::CacheInfo(RouteCacheInfo::parse(
                 &RouteCacheInfoBuffer::new_checked(payload)?,
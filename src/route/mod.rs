@@ -5,7 +5,7 @@ mod attribute;
 mod cache_info;
 mod flags;
 mod header;
-mod lwtunnel;
+pub(crate) mod lwtunnel;
 mod message;
 pub(crate) mod metrics;
 mod mfc_stats;
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+use netlink_packet_utils::traits::{Emitable, Parseable};
+
+use crate::route::flags::RouteFlags;
+use crate::route::{
+    RouteAttribute, RouteHeader, RouteMessage, RouteMessageBuffer,
+    RouteProtocol, RouteScope, RouteType,
+};
+use crate::AddressFamily;
+
+// Setup:
+//      sysctl -w net.ipv4.nexthop_compat_mode=0
+//      ip nexthop add id 1 via 192.168.1.1 dev eth0
+//      ip route add 10.0.0.0/24 nhid 1 proto bgp metric 20
+// Kernel reply of `ip route show 10.0.0.0/24`
+#[test]
+fn test_ipv4_route_nh_id() {
+    let raw = vec![
+        0x02, 0x18, 0x00, 0x00, 0xfe, 0xba, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x0f, 0x00, 0xfe, 0x00, 0x00, 0x00, 0x08, 0x00, 0x01, 0x00,
+        0x0a, 0x00, 0x00, 0x00, 0x08, 0x00, 0x06, 0x00, 0x14, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x1e, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteMessage {
+        header: RouteHeader {
+            address_family: AddressFamily::Inet,
+            destination_prefix_length: 24,
+            source_prefix_length: 0,
+            tos: 0,
+            table: 254,
+            protocol: RouteProtocol::Bgp,
+            scope: RouteScope::Universe,
+            kind: RouteType::Unicast,
+            flags: RouteFlags::empty(),
+        },
+        attributes: vec![
+            RouteAttribute::Table(254),
+            RouteAttribute::Destination(
+                Ipv4Addr::from_str("10.0.0.0").unwrap().into(),
+            ),
+            RouteAttribute::Priority(20),
+            RouteAttribute::NhId(1),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        RouteMessage::parse(&RouteMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
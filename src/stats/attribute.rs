@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{OffloadXstats, StatsAfSpec, StatsLinkXstats};
+use crate::link::{
+    buffer_tool::expand_buffer_if_small, stats64::LINK_STATS64_LEN, Stats64,
+    Stats64Buffer,
+};
+
+const IFLA_STATS_LINK_64: u16 = 1;
+const IFLA_STATS_LINK_XSTATS: u16 = 2;
+const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+const IFLA_STATS_AF_SPEC: u16 = 5;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum StatsAttribute {
+    Link64(Stats64),
+    /// Extended statistics of the interface itself
+    LinkXstats(Vec<StatsLinkXstats>),
+    /// Extended statistics of the interface as port of its controller
+    LinkXstatsSlave(Vec<StatsLinkXstats>),
+    LinkOffloadXstats(Vec<OffloadXstats>),
+    AfSpec(Vec<StatsAfSpec>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Link64(value) => value.buffer_len(),
+            Self::LinkXstats(nlas) => nlas.as_slice().buffer_len(),
+            Self::LinkXstatsSlave(nlas) => nlas.as_slice().buffer_len(),
+            Self::LinkOffloadXstats(nlas) => nlas.as_slice().buffer_len(),
+            Self::AfSpec(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link64(value) => value.emit(buffer),
+            Self::LinkXstats(nlas) => nlas.as_slice().emit(buffer),
+            Self::LinkXstatsSlave(nlas) => nlas.as_slice().emit(buffer),
+            Self::LinkOffloadXstats(nlas) => nlas.as_slice().emit(buffer),
+            Self::AfSpec(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link64(_) => IFLA_STATS_LINK_64,
+            Self::LinkXstats(_) => IFLA_STATS_LINK_XSTATS,
+            Self::LinkXstatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            Self::LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            Self::AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsAttribute
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_LINK_64 => {
+                Self::Link64(Stats64::parse(&Stats64Buffer::new(
+                    expand_buffer_if_small(
+                        payload,
+                        LINK_STATS64_LEN,
+                        "IFLA_STATS_LINK_64",
+                    )
+                    .as_slice(),
+                ))?)
+            }
+            IFLA_STATS_LINK_XSTATS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(StatsLinkXstats::parse(nla)?);
+                }
+                Self::LinkXstats(nlas)
+            }
+            IFLA_STATS_LINK_XSTATS_SLAVE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(StatsLinkXstats::parse(nla)?);
+                }
+                Self::LinkXstatsSlave(nlas)
+            }
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(OffloadXstats::parse(nla)?);
+                }
+                Self::LinkOffloadXstats(nlas)
+            }
+            IFLA_STATS_AF_SPEC => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(StatsAfSpec::parse(nla)?);
+                }
+                Self::AfSpec(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u64,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const BOND_XSTATS_3AD: u16 = 1;
+
+const BOND_3AD_STAT_LACPDU_RX: u16 = 0;
+const BOND_3AD_STAT_LACPDU_TX: u16 = 1;
+const BOND_3AD_STAT_LACPDU_UNKNOWN_RX: u16 = 2;
+const BOND_3AD_STAT_LACPDU_ILLEGAL_RX: u16 = 3;
+const BOND_3AD_STAT_MARKER_RX: u16 = 4;
+const BOND_3AD_STAT_MARKER_TX: u16 = 5;
+const BOND_3AD_STAT_MARKER_RESP_RX: u16 = 6;
+const BOND_3AD_STAT_MARKER_RESP_TX: u16 = 7;
+const BOND_3AD_STAT_MARKER_UNKNOWN_RX: u16 = 8;
+// const BOND_3AD_STAT_PAD: u16 = 9;
+
+/// Bond and bond port statistics nested in `LINK_XSTATS_TYPE_BOND`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum BondXstats {
+    /// 802.3ad (LACP) statistics
+    Lacp(Vec<Bond3adStats>),
+    Other(DefaultNla),
+}
+
+impl Nla for BondXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Lacp(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Lacp(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Lacp(_) => BOND_XSTATS_3AD,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BondXstats {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BOND_XSTATS_3AD => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(Bond3adStats::parse(nla)?);
+                }
+                Self::Lacp(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum Bond3adStats {
+    LacpduRx(u64),
+    LacpduTx(u64),
+    LacpduUnknownRx(u64),
+    LacpduIllegalRx(u64),
+    MarkerRx(u64),
+    MarkerTx(u64),
+    MarkerRespRx(u64),
+    MarkerRespTx(u64),
+    MarkerUnknownRx(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for Bond3adStats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::LacpduRx(_)
+            | Self::LacpduTx(_)
+            | Self::LacpduUnknownRx(_)
+            | Self::LacpduIllegalRx(_)
+            | Self::MarkerRx(_)
+            | Self::MarkerTx(_)
+            | Self::MarkerRespRx(_)
+            | Self::MarkerRespTx(_)
+            | Self::MarkerUnknownRx(_) => 8,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::LacpduRx(value)
+            | Self::LacpduTx(value)
+            | Self::LacpduUnknownRx(value)
+            | Self::LacpduIllegalRx(value)
+            | Self::MarkerRx(value)
+            | Self::MarkerTx(value)
+            | Self::MarkerRespRx(value)
+            | Self::MarkerRespTx(value)
+            | Self::MarkerUnknownRx(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::LacpduRx(_) => BOND_3AD_STAT_LACPDU_RX,
+            Self::LacpduTx(_) => BOND_3AD_STAT_LACPDU_TX,
+            Self::LacpduUnknownRx(_) => BOND_3AD_STAT_LACPDU_UNKNOWN_RX,
+            Self::LacpduIllegalRx(_) => BOND_3AD_STAT_LACPDU_ILLEGAL_RX,
+            Self::MarkerRx(_) => BOND_3AD_STAT_MARKER_RX,
+            Self::MarkerTx(_) => BOND_3AD_STAT_MARKER_TX,
+            Self::MarkerRespRx(_) => BOND_3AD_STAT_MARKER_RESP_RX,
+            Self::MarkerRespTx(_) => BOND_3AD_STAT_MARKER_RESP_TX,
+            Self::MarkerUnknownRx(_) => BOND_3AD_STAT_MARKER_UNKNOWN_RX,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Bond3adStats {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BOND_3AD_STAT_LACPDU_RX => Self::LacpduRx(parse_u64(payload)?),
+            BOND_3AD_STAT_LACPDU_TX => Self::LacpduTx(parse_u64(payload)?),
+            BOND_3AD_STAT_LACPDU_UNKNOWN_RX => {
+                Self::LacpduUnknownRx(parse_u64(payload)?)
+            }
+            BOND_3AD_STAT_LACPDU_ILLEGAL_RX => {
+                Self::LacpduIllegalRx(parse_u64(payload)?)
+            }
+            BOND_3AD_STAT_MARKER_RX => Self::MarkerRx(parse_u64(payload)?),
+            BOND_3AD_STAT_MARKER_TX => Self::MarkerTx(parse_u64(payload)?),
+            BOND_3AD_STAT_MARKER_RESP_RX => {
+                Self::MarkerRespRx(parse_u64(payload)?)
+            }
+            BOND_3AD_STAT_MARKER_RESP_TX => {
+                Self::MarkerRespTx(parse_u64(payload)?)
+            }
+            BOND_3AD_STAT_MARKER_UNKNOWN_RX => {
+                Self::MarkerUnknownRx(parse_u64(payload)?)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+
+mod af_spec;
+mod attribute;
+mod bond;
+mod bridge;
+mod header;
+mod message;
+mod offload;
+#[cfg(test)]
+mod tests;
+mod xstats;
+
+pub use self::af_spec::{
+    MplsLinkStats, MplsLinkStatsBuffer, MplsStats, StatsAfSpec,
+};
+pub use self::attribute::StatsAttribute;
+pub use self::bond::{Bond3adStats, BondXstats};
+pub use self::bridge::{
+    BridgeMulticastStats, BridgeMulticastStatsBuffer, BridgeStpXstats,
+    BridgeStpXstatsBuffer, BridgeVlanXstats, BridgeVlanXstatsBuffer,
+    BridgeXstats,
+};
+pub use self::header::{StatsFilterMask, StatsHeader, StatsMessageBuffer};
+pub use self::message::StatsMessage;
+pub use self::offload::{
+    HwStats64, HwStats64Buffer, OffloadHwStatsInfo, OffloadHwStatsState,
+    OffloadXstats,
+};
+pub use self::xstats::StatsLinkXstats;
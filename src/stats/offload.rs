@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::{
+    buffer_tool::expand_buffer_if_small, stats64::LINK_STATS64_LEN, Stats64,
+    Stats64Buffer,
+};
+
+const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO: u16 = 2;
+const IFLA_OFFLOAD_XSTATS_L3_STATS: u16 = 3;
+
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST: u16 = 1;
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED: u16 = 2;
+
+/// Offloaded statistics nested in `IFLA_STATS_LINK_OFFLOAD_XSTATS`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum OffloadXstats {
+    /// Packets hit CPU instead of being offloaded
+    CpuHit(Stats64),
+    HwStatsInfo(Vec<OffloadHwStatsInfo>),
+    /// Hardware L3 statistics
+    L3Stats(HwStats64),
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::CpuHit(value) => value.buffer_len(),
+            Self::HwStatsInfo(nlas) => nlas.as_slice().buffer_len(),
+            Self::L3Stats(_) => HW_STATS64_LEN,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::CpuHit(value) => value.emit(buffer),
+            Self::HwStatsInfo(nlas) => nlas.as_slice().emit(buffer),
+            Self::L3Stats(value) => value.emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            Self::HwStatsInfo(_) => {
+                IFLA_OFFLOAD_XSTATS_HW_S_INFO | NLA_F_NESTED
+            }
+            Self::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for OffloadXstats
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => {
+                Self::CpuHit(Stats64::parse(&Stats64Buffer::new(
+                    expand_buffer_if_small(
+                        payload,
+                        LINK_STATS64_LEN,
+                        "IFLA_OFFLOAD_XSTATS_CPU_HIT",
+                    )
+                    .as_slice(),
+                ))?)
+            }
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(OffloadHwStatsInfo::parse(nla)?);
+                }
+                Self::HwStatsInfo(nlas)
+            }
+            IFLA_OFFLOAD_XSTATS_L3_STATS => Self::L3Stats(HwStats64::parse(
+                &HwStats64Buffer::new_checked(payload)?,
+            )?),
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// Hardware statistics availability nested in
+/// `IFLA_OFFLOAD_XSTATS_HW_S_INFO`, indexed by statistics type.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum OffloadHwStatsInfo {
+    L3Stats(Vec<OffloadHwStatsState>),
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadHwStatsInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::L3Stats(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::L3Stats(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS | NLA_F_NESTED,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for OffloadHwStatsInfo
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_L3_STATS => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(OffloadHwStatsState::parse(nla)?);
+                }
+                Self::L3Stats(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum OffloadHwStatsState {
+    /// Hardware statistics collection is requested
+    Request(bool),
+    /// Hardware statistics collection is in use
+    Used(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadHwStatsState {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Request(_) | Self::Used(_) => 1,
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Request(value) | Self::Used(value) => {
+                buffer[0] = *value as u8
+            }
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Request(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST,
+            Self::Used(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for OffloadHwStatsState
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST => {
+                Self::Request(parse_u8(payload)? > 0)
+            }
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED => {
+                Self::Used(parse_u8(payload)? > 0)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const HW_STATS64_LEN: usize = 72;
+
+buffer!(HwStats64Buffer(HW_STATS64_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    multicast: (u64, 64..72),
+});
+
+/// Kernel `struct rtnl_hw_stats64`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct HwStats64 {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub multicast: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<HwStats64Buffer<T>> for HwStats64 {
+    type Error = DecodeError;
+    fn parse(buf: &HwStats64Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            multicast: buf.multicast(),
+        })
+    }
+}
+
+impl Emitable for HwStats64 {
+    fn buffer_len(&self) -> usize {
+        HW_STATS64_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = HwStats64Buffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_multicast(self.multicast);
+    }
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::{
+    link::Stats64,
+    stats::{
+        Bond3adStats, BondXstats, MplsLinkStats, MplsStats, OffloadHwStatsInfo,
+        OffloadHwStatsState, OffloadXstats, StatsAfSpec, StatsAttribute,
+        StatsFilterMask, StatsHeader, StatsLinkXstats, StatsMessage,
+        StatsMessageBuffer,
+    },
+    AddressFamily,
+};
+
+// Kernel reply of `ip stats show dev eth0 group link`
+#[test]
+fn test_stats_link_64() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0xcc, 0x00, 0x01, 0x00, 0xe8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x84, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf4, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x77, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 2,
+            filter_mask: StatsFilterMask::Link64,
+        },
+        attributes: vec![StatsAttribute::Link64(Stats64 {
+            rx_packets: 1000,
+            tx_packets: 900,
+            rx_bytes: 128000,
+            tx_bytes: 96000,
+            multicast: 10,
+            ..Default::default()
+        })],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse(&StatsMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Setup:
+//      ip link add bond0 type bond mode 802.3ad
+//      ip link set eth1 master bond0
+// Kernel reply of `ip stats show dev eth1 group xstats_slave subgroup bond`
+#[test]
+fn test_stats_bond_port_lacp() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x78, 0x00, 0x03, 0x00, 0x74, 0x00, 0x02, 0x00, 0x70, 0x00, 0x01, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x01, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 4,
+            filter_mask: StatsFilterMask::LinkXstatsSlave,
+        },
+        attributes: vec![StatsAttribute::LinkXstatsSlave(vec![
+            StatsLinkXstats::Bond(vec![BondXstats::Lacp(vec![
+                Bond3adStats::LacpduRx(12),
+                Bond3adStats::LacpduTx(13),
+                Bond3adStats::LacpduUnknownRx(0),
+                Bond3adStats::LacpduIllegalRx(0),
+                Bond3adStats::MarkerRx(0),
+                Bond3adStats::MarkerTx(0),
+                Bond3adStats::MarkerRespRx(0),
+                Bond3adStats::MarkerRespTx(0),
+                Bond3adStats::MarkerUnknownRx(0),
+            ])]),
+        ])],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse(&StatsMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
+
+// Kernel RTM_NEWSTATS reply for IFLA_STATS_LINK_OFFLOAD_XSTATS and
+// IFLA_STATS_AF_SPEC of a MPLS enabled interface. The offload statistics is
+// limited to IFLA_OFFLOAD_XSTATS_HW_S_INFO.
+#[test]
+fn test_stats_offload_hw_info_and_mpls() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x04, 0x00, 0x18, 0x00, 0x02, 0x80, 0x14, 0x00, 0x03, 0x80,
+        0x05, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x54, 0x00, 0x05, 0x00, 0x50, 0x00, 0x1c, 0x00,
+        0x4c, 0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf4, 0x01, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x58, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: AddressFamily::Unspec,
+            ifindex: 5,
+            filter_mask: StatsFilterMask::LinkOffloadXstats
+                | StatsFilterMask::AfSpec,
+        },
+        attributes: vec![
+            StatsAttribute::LinkOffloadXstats(vec![
+                OffloadXstats::HwStatsInfo(vec![OffloadHwStatsInfo::L3Stats(
+                    vec![
+                        OffloadHwStatsState::Request(true),
+                        OffloadHwStatsState::Used(false),
+                    ],
+                )]),
+            ]),
+            StatsAttribute::AfSpec(vec![StatsAfSpec::Mpls(vec![
+                MplsStats::Link(MplsLinkStats {
+                    rx_packets: 5,
+                    tx_packets: 6,
+                    rx_bytes: 500,
+                    tx_bytes: 600,
+                    rx_noroute: 1,
+                    ..Default::default()
+                }),
+            ])]),
+        ],
+    };
+
+    assert_eq!(
+        expected,
+        StatsMessage::parse(&StatsMessageBuffer::new(&raw)).unwrap()
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+
+    expected.emit(&mut buf);
+
+    assert_eq!(buf, raw);
+}
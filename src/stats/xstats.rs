@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{BondXstats, BridgeXstats};
+
+const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+const LINK_XSTATS_TYPE_BOND: u16 = 2;
+
+/// Extended statistics nested in `IFLA_STATS_LINK_XSTATS` and
+/// `IFLA_STATS_LINK_XSTATS_SLAVE`.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum StatsLinkXstats {
+    Bridge(Vec<BridgeXstats>),
+    Bond(Vec<BondXstats>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsLinkXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Bridge(nlas) => nlas.as_slice().buffer_len(),
+            Self::Bond(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Bridge(nlas) => nlas.as_slice().emit(buffer),
+            Self::Bond(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Bridge(_) => LINK_XSTATS_TYPE_BRIDGE,
+            Self::Bond(_) => LINK_XSTATS_TYPE_BOND,
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsLinkXstats
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LINK_XSTATS_TYPE_BRIDGE => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BridgeXstats::parse(nla)?);
+                }
+                Self::Bridge(nlas)
+            }
+            LINK_XSTATS_TYPE_BOND => {
+                let mut nlas = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    nlas.push(BondXstats::parse(nla)?);
+                }
+                Self::Bond(nlas)
+            }
+            _kind => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
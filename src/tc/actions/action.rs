@@ -9,11 +9,13 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
-use crate::tc::TcStats2;
+use crate::tc::{TcExtensionNla, TcStats2};
 
 use super::{
-    TcActionMirror, TcActionMirrorOption, TcActionNat, TcActionNatOption,
-    TcActionTunnelKey, TcActionTunnelKeyOption,
+    flags::{emit_bitfield32, parse_bitfield32, NLA_BITFIELD32_LEN},
+    TcActionFlags, TcActionFlagsWithSelector, TcActionHwStatsType,
+    TcActionHwStatsWithSelector, TcActionMirror, TcActionMirrorOption,
+    TcActionNat, TcActionNatOption, TcActionTunnelKey, TcActionTunnelKeyOption,
 };
 
 /// TODO: determine when and why to use this as opposed to the buffer's `kind`.
@@ -43,6 +45,39 @@ impl Default for TcAction {
     }
 }
 
+impl TcAction {
+    /// Create an action holding only its [`TcActionAttribute::Kind`], used
+    /// for selecting actions in dump and flush requests.
+    pub fn new_with_kind(kind: &str) -> Self {
+        Self {
+            attributes: vec![TcActionAttribute::Kind(kind.to_string())],
+            ..Default::default()
+        }
+    }
+
+    /// Kind of this action.
+    pub fn kind(&self) -> Option<&str> {
+        self.attributes.iter().find_map(|a| {
+            if let TcActionAttribute::Kind(k) = a {
+                Some(k.as_str())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Index of this action.
+    pub fn index(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|a| {
+            if let TcActionAttribute::Index(i) = a {
+                Some(*i)
+            } else {
+                None
+            }
+        })
+    }
+}
+
 impl Nla for TcAction {
     fn value_len(&self) -> usize {
         self.attributes.as_slice().buffer_len()
@@ -115,9 +150,9 @@ const TCA_ACT_INDEX: u16 = 3;
 const TCA_ACT_STATS: u16 = 4;
 // const TCA_ACT_PAD: u16 = 5;
 const TCA_ACT_COOKIE: u16 = 6;
-// const TCA_ACT_FLAGS: u16 = 7;
-// const TCA_ACT_HW_STATS: u16 = 8;
-// const TCA_ACT_USED_HW_STATS: u16 = 9;
+const TCA_ACT_FLAGS: u16 = 7;
+const TCA_ACT_HW_STATS: u16 = 8;
+const TCA_ACT_USED_HW_STATS: u16 = 9;
 const TCA_ACT_IN_HW_COUNT: u16 = 10;
 
 /// Attributes of a traffic control action.
@@ -165,6 +200,12 @@ pub enum TcActionAttribute {
     ///
     /// [`Cookie`]: #variant.Cookie
     Cookie(Vec<u8>),
+    /// Flags of the action.
+    Flags(TcActionFlagsWithSelector),
+    /// Type of hardware statistics requested for the action.
+    HwStats(TcActionHwStatsWithSelector),
+    /// Type of hardware statistics used by the driver offloading the action.
+    UsedHwStats(TcActionHwStatsWithSelector),
     /// Number of times the action has been installed in hardware.
     InHwCount(u32),
     /// Other attributes unknown at the time of writing or not yet supported by
@@ -179,6 +220,9 @@ impl Nla for TcActionAttribute {
             Self::Kind(k) => k.len() + 1,
             Self::Options(opt) => opt.as_slice().buffer_len(),
             Self::Index(_) | Self::InHwCount(_) => 4,
+            Self::Flags(_) | Self::HwStats(_) | Self::UsedHwStats(_) => {
+                NLA_BITFIELD32_LEN
+            }
             Self::Stats(s) => s.as_slice().buffer_len(),
             Self::Other(attr) => attr.value_len(),
         }
@@ -195,6 +239,12 @@ impl Nla for TcActionAttribute {
                 NativeEndian::write_u32(buffer, *value);
             }
             Self::Stats(s) => s.as_slice().emit(buffer),
+            Self::Flags(v) => {
+                emit_bitfield32(buffer, v.flags.bits(), v.selector.bits())
+            }
+            Self::HwStats(v) | Self::UsedHwStats(v) => {
+                emit_bitfield32(buffer, v.stats_type.bits(), v.selector.bits())
+            }
             Self::Other(attr) => attr.emit_value(buffer),
         }
     }
@@ -205,6 +255,9 @@ impl Nla for TcActionAttribute {
             Self::Index(_) => TCA_ACT_INDEX,
             Self::Stats(_) => TCA_ACT_STATS,
             Self::Cookie(_) => TCA_ACT_COOKIE,
+            Self::Flags(_) => TCA_ACT_FLAGS,
+            Self::HwStats(_) => TCA_ACT_HW_STATS,
+            Self::UsedHwStats(_) => TCA_ACT_USED_HW_STATS,
             Self::InHwCount(_) => TCA_ACT_IN_HW_COUNT,
             Self::Other(nla) => nla.kind(),
         }
@@ -247,6 +300,28 @@ where
             TCA_ACT_IN_HW_COUNT => {
                 TcActionAttribute::InHwCount(parse_u32(buf.value())?)
             }
+            TCA_ACT_FLAGS => {
+                let (flags, selector) = parse_bitfield32(buf, "TCA_ACT_FLAGS")?;
+                TcActionAttribute::Flags(TcActionFlagsWithSelector {
+                    flags: TcActionFlags::from_bits_retain(flags),
+                    selector: TcActionFlags::from_bits_retain(selector),
+                })
+            }
+            TCA_ACT_HW_STATS | TCA_ACT_USED_HW_STATS => {
+                let (stats_type, selector) =
+                    parse_bitfield32(buf, "TCA_ACT_HW_STATS")?;
+                let v = TcActionHwStatsWithSelector {
+                    stats_type: TcActionHwStatsType::from_bits_retain(
+                        stats_type,
+                    ),
+                    selector: TcActionHwStatsType::from_bits_retain(selector),
+                };
+                if buf.kind() == TCA_ACT_HW_STATS {
+                    TcActionAttribute::HwStats(v)
+                } else {
+                    TcActionAttribute::UsedHwStats(v)
+                }
+            }
             _ => TcActionAttribute::Other(DefaultNla::parse(buf)?),
         })
     }
@@ -277,6 +352,11 @@ pub enum TcActionOption {
     /// These options type can be used to assign encapsulation properties to
     /// the packet.
     TunnelKey(TcActionTunnelKeyOption),
+    /// Options of action kind unknown to this crate, built by the user.
+    ///
+    /// Parsing never produces this variant, use
+    /// [`TcExtensionNla::parse_with`] to decode `Other`.
+    Extension(TcExtensionNla),
     /// Other action types not yet supported by this library.
     Other(DefaultNla),
 }
@@ -287,6 +367,7 @@ impl Nla for TcActionOption {
             Self::Mirror(nla) => nla.value_len(),
             Self::Nat(nla) => nla.value_len(),
             Self::TunnelKey(nla) => nla.value_len(),
+            Self::Extension(nla) => nla.value_len(),
             Self::Other(nla) => nla.value_len(),
         }
     }
@@ -296,6 +377,7 @@ impl Nla for TcActionOption {
             Self::Mirror(nla) => nla.emit_value(buffer),
             Self::Nat(nla) => nla.emit_value(buffer),
             Self::TunnelKey(nla) => nla.emit_value(buffer),
+            Self::Extension(nla) => nla.emit_value(buffer),
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
@@ -305,6 +387,7 @@ impl Nla for TcActionOption {
             Self::Mirror(nla) => nla.kind(),
             Self::Nat(nla) => nla.kind(),
             Self::TunnelKey(nla) => nla.kind(),
+            Self::Extension(nla) => nla.kind(),
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -542,11 +625,20 @@ impl From<TcActionType> for i32 {
 
 pub const TC_TCF_BUF_LEN: usize = 32;
 
+/// Timestamps of an action (kernel `struct tcf_t`), shared by all action
+/// kinds as their `*_TM` attribute, e.g. [`TcActionMirrorOption::Tm`].
+///
+/// All values are in clock ticks (`USER_HZ`) relative to now, `0` means
+/// never.
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct Tcf {
+    /// Time since the action was installed.
     pub install: u64,
+    /// Time since the action was last used.
     pub lastuse: u64,
+    /// Time until the action expires.
     pub expires: u64,
+    /// Time since the action was first used.
     pub firstuse: u64,
 }
 
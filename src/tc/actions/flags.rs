@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlaBuffer, parsers::parse_u32, DecodeError};
+
+const TCA_ACT_FLAGS_NO_PERCPU_STATS: u32 = 1 << 0;
+const TCA_ACT_FLAGS_SKIP_HW: u32 = 1 << 1;
+const TCA_ACT_FLAGS_SKIP_SW: u32 = 1 << 2;
+
+bitflags! {
+    /// Flags of an action (`TCA_ACT_FLAGS`).
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+    #[non_exhaustive]
+    pub struct TcActionFlags: u32 {
+        /// Do not allocate per-cpu statistics, trading precision for
+        /// update speed.
+        const NoPercpuStats = TCA_ACT_FLAGS_NO_PERCPU_STATS;
+        /// Do not offload the action to hardware.
+        const SkipHw = TCA_ACT_FLAGS_SKIP_HW;
+        /// Only run the action in hardware.
+        const SkipSw = TCA_ACT_FLAGS_SKIP_SW;
+        const _ = !0;
+    }
+}
+
+const TCA_ACT_HW_STATS_IMMEDIATE: u32 = 1 << 0;
+const TCA_ACT_HW_STATS_DELAYED: u32 = 1 << 1;
+
+bitflags! {
+    /// Type of hardware statistics of an action (`TCA_ACT_HW_STATS` and
+    /// `TCA_ACT_USED_HW_STATS`).
+    ///
+    /// Hardware statistics are disabled when no type is set, i.e.
+    /// [`TcActionHwStatsType::empty()`].
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+    #[non_exhaustive]
+    pub struct TcActionHwStatsType: u32 {
+        /// Statistics are read from hardware on every dump.
+        const Immediate = TCA_ACT_HW_STATS_IMMEDIATE;
+        /// Statistics are periodically fetched from hardware, might be
+        /// outdated.
+        const Delayed = TCA_ACT_HW_STATS_DELAYED;
+        /// Let the driver choose between immediate and delayed.
+        const Any = TCA_ACT_HW_STATS_IMMEDIATE | TCA_ACT_HW_STATS_DELAYED;
+        const _ = !0;
+    }
+}
+
+/// [`TcActionFlagsWithSelector`] holds the [`TcActionFlags`] of an action
+/// along with the `selector` indicating which flags are valid (kernel
+/// `struct nla_bitfield32`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+pub struct TcActionFlagsWithSelector {
+    pub flags: TcActionFlags,
+    /// Any flags which are set in `flags` but which are not set in
+    /// `selector` will be ignored by kernel.
+    pub selector: TcActionFlags,
+}
+
+impl TcActionFlagsWithSelector {
+    /// Create a new [`TcActionFlagsWithSelector`] with `selector` set to
+    /// `flags`.
+    #[must_use]
+    pub fn new(flags: TcActionFlags) -> Self {
+        Self {
+            flags,
+            selector: flags,
+        }
+    }
+}
+
+/// [`TcActionHwStatsWithSelector`] holds the [`TcActionHwStatsType`] of an
+/// action along with the `selector` indicating which types are valid (kernel
+/// `struct nla_bitfield32`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, PartialOrd, Ord, Hash)]
+pub struct TcActionHwStatsWithSelector {
+    pub stats_type: TcActionHwStatsType,
+    /// Any types which are set in `stats_type` but which are not set in
+    /// `selector` will be ignored by kernel.
+    pub selector: TcActionHwStatsType,
+}
+
+impl TcActionHwStatsWithSelector {
+    /// Create a new [`TcActionHwStatsWithSelector`] with `selector` set to
+    /// [`TcActionHwStatsType::Any`], which is what kernel is reporting.
+    #[must_use]
+    pub fn new(stats_type: TcActionHwStatsType) -> Self {
+        Self {
+            stats_type,
+            selector: TcActionHwStatsType::Any,
+        }
+    }
+}
+
+pub(crate) const NLA_BITFIELD32_LEN: usize = 8;
+
+pub(crate) fn parse_bitfield32<T: AsRef<[u8]> + ?Sized>(
+    buf: &NlaBuffer<&T>,
+    nla_name: &str,
+) -> Result<(u32, u32), DecodeError> {
+    let payload = buf.value();
+    if payload.len() != NLA_BITFIELD32_LEN {
+        return Err(DecodeError::from(format!(
+            "Invalid {nla_name} length {}, expecting {NLA_BITFIELD32_LEN}",
+            payload.len()
+        )));
+    }
+    Ok((parse_u32(&payload[..4])?, parse_u32(&payload[4..])?))
+}
+
+pub(crate) fn emit_bitfield32(buffer: &mut [u8], value: u32, selector: u32) {
+    buffer[..4].copy_from_slice(&value.to_ne_bytes());
+    buffer[4..8].copy_from_slice(&selector.to_ne_bytes());
+}
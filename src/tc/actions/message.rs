@@ -4,7 +4,10 @@ use netlink_packet_utils::nla::{DefaultNla, NlaBuffer};
 use netlink_packet_utils::nla::{Nla, NlasIterator};
 use netlink_packet_utils::{DecodeError, Emitable, Parseable};
 
-use crate::tc::actions::{TcActionMessageBuffer, TcActionMessageHeader};
+use crate::tc::actions::{
+    flags::{emit_bitfield32, parse_bitfield32, NLA_BITFIELD32_LEN},
+    TcActionMessageBuffer, TcActionMessageHeader,
+};
 use crate::tc::TcAction;
 
 /// Message to describe [tc-actions]
@@ -19,6 +22,95 @@ pub struct TcActionMessage {
     pub attributes: Vec<TcActionMessageAttribute>,
 }
 
+impl TcActionMessage {
+    /// Create the payload of a `RTM_GETACTION` request with `NLM_F_DUMP` for
+    /// listing all actions of specified `kind`. Large dump is enabled so
+    /// that more than `TCA_ACT_MAX_PRIO` actions are returned.
+    pub fn new_dump(kind: &str) -> Self {
+        Self {
+            header: TcActionMessageHeader::default(),
+            attributes: vec![
+                TcActionMessageAttribute::Actions(vec![
+                    TcAction::new_with_kind(kind),
+                ]),
+                TcActionMessageAttribute::Flags(
+                    TcActionMessageFlagsWithSelector::new(
+                        TcActionMessageFlags::LargeDump,
+                    ),
+                ),
+            ],
+        }
+    }
+
+    /// Create the payload of a `RTM_DELACTION` request with `NLM_F_ROOT`
+    /// for deleting all actions of specified `kind`.
+    ///
+    /// Actions still bound to a filter cannot be deleted and make the whole
+    /// flush fail.
+    pub fn new_flush(kind: &str) -> Self {
+        Self {
+            header: TcActionMessageHeader::default(),
+            attributes: vec![TcActionMessageAttribute::Actions(vec![
+                TcAction::new_with_kind(kind),
+            ])],
+        }
+    }
+
+    /// Only dump actions used within `msecs` milliseconds.
+    #[must_use]
+    pub fn with_time_delta(mut self, msecs: u32) -> Self {
+        self.attributes.retain(|a| {
+            !matches!(a, TcActionMessageAttribute::RootTimeDelta(_))
+        });
+        self.attributes
+            .push(TcActionMessageAttribute::RootTimeDelta(msecs));
+        self
+    }
+
+    /// Number of actions held by this message, reported by kernel in
+    /// replies of dump request.
+    pub fn root_count(&self) -> Option<u32> {
+        self.attributes.iter().find_map(|a| {
+            if let TcActionMessageAttribute::RootCount(c) = a {
+                Some(*c)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Iterate over all actions held by this message. A dump reply might be
+    /// split into multiple messages, use `flat_map()` to collect actions
+    /// of all of them.
+    pub fn actions(&self) -> impl Iterator<Item = &TcAction> {
+        self.attributes
+            .iter()
+            .filter_map(|a| {
+                if let TcActionMessageAttribute::Actions(actions) = a {
+                    Some(actions.iter())
+                } else {
+                    None
+                }
+            })
+            .flatten()
+    }
+
+    /// Consume the message, returning all actions it holds.
+    pub fn into_actions(self) -> Vec<TcAction> {
+        self.attributes
+            .into_iter()
+            .filter_map(|a| {
+                if let TcActionMessageAttribute::Actions(actions) = a {
+                    Some(actions)
+                } else {
+                    None
+                }
+            })
+            .flatten()
+            .collect()
+    }
+}
+
 const TCA_ACT_FLAG_LARGE_DUMP_ON: u32 = 1 << 0;
 const TCA_ACT_FLAG_TERSE_DUMP: u32 = 1 << 1;
 
@@ -60,7 +152,7 @@ pub struct TcActionMessageFlagsWithSelector {
 
 impl Nla for TcActionMessageFlagsWithSelector {
     fn value_len(&self) -> usize {
-        8
+        NLA_BITFIELD32_LEN
     }
 
     fn kind(&self) -> u16 {
@@ -68,8 +160,7 @@ impl Nla for TcActionMessageFlagsWithSelector {
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
-        buffer[..4].copy_from_slice(&self.flags.bits().to_ne_bytes());
-        buffer[4..8].copy_from_slice(&self.selector.bits().to_ne_bytes());
+        emit_bitfield32(buffer, self.flags.bits(), self.selector.bits())
     }
 }
 
@@ -108,26 +199,11 @@ impl<'a, T: AsRef<[u8]> + 'a + ?Sized> Parseable<NlaBuffer<&'a T>>
 {
     type Error = DecodeError;
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        let value = buf.value();
-        if value.len() != 8 {
-            return Err(DecodeError::from("invalid length"));
-        }
-        let flags = TcActionMessageFlags::from_bits(u32::from_ne_bytes(
-            value[0..4].try_into().map_err(|e| {
-                DecodeError::from(format!(
-                    "Failed to parse TcActionMessageFlagsWithSelector: {e}"
-                ))
-            })?,
-        ))
-        .ok_or_else(|| DecodeError::from("invalid flags"))?;
-        let selector = TcActionMessageFlags::from_bits(u32::from_ne_bytes(
-            value[4..].try_into().map_err(|e| {
-                DecodeError::from(format!(
-                    "Failed to parse TcActionMessageFlagsWithSelector selector: {e}"
-                ))
-            })?,
-        ))
-        .ok_or_else(|| DecodeError::from("invalid flags selector"))?;
+        let (flags, selector) = parse_bitfield32(buf, "TCA_ROOT_FLAGS")?;
+        let flags = TcActionMessageFlags::from_bits(flags)
+            .ok_or_else(|| DecodeError::from("invalid flags"))?;
+        let selector = TcActionMessageFlags::from_bits(selector)
+            .ok_or_else(|| DecodeError::from("invalid flags selector"))?;
         Ok(Self::new_with_selector(flags, selector))
     }
 }
@@ -151,7 +227,7 @@ pub enum TcActionMessageAttribute {
     Flags(TcActionMessageFlagsWithSelector),
     /// Number of actions being dumped.
     RootCount(u32),
-    /// Time delta.
+    /// Only dump actions used within this number of milliseconds.
     RootTimeDelta(u32),
     /// Extended warning message.
     RootExtWarnMsg(String),
@@ -185,7 +261,7 @@ impl<'a, T: AsRef<[u8]> + 'a + ?Sized> Parseable<NlaBuffer<&'a T>>
                 Self::RootCount(count)
             }
             TCA_ROOT_TIME_DELTA => {
-                let delta = u32::from_be_bytes(
+                let delta = u32::from_ne_bytes(
                     buf.value().try_into().map_err(|e| {
                         DecodeError::from(format!(
                             "Failed to parse TCA_ROOT_TIME_DELTA: {e}"
@@ -236,7 +312,7 @@ impl Nla for TcActionMessageAttribute {
                 buffer.copy_from_slice(&count.to_ne_bytes());
             }
             Self::RootTimeDelta(delta) => {
-                buffer.copy_from_slice(&delta.to_be_bytes());
+                buffer.copy_from_slice(&delta.to_ne_bytes());
             }
             Self::RootExtWarnMsg(msg) => buffer.copy_from_slice(msg.as_bytes()),
             Self::Other(nla) => nla.emit_value(buffer),
@@ -2,6 +2,11 @@
 
 pub use nat_flag::TcNatFlags;
 
+pub use self::flags::{
+    TcActionFlags, TcActionFlagsWithSelector, TcActionHwStatsType,
+    TcActionHwStatsWithSelector,
+};
+
 pub use self::action::{
     TcAction, TcActionAttribute, TcActionGeneric, TcActionGenericBuffer,
     TcActionOption, TcActionType, Tcf, TcfBuffer, TC_TCF_BUF_LEN,
@@ -21,6 +26,7 @@ pub use self::tunnel_key::{
 };
 
 mod action;
+mod flags;
 mod header;
 mod message;
 mod mirror;
@@ -1,8 +1,9 @@
 // SPDX-License-Identifier: MIT
 
 use crate::tc::{
-    TcAction, TcActionAttribute, TcActionGeneric, TcActionGenericBuffer,
-    TcActionType, TcStats2, TcStatsBasic,
+    TcAction, TcActionAttribute, TcActionFlags, TcActionFlagsWithSelector,
+    TcActionGeneric, TcActionGenericBuffer, TcActionHwStatsType,
+    TcActionHwStatsWithSelector, TcActionType, TcStats2, TcStatsBasic,
 };
 use netlink_packet_utils::nla::NlaBuffer;
 use netlink_packet_utils::{Emitable, Parseable};
@@ -68,3 +69,77 @@ fn tc_action_parse_back_example() {
             .unwrap();
     assert_eq!(orig, parsed);
 }
+
+#[test]
+fn tc_action_parse_back_flags() {
+    let orig = TcAction {
+        tab: 1,
+        attributes: vec![
+            TcActionAttribute::Kind("example".into()),
+            TcActionAttribute::Flags(TcActionFlagsWithSelector::new(
+                TcActionFlags::SkipSw | TcActionFlags::NoPercpuStats,
+            )),
+            TcActionAttribute::HwStats(TcActionHwStatsWithSelector::new(
+                TcActionHwStatsType::Delayed,
+            )),
+            TcActionAttribute::UsedHwStats(TcActionHwStatsWithSelector::new(
+                TcActionHwStatsType::Delayed,
+            )),
+        ],
+    };
+    let mut buffer = vec![0; orig.buffer_len()];
+    orig.emit(&mut buffer);
+    let parsed =
+        TcAction::parse(&NlaBuffer::new_checked(buffer.as_slice()).unwrap())
+            .unwrap();
+    assert_eq!(orig, parsed);
+}
+
+// Setup:
+//      tc actions add action gact pass index 1 hw_stats disabled
+//
+// Raw packet modification:
+//   * Only TCA_ACT_KIND and TCA_ACT_HW_STATS of action kept.
+//   * TCA_ACT_FLAGS with skip_hw and full selector is added by hand, as
+//     iproute2 only sends the `no_percpu` flag.
+#[test]
+fn tc_action_parse_flags_and_hw_stats() {
+    let raw = vec![
+        0x09, 0x00, 0x01, 0x00, // TCA_ACT_KIND
+        0x67, 0x61, 0x63, 0x74, 0x00, 0x00, 0x00, 0x00, // "gact"
+        0x0c, 0x00, 0x07, 0x00, // TCA_ACT_FLAGS
+        0x02, 0x00, 0x00, 0x00, // skip_hw
+        0x07, 0x00, 0x00, 0x00, // selector
+        0x0c, 0x00, 0x08, 0x00, // TCA_ACT_HW_STATS
+        0x00, 0x00, 0x00, 0x00, // disabled
+        0x03, 0x00, 0x00, 0x00, // selector: any
+    ];
+    let mut nla = vec![0x28, 0x00, 0x01, 0x00];
+    nla.extend_from_slice(&raw);
+
+    let expected = TcAction {
+        tab: 1,
+        attributes: vec![
+            TcActionAttribute::Kind("gact".into()),
+            TcActionAttribute::Flags(TcActionFlagsWithSelector {
+                flags: TcActionFlags::SkipHw,
+                selector: TcActionFlags::NoPercpuStats
+                    | TcActionFlags::SkipHw
+                    | TcActionFlags::SkipSw,
+            }),
+            TcActionAttribute::HwStats(TcActionHwStatsWithSelector::new(
+                TcActionHwStatsType::empty(),
+            )),
+        ],
+    };
+
+    assert_eq!(
+        TcAction::parse(&NlaBuffer::new_checked(nla.as_slice()).unwrap())
+            .unwrap(),
+        expected
+    );
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(buf, nla);
+}
@@ -16,7 +16,6 @@ use crate::tc::TcActionAttribute::{Cookie, Index, Kind};
 use crate::AddressFamily;
 
 mod mirror {
-    use netlink_packet_utils::nla::DefaultNla;
     use netlink_packet_utils::Parseable;
 
     use crate::tc::actions::message::TcActionMessage;
@@ -25,7 +24,7 @@ mod mirror {
     };
     use crate::tc::actions::{TcActionMessageBuffer, TcActionMessageHeader};
     use crate::tc::TcActionAttribute::{
-        InHwCount, Kind, Options, Other, Stats,
+        InHwCount, Kind, Options, Stats, UsedHwStats,
     };
     use crate::tc::TcActionMirrorOption::{Parms, Tm};
     use crate::tc::TcActionOption::Mirror;
@@ -33,7 +32,8 @@ mod mirror {
     use crate::tc::TcMirrorActionType::{EgressRedir, IngressMirror};
     use crate::tc::TcStats2::{Basic, BasicHw, Queue};
     use crate::tc::{
-        TcAction, TcActionGeneric, TcMirror, TcStatsBasic, TcStatsQueue, Tcf,
+        TcAction, TcActionGeneric, TcActionHwStatsType,
+        TcActionHwStatsWithSelector, TcMirror, TcStatsBasic, TcStatsQueue, Tcf,
     };
     use crate::AddressFamily;
 
@@ -108,6 +108,53 @@ mod mirror {
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00,
         ];
+        /// Reply messages of [`LIST`] when kernel splits the dump into
+        /// two messages, each holding its own `TCA_ROOT_COUNT` and
+        /// `TCA_ACT_TAB`.
+        ///
+        /// Raw packet modification:
+        ///   * Each action of [`LIST`] moved into its own message with
+        ///     `TCA_ROOT_COUNT` set to 1 and the action tab restarted from 0.
+        pub(super) const LIST_PART1: &[u8] = &[
+            0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0xb4, 0x00, 0x01, 0x00, 0xb0, 0x00, 0x00, 0x00, 0x0b, 0x00,
+            0x01, 0x00, 0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, 0x00, 0x44,
+            0x00, 0x04, 0x00, 0x14, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x14, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18,
+            0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x0c, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
+            0x00, 0x00, 0x08, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48,
+            0x00, 0x02, 0x00, 0x20, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x24, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        pub(super) const LIST_PART2: &[u8] = &[
+            0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x03, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0xb4, 0x00, 0x01, 0x00, 0xb0, 0x00, 0x00, 0x00, 0x0b, 0x00,
+            0x01, 0x00, 0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, 0x00, 0x44,
+            0x00, 0x04, 0x00, 0x14, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x14, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18,
+            0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x0c, 0x00, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
+            0x00, 0x00, 0x08, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x48,
+            0x00, 0x02, 0x00, 0x20, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x24, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
     }
 
     #[test]
@@ -206,9 +253,8 @@ mod mirror {
                                     overlimits: 0,
                                 }),
                             ]),
-                            Other(DefaultNla::new(
-                                9,
-                                vec![0, 0, 0, 0, 3, 0, 0, 0],
+                            UsedHwStats(TcActionHwStatsWithSelector::new(
+                                TcActionHwStatsType::empty(),
                             )),
                             InHwCount(0),
                             Options(vec![
@@ -253,9 +299,8 @@ mod mirror {
                                     overlimits: 0,
                                 }),
                             ]),
-                            Other(DefaultNla::new(
-                                9,
-                                vec![0, 0, 0, 0, 3, 0, 0, 0],
+                            UsedHwStats(TcActionHwStatsWithSelector::new(
+                                TcActionHwStatsType::empty(),
                             )),
                             InHwCount(0),
                             Options(vec![
@@ -288,6 +333,41 @@ mod mirror {
         .unwrap();
         assert_eq!(parsed, expected);
     }
+
+    #[test]
+    fn parse_multipart_list_reply() {
+        let replies: Vec<TcActionMessage> =
+            [message::LIST_PART1, message::LIST_PART2]
+                .iter()
+                .map(|raw| {
+                    TcActionMessage::parse(
+                        &TcActionMessageBuffer::new_checked(raw).unwrap(),
+                    )
+                    .unwrap()
+                })
+                .collect();
+
+        assert_eq!(
+            replies.iter().map(|r| r.root_count()).collect::<Vec<_>>(),
+            vec![Some(1), Some(1)]
+        );
+
+        let indexes: Vec<u32> = replies
+            .into_iter()
+            .flat_map(TcActionMessage::into_actions)
+            .flat_map(|action| action.attributes)
+            .filter_map(|attr| match attr {
+                Options(options) => Some(options),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|option| match option {
+                Mirror(Parms(parms)) => Some(parms.generic.index),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(indexes, vec![1, 2]);
+    }
 }
 
 #[test]
@@ -410,3 +490,74 @@ fn tc_action_message_parse_back_example_value() {
     .unwrap();
     assert_eq!(orig, parsed);
 }
+
+// Capture of request message for
+//
+//     tc actions list action gact since 1000
+const DUMP_SINCE: &[u8] = &[
+    0x00, 0x00, 0x00, 0x00, // header
+    0x14, 0x00, 0x01, 0x00, // TCA_ACT_TAB
+    0x10, 0x00, 0x01, 0x00, // action 1
+    0x09, 0x00, 0x01, 0x00, // TCA_ACT_KIND
+    0x67, 0x61, 0x63, 0x74, 0x00, 0x00, 0x00, 0x00, // "gact"
+    0x0c, 0x00, 0x02, 0x00, // TCA_ROOT_FLAGS
+    0x01, 0x00, 0x00, 0x00, // TCA_ACT_FLAG_LARGE_DUMP_ON
+    0x01, 0x00, 0x00, 0x00, // selector
+    0x08, 0x00, 0x04, 0x00, // TCA_ROOT_TIME_DELTA
+    0xe8, 0x03, 0x00, 0x00, // 1000 ms in host byte order
+];
+
+#[test]
+fn tc_action_message_new_dump() {
+    let msg = TcActionMessage::new_dump("gact").with_time_delta(1000);
+
+    let mut buffer = vec![0; msg.buffer_len()];
+    msg.emit(&mut buffer);
+    assert_eq!(buffer, DUMP_SINCE);
+}
+
+// Kernel reads TCA_ROOT_TIME_DELTA with `nla_get_u32()`, hence it is in host
+// byte order.
+#[test]
+fn tc_action_message_parse_dump_since() {
+    let parsed = TcActionMessage::parse(
+        &TcActionMessageBuffer::new_checked(DUMP_SINCE).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        parsed,
+        TcActionMessage {
+            header: TcActionMessageHeader {
+                family: AddressFamily::Unspec,
+            },
+            attributes: vec![
+                Actions(vec![TcAction {
+                    tab: 1,
+                    attributes: vec![Kind("gact".into())],
+                }]),
+                Flags(TcActionMessageFlagsWithSelector::new(
+                    TcActionMessageFlags::LargeDump,
+                )),
+                RootTimeDelta(1000),
+            ],
+        }
+    );
+}
+
+#[test]
+fn tc_action_message_new_flush() {
+    let msg = TcActionMessage::new_flush("mirred");
+    let mut buffer = vec![0; msg.buffer_len()];
+    msg.emit(&mut buffer);
+    let parsed = TcActionMessage::parse(
+        &TcActionMessageBuffer::new_checked(buffer.as_slice()).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(parsed.root_count(), None);
+    let actions: Vec<&TcAction> = parsed.actions().collect();
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0].kind(), Some("mirred"));
+    assert_eq!(actions[0].index(), None);
+}
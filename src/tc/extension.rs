@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT
+
+use std::{any::Any, fmt::Debug, sync::Arc};
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    DecodeError, Emitable,
+};
+
+/// Option of a qdisc, filter or action kind unknown to this crate, decoded by
+/// a [`TcExtensionParser`] through [`TcExtensionNla::parse_with`].
+pub trait TcExtension: Debug + Send + Sync + 'static {
+    /// Type of the NLA holding this option.
+    fn kind(&self) -> u16;
+    /// Length of the NLA payload.
+    fn value_len(&self) -> usize;
+    /// Write the NLA payload into `buffer`.
+    fn emit_value(&self, buffer: &mut [u8]);
+    /// Used by [`TcExtensionNla::downcast_ref`] to get back the concrete
+    /// type.
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Parser of the nested options of a single qdisc, filter or action kind.
+pub trait TcExtensionParser {
+    /// Parse one NLA nested in `TCA_OPTIONS` (qdisc, class and filter) or
+    /// `TCA_ACT_OPTIONS` (action).
+    fn parse(
+        &self,
+        buf: &NlaBuffer<&[u8]>,
+    ) -> Result<Box<dyn TcExtension>, DecodeError>;
+}
+
+impl<F> TcExtensionParser for F
+where
+    F: Fn(&NlaBuffer<&[u8]>) -> Result<Box<dyn TcExtension>, DecodeError>,
+{
+    fn parse(
+        &self,
+        buf: &NlaBuffer<&[u8]>,
+    ) -> Result<Box<dyn TcExtension>, DecodeError> {
+        self(buf)
+    }
+}
+
+/// Option decoded by a [`TcExtensionParser`].
+///
+/// This crate parses options of unknown kinds as `Other`. Callers knowing
+/// the kind convert them with [`TcExtensionNla::parse_with`] or
+/// [`TcExtensionNla::parse_nested_with`].
+///
+/// Two options are equal when they serialize to the same NLA.
+#[derive(Debug, Clone)]
+pub struct TcExtensionNla(Arc<dyn TcExtension>);
+
+impl TcExtensionNla {
+    /// Wrap `value` so it can be stored in
+    /// [`crate::tc::TcOption::Extension`] or
+    /// [`crate::tc::TcActionOption::Extension`] when building messages.
+    pub fn new<E: TcExtension>(value: E) -> Self {
+        Self(Arc::new(value))
+    }
+
+    /// Get the concrete type returned by the [`TcExtensionParser`].
+    pub fn downcast_ref<E: TcExtension>(&self) -> Option<&E> {
+        self.0.as_any().downcast_ref::<E>()
+    }
+
+    /// Decode a single option stored as
+    /// [`crate::tc::TcActionOption::Other`] using `parser`.
+    pub fn parse_with<P: TcExtensionParser + ?Sized>(
+        nla: &DefaultNla,
+        parser: &P,
+    ) -> Result<Self, DecodeError> {
+        let mut buffer = vec![0u8; nla.buffer_len()];
+        nla.emit(&mut buffer);
+        Ok(parser
+            .parse(&NlaBuffer::new_checked(buffer.as_slice())?)?
+            .into())
+    }
+
+    /// Decode the nested options of a `TCA_OPTIONS` stored as
+    /// [`crate::tc::TcOption::Other`] using `parser`.
+    pub fn parse_nested_with<P: TcExtensionParser + ?Sized>(
+        nla: &DefaultNla,
+        parser: &P,
+    ) -> Result<Vec<Self>, DecodeError> {
+        let mut value = vec![0u8; nla.value_len()];
+        nla.emit_value(&mut value);
+        NlasIterator::new(value.as_slice())
+            .map(|nla| Ok(parser.parse(&nla?)?.into()))
+            .collect()
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = vec![0u8; self.value_len()];
+        self.emit_value(&mut buffer);
+        buffer
+    }
+}
+
+impl From<Box<dyn TcExtension>> for TcExtensionNla {
+    fn from(value: Box<dyn TcExtension>) -> Self {
+        Self(Arc::from(value))
+    }
+}
+
+impl PartialEq for TcExtensionNla {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind() == other.kind() && self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Eq for TcExtensionNla {}
+
+impl Nla for TcExtensionNla {
+    fn value_len(&self) -> usize {
+        self.0.value_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.0.emit_value(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        self.0.kind()
+    }
+}
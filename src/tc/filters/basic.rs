@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+
+/// Basic filter
+///
+/// Classifies packets using an extended match (ematch) expression.
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::TcEmatchTree;
+use crate::tc::{TcAction, TcHandle};
+
+const TCA_BASIC_CLASSID: u16 = 1;
+const TCA_BASIC_EMATCHES: u16 = 2;
+const TCA_BASIC_ACT: u16 = 3;
+const TCA_BASIC_POLICE: u16 = 4;
+const TCA_BASIC_PCNT: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct TcFilterBasic {}
+
+impl TcFilterBasic {
+    pub const KIND: &'static str = "basic";
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcFilterBasicOption {
+    ClassId(TcHandle),
+    Ematches(TcEmatchTree),
+    Action(Vec<TcAction>),
+    Police(Vec<u8>),
+    Pcnt(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterBasicOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ClassId(_) => 4,
+            Self::Ematches(t) => t.buffer_len(),
+            Self::Action(acts) => acts.as_slice().buffer_len(),
+            Self::Police(b) | Self::Pcnt(b) => b.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ClassId(i) => NativeEndian::write_u32(buffer, (*i).into()),
+            Self::Ematches(t) => t.emit(buffer),
+            Self::Action(acts) => acts.as_slice().emit(buffer),
+            Self::Police(b) | Self::Pcnt(b) => {
+                buffer.copy_from_slice(b.as_slice())
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ClassId(_) => TCA_BASIC_CLASSID,
+            Self::Ematches(_) => TCA_BASIC_EMATCHES,
+            Self::Action(_) => TCA_BASIC_ACT,
+            Self::Police(_) => TCA_BASIC_POLICE,
+            Self::Pcnt(_) => TCA_BASIC_PCNT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterBasicOption
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_BASIC_CLASSID => Self::ClassId(parse_u32(payload)?.into()),
+            TCA_BASIC_EMATCHES => Self::Ematches(TcEmatchTree::parse(payload)?),
+            TCA_BASIC_ACT => {
+                let mut acts = vec![];
+                for act in NlasIterator::new(payload) {
+                    let act = act?;
+                    acts.push(TcAction::parse(&act)?);
+                }
+                Self::Action(acts)
+            }
+            TCA_BASIC_POLICE => Self::Police(payload.to_vec()),
+            TCA_BASIC_PCNT => Self::Pcnt(payload.to_vec()),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
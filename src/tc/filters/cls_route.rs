@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+/// Route filter
+///
+/// Classifies packets by the realm of the route they matched, or by their
+/// incoming interface.
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tc::{TcAction, TcHandle};
+
+const TCA_ROUTE4_CLASSID: u16 = 1;
+const TCA_ROUTE4_TO: u16 = 2;
+const TCA_ROUTE4_FROM: u16 = 3;
+const TCA_ROUTE4_IIF: u16 = 4;
+const TCA_ROUTE4_POLICE: u16 = 5;
+const TCA_ROUTE4_ACT: u16 = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct TcFilterRoute {}
+
+impl TcFilterRoute {
+    pub const KIND: &'static str = "route";
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcFilterRouteOption {
+    ClassId(TcHandle),
+    /// Destination realm of route.
+    To(u32),
+    /// Source realm of route.
+    From(u32),
+    /// Interface index the packet was received on.
+    Iif(u32),
+    Police(Vec<u8>),
+    Action(Vec<TcAction>),
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterRouteOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ClassId(_) | Self::To(_) | Self::From(_) | Self::Iif(_) => 4,
+            Self::Police(b) => b.len(),
+            Self::Action(acts) => acts.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ClassId(i) => NativeEndian::write_u32(buffer, (*i).into()),
+            Self::To(i) | Self::From(i) | Self::Iif(i) => {
+                NativeEndian::write_u32(buffer, *i)
+            }
+            Self::Police(b) => buffer.copy_from_slice(b.as_slice()),
+            Self::Action(acts) => acts.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ClassId(_) => TCA_ROUTE4_CLASSID,
+            Self::To(_) => TCA_ROUTE4_TO,
+            Self::From(_) => TCA_ROUTE4_FROM,
+            Self::Iif(_) => TCA_ROUTE4_IIF,
+            Self::Police(_) => TCA_ROUTE4_POLICE,
+            Self::Action(_) => TCA_ROUTE4_ACT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterRouteOption
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ROUTE4_CLASSID => Self::ClassId(parse_u32(payload)?.into()),
+            TCA_ROUTE4_TO => Self::To(parse_u32(payload)?),
+            TCA_ROUTE4_FROM => Self::From(parse_u32(payload)?),
+            TCA_ROUTE4_IIF => Self::Iif(parse_u32(payload)?),
+            TCA_ROUTE4_POLICE => Self::Police(payload.to_vec()),
+            TCA_ROUTE4_ACT => {
+                let mut acts = vec![];
+                for act in NlasIterator::new(payload) {
+                    let act = act?;
+                    acts.push(TcAction::parse(&act)?);
+                }
+                Self::Action(acts)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
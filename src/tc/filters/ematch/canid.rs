@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// CAN identifier filter (`struct can_filter`) of the canid ematch, matches
+/// when `received_can_id & can_mask == can_id & can_mask`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcEmatchCanFilter {
+    pub can_id: u32,
+    pub can_mask: u32,
+}
+
+pub(crate) const CAN_FILTER_LEN: usize = 8;
+
+buffer!(TcEmatchCanFilterBuffer(CAN_FILTER_LEN) {
+    can_id: (u32, 0..4),
+    can_mask: (u32, 4..8),
+});
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcEmatchCanFilterBuffer<&T>>
+    for TcEmatchCanFilter
+{
+    type Error = DecodeError;
+    fn parse(buf: &TcEmatchCanFilterBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            can_id: buf.can_id(),
+            can_mask: buf.can_mask(),
+        })
+    }
+}
+
+impl Emitable for TcEmatchCanFilter {
+    fn buffer_len(&self) -> usize {
+        CAN_FILTER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcEmatchCanFilterBuffer::new(buffer);
+        buffer.set_can_id(self.can_id);
+        buffer.set_can_mask(self.can_mask);
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TCF_EM_ALIGN_U8: u8 = 1;
+const TCF_EM_ALIGN_U16: u8 = 2;
+const TCF_EM_ALIGN_U32: u8 = 4;
+
+const TCF_EM_CMP_TRANS: u8 = 1;
+
+const TCF_LAYER_LINK: u8 = 0;
+const TCF_LAYER_NETWORK: u8 = 1;
+const TCF_LAYER_TRANSPORT: u8 = 2;
+
+const TCF_EM_OPND_EQ: u8 = 0;
+const TCF_EM_OPND_GT: u8 = 1;
+const TCF_EM_OPND_LT: u8 = 2;
+
+/// Compare ematch (`struct tcf_em_cmp`), compares `val` against the masked
+/// data at offset `off` of `layer`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcEmatchCmp {
+    pub val: u32,
+    pub mask: u32,
+    pub off: u16,
+    pub align: TcEmatchAlign,
+    /// Convert data from network byte order before comparing.
+    pub trans: bool,
+    pub layer: TcEmatchLayer,
+    pub opnd: TcEmatchOperand,
+}
+
+const TCF_EM_CMP_LEN: usize = 12;
+
+buffer!(TcEmatchCmpBuffer(TCF_EM_CMP_LEN) {
+    val: (u32, 0..4),
+    mask: (u32, 4..8),
+    off: (u16, 8..10),
+    // align:4 and flags:4
+    align_flags: (u8, 10),
+    // layer:4 and opnd:4
+    layer_opnd: (u8, 11),
+});
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcEmatchCmpBuffer<&T>> for TcEmatchCmp {
+    type Error = DecodeError;
+    fn parse(buf: &TcEmatchCmpBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            val: buf.val(),
+            mask: buf.mask(),
+            off: buf.off(),
+            align: (buf.align_flags() & 0xf).into(),
+            trans: (buf.align_flags() >> 4) & TCF_EM_CMP_TRANS > 0,
+            layer: (buf.layer_opnd() & 0xf).into(),
+            opnd: (buf.layer_opnd() >> 4).into(),
+        })
+    }
+}
+
+impl Emitable for TcEmatchCmp {
+    fn buffer_len(&self) -> usize {
+        TCF_EM_CMP_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcEmatchCmpBuffer::new(buffer);
+        buffer.set_val(self.val);
+        buffer.set_mask(self.mask);
+        buffer.set_off(self.off);
+        let flags = if self.trans { TCF_EM_CMP_TRANS } else { 0 };
+        buffer.set_align_flags((u8::from(self.align) & 0xf) | (flags << 4));
+        buffer.set_layer_opnd(
+            (u8::from(self.layer) & 0xf) | (u8::from(self.opnd) << 4),
+        );
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcEmatchAlign {
+    #[default]
+    U8,
+    U16,
+    U32,
+    Other(u8),
+}
+
+impl From<u8> for TcEmatchAlign {
+    fn from(d: u8) -> Self {
+        match d {
+            TCF_EM_ALIGN_U8 => Self::U8,
+            TCF_EM_ALIGN_U16 => Self::U16,
+            TCF_EM_ALIGN_U32 => Self::U32,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcEmatchAlign> for u8 {
+    fn from(v: TcEmatchAlign) -> u8 {
+        match v {
+            TcEmatchAlign::U8 => TCF_EM_ALIGN_U8,
+            TcEmatchAlign::U16 => TCF_EM_ALIGN_U16,
+            TcEmatchAlign::U32 => TCF_EM_ALIGN_U32,
+            TcEmatchAlign::Other(d) => d,
+        }
+    }
+}
+
+/// Packet layer the offset of ematch is relative to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcEmatchLayer {
+    #[default]
+    Link,
+    Network,
+    Transport,
+    Other(u8),
+}
+
+impl From<u8> for TcEmatchLayer {
+    fn from(d: u8) -> Self {
+        match d {
+            TCF_LAYER_LINK => Self::Link,
+            TCF_LAYER_NETWORK => Self::Network,
+            TCF_LAYER_TRANSPORT => Self::Transport,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcEmatchLayer> for u8 {
+    fn from(v: TcEmatchLayer) -> u8 {
+        match v {
+            TcEmatchLayer::Link => TCF_LAYER_LINK,
+            TcEmatchLayer::Network => TCF_LAYER_NETWORK,
+            TcEmatchLayer::Transport => TCF_LAYER_TRANSPORT,
+            TcEmatchLayer::Other(d) => d,
+        }
+    }
+}
+
+/// Comparison operand of ematch.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcEmatchOperand {
+    #[default]
+    Eq,
+    Gt,
+    Lt,
+    Other(u8),
+}
+
+impl From<u8> for TcEmatchOperand {
+    fn from(d: u8) -> Self {
+        match d {
+            TCF_EM_OPND_EQ => Self::Eq,
+            TCF_EM_OPND_GT => Self::Gt,
+            TCF_EM_OPND_LT => Self::Lt,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcEmatchOperand> for u8 {
+    fn from(v: TcEmatchOperand) -> u8 {
+        match v {
+            TcEmatchOperand::Eq => TCF_EM_OPND_EQ,
+            TcEmatchOperand::Gt => TCF_EM_OPND_GT,
+            TcEmatchOperand::Lt => TCF_EM_OPND_LT,
+            TcEmatchOperand::Other(d) => d,
+        }
+    }
+}
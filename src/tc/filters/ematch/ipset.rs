@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// IP set ematch (`struct xt_set_info`), matches packets against an ipset.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcEmatchIpset {
+    /// Index of the ipset, could be queried via `IPSET_CMD_GET_BYNAME`.
+    pub index: u16,
+    /// Number of dimensions to match.
+    pub dim: u8,
+    /// Bitmask of dimensions matched against source address/port, others
+    /// are matched against destination.
+    pub flags: u8,
+}
+
+const TCF_EM_IPSET_LEN: usize = 4;
+
+buffer!(TcEmatchIpsetBuffer(TCF_EM_IPSET_LEN) {
+    index: (u16, 0..2),
+    dim: (u8, 2),
+    flags: (u8, 3),
+});
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcEmatchIpsetBuffer<&T>>
+    for TcEmatchIpset
+{
+    type Error = DecodeError;
+    fn parse(buf: &TcEmatchIpsetBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            dim: buf.dim(),
+            flags: buf.flags(),
+        })
+    }
+}
+
+impl Emitable for TcEmatchIpset {
+    fn buffer_len(&self) -> usize {
+        TCF_EM_IPSET_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcEmatchIpsetBuffer::new(buffer);
+        buffer.set_index(self.index);
+        buffer.set_dim(self.dim);
+        buffer.set_flags(self.flags);
+    }
+}
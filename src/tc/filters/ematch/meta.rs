@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::TcEmatchOperand;
+
+const TCA_EM_META_HDR: u16 = 1;
+const TCA_EM_META_LVALUE: u16 = 2;
+const TCA_EM_META_RVALUE: u16 = 3;
+
+/// Attribute of the meta ematch, which compares packet or socket metadata.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcEmatchMetaOption {
+    Header(TcEmatchMetaHeader),
+    /// Value of left operand, only used by [`TcEmatchMetaValue::id`] of
+    /// `TCF_META_ID_VALUE`.
+    LeftValue(Vec<u8>),
+    /// Value of right operand, only used by [`TcEmatchMetaValue::id`] of
+    /// `TCF_META_ID_VALUE`.
+    RightValue(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for TcEmatchMetaOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(v) => v.buffer_len(),
+            Self::LeftValue(v) | Self::RightValue(v) => v.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(v) => v.emit(buffer),
+            Self::LeftValue(v) | Self::RightValue(v) => {
+                buffer.copy_from_slice(v.as_slice())
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => TCA_EM_META_HDR,
+            Self::LeftValue(_) => TCA_EM_META_LVALUE,
+            Self::RightValue(_) => TCA_EM_META_RVALUE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcEmatchMetaOption
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_EM_META_HDR => Self::Header(TcEmatchMetaHeader::parse(
+                &TcEmatchMetaHeaderBuffer::new_checked(payload)?,
+            )?),
+            TCA_EM_META_LVALUE => Self::LeftValue(payload.to_vec()),
+            TCA_EM_META_RVALUE => Self::RightValue(payload.to_vec()),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// Operands of meta ematch (`struct tcf_meta_hdr`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcEmatchMetaHeader {
+    pub left: TcEmatchMetaValue,
+    pub right: TcEmatchMetaValue,
+}
+
+const TCF_META_HDR_LEN: usize = 8;
+
+buffer!(TcEmatchMetaHeaderBuffer(TCF_META_HDR_LEN) {
+    left_kind: (u16, 0..2),
+    left_shift: (u8, 2),
+    left_op: (u8, 3),
+    right_kind: (u16, 4..6),
+    right_shift: (u8, 6),
+    right_op: (u8, 7),
+});
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcEmatchMetaHeaderBuffer<&T>>
+    for TcEmatchMetaHeader
+{
+    type Error = DecodeError;
+    fn parse(buf: &TcEmatchMetaHeaderBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            left: TcEmatchMetaValue::new(
+                buf.left_kind(),
+                buf.left_shift(),
+                buf.left_op(),
+            ),
+            right: TcEmatchMetaValue::new(
+                buf.right_kind(),
+                buf.right_shift(),
+                buf.right_op(),
+            ),
+        })
+    }
+}
+
+impl Emitable for TcEmatchMetaHeader {
+    fn buffer_len(&self) -> usize {
+        TCF_META_HDR_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcEmatchMetaHeaderBuffer::new(buffer);
+        buffer.set_left_kind(self.left.kind());
+        buffer.set_left_shift(self.left.shift);
+        buffer.set_left_op(self.left.op.into());
+        buffer.set_right_kind(self.right.kind());
+        buffer.set_right_shift(self.right.shift);
+        buffer.set_right_op(self.right.op.into());
+    }
+}
+
+const TCF_META_TYPE_SHIFT: u16 = 12;
+const TCF_META_ID_MASK: u16 = 0x7ff;
+
+/// Operand of meta ematch (`struct tcf_meta_val`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcEmatchMetaValue {
+    pub value_type: TcEmatchMetaType,
+    /// Metadata to compare, kernel `TCF_META_ID_*`, e.g. `TCF_META_ID_VALUE`
+    /// (1) for the value held by [`TcEmatchMetaOption::LeftValue`] or
+    /// [`TcEmatchMetaOption::RightValue`], `TCF_META_ID_NFMARK` (11) for
+    /// firewall mark.
+    pub id: u16,
+    /// Right shift applied to the value.
+    pub shift: u8,
+    pub op: TcEmatchOperand,
+}
+
+impl TcEmatchMetaValue {
+    fn new(kind: u16, shift: u8, op: u8) -> Self {
+        Self {
+            value_type: ((kind >> TCF_META_TYPE_SHIFT) as u8).into(),
+            id: kind & TCF_META_ID_MASK,
+            shift,
+            op: op.into(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        ((u8::from(self.value_type) as u16) << TCF_META_TYPE_SHIFT)
+            | (self.id & TCF_META_ID_MASK)
+    }
+}
+
+const TCF_META_TYPE_VAR: u8 = 0;
+const TCF_META_TYPE_INT: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcEmatchMetaType {
+    /// Variable length value, e.g. interface name.
+    #[default]
+    Var,
+    /// Integer value.
+    Int,
+    Other(u8),
+}
+
+impl From<u8> for TcEmatchMetaType {
+    fn from(d: u8) -> Self {
+        match d {
+            TCF_META_TYPE_VAR => Self::Var,
+            TCF_META_TYPE_INT => Self::Int,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcEmatchMetaType> for u8 {
+    fn from(v: TcEmatchMetaType) -> u8 {
+        match v {
+            TcEmatchMetaType::Var => TCF_META_TYPE_VAR,
+            TcEmatchMetaType::Int => TCF_META_TYPE_INT,
+            TcEmatchMetaType::Other(d) => d,
+        }
+    }
+}
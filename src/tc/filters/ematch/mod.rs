@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+
+mod canid;
+mod cmp;
+mod ipset;
+mod meta;
+mod nbyte;
+mod tree;
+
+pub use self::canid::TcEmatchCanFilter;
+pub use self::cmp::{
+    TcEmatchAlign, TcEmatchCmp, TcEmatchLayer, TcEmatchOperand,
+};
+pub use self::ipset::TcEmatchIpset;
+pub use self::meta::{
+    TcEmatchMetaHeader, TcEmatchMetaOption, TcEmatchMetaType, TcEmatchMetaValue,
+};
+pub use self::nbyte::TcEmatchNbyte;
+pub use self::tree::{TcEmatch, TcEmatchData, TcEmatchFlags, TcEmatchTree};
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::TcEmatchLayer;
+
+/// N-byte ematch (`struct tcf_em_nbyte`), compares `needle` against the data
+/// at offset `off` of `layer`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcEmatchNbyte {
+    pub off: u16,
+    pub layer: TcEmatchLayer,
+    /// Up to 4095 bytes to compare with, the kernel `len` field is 12 bits
+    /// wide. Longer needle is truncated to 4095 bytes when emitting.
+    pub needle: Vec<u8>,
+}
+
+impl TcEmatchNbyte {
+    // The part of `needle` fitting into the 12 bits `len` field.
+    fn emit_needle(&self) -> &[u8] {
+        let len = self.needle.len().min(TCF_EM_NBYTE_LEN_MASK as usize);
+        &self.needle[..len]
+    }
+}
+
+const TCF_EM_NBYTE_LEN: usize = 4;
+const TCF_EM_NBYTE_LEN_MASK: u16 = 0xfff;
+
+buffer!(TcEmatchNbyteBuffer(TCF_EM_NBYTE_LEN) {
+    off: (u16, 0..2),
+    // len:12 and layer:4
+    len_layer: (u16, 2..4),
+    needle: (slice, TCF_EM_NBYTE_LEN..),
+});
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<TcEmatchNbyteBuffer<&T>>
+    for TcEmatchNbyte
+{
+    type Error = DecodeError;
+    fn parse(buf: &TcEmatchNbyteBuffer<&T>) -> Result<Self, DecodeError> {
+        let len = (buf.len_layer() & TCF_EM_NBYTE_LEN_MASK) as usize;
+        let needle = buf.needle();
+        if needle.len() < len {
+            return Err(DecodeError::from(format!(
+                "Invalid nbyte ematch, needle length {len} exceeds \
+                 remaining {} bytes",
+                needle.len()
+            )));
+        }
+        Ok(Self {
+            off: buf.off(),
+            layer: ((buf.len_layer() >> 12) as u8).into(),
+            needle: needle[..len].to_vec(),
+        })
+    }
+}
+
+impl Emitable for TcEmatchNbyte {
+    fn buffer_len(&self) -> usize {
+        TCF_EM_NBYTE_LEN + self.emit_needle().len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let needle = self.emit_needle();
+        let mut buffer = TcEmatchNbyteBuffer::new(buffer);
+        buffer.set_off(self.off);
+        buffer.set_len_layer(
+            needle.len() as u16 | ((u8::from(self.layer) as u16) << 12),
+        );
+        buffer.needle_mut()[..needle.len()].copy_from_slice(needle);
+    }
+}
@@ -0,0 +1,380 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    canid::{TcEmatchCanFilterBuffer, CAN_FILTER_LEN},
+    cmp::TcEmatchCmpBuffer,
+    ipset::TcEmatchIpsetBuffer,
+    nbyte::TcEmatchNbyteBuffer,
+    TcEmatchCanFilter, TcEmatchCmp, TcEmatchIpset, TcEmatchMetaOption,
+    TcEmatchNbyte,
+};
+use crate::tc::filters::cls_u32::TcU32KeyBuffer;
+use crate::tc::TcU32Key;
+
+const TCA_EMATCH_TREE_HDR: u16 = 1;
+const TCA_EMATCH_TREE_LIST: u16 = 2;
+
+const TCF_EM_CONTAINER: u16 = 0;
+const TCF_EM_CMP: u16 = 1;
+const TCF_EM_NBYTE: u16 = 2;
+const TCF_EM_U32: u16 = 3;
+const TCF_EM_META: u16 = 4;
+// const TCF_EM_TEXT: u16 = 5;
+// const TCF_EM_VLAN: u16 = 6;
+const TCF_EM_CANID: u16 = 7;
+const TCF_EM_IPSET: u16 = 8;
+// const TCF_EM_IPT: u16 = 9;
+
+/// Extended match tree used by the `basic` and `flow` classifiers.
+///
+/// Matches are evaluated in order, each one combined with the next one
+/// according to its [`TcEmatchFlags`] relation until a match without
+/// relation ends the expression. A [`TcEmatchData::Container`] refers to the
+/// position of the first match of a sub-expression, which is how
+/// parenthesized expressions are built.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcEmatchTree {
+    /// Program ID, `TCF_EM_PROG_TC` (2) for tc.
+    pub progid: u16,
+    pub matches: Vec<TcEmatch>,
+}
+
+const TCF_EM_PROG_TC: u16 = 2;
+
+impl TcEmatchTree {
+    pub fn new(matches: Vec<TcEmatch>) -> Self {
+        Self {
+            progid: TCF_EM_PROG_TC,
+            matches,
+        }
+    }
+}
+
+const TCF_EMATCH_TREE_HDR_LEN: usize = 4;
+
+buffer!(TcEmatchTreeHeaderBuffer(TCF_EMATCH_TREE_HDR_LEN) {
+    nmatches: (u16, 0..2),
+    progid: (u16, 2..4),
+});
+
+// The tree is emitted as the value of TCA_BASIC_EMATCHES or
+// TCA_FLOW_EMATCHES, holding the TCA_EMATCH_TREE_* NLAs.
+impl Emitable for TcEmatchTree {
+    fn buffer_len(&self) -> usize {
+        self.attributes().as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.attributes().as_slice().emit(buffer)
+    }
+}
+
+impl TcEmatchTree {
+    fn attributes(&self) -> Vec<TcEmatchTreeAttribute<'_>> {
+        vec![
+            TcEmatchTreeAttribute::Header(self),
+            TcEmatchTreeAttribute::List(
+                self.matches
+                    .iter()
+                    .enumerate()
+                    .map(|(i, m)| TcEmatchListItem((i + 1) as u16, m))
+                    .collect(),
+            ),
+        ]
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut tree = Self::default();
+        let mut nmatches = 0;
+        for nla in NlasIterator::new(payload) {
+            let nla = nla?;
+            match nla.kind() {
+                TCA_EMATCH_TREE_HDR => {
+                    let hdr =
+                        TcEmatchTreeHeaderBuffer::new_checked(nla.value())?;
+                    nmatches = hdr.nmatches() as usize;
+                    tree.progid = hdr.progid();
+                }
+                TCA_EMATCH_TREE_LIST => {
+                    for item in NlasIterator::new(nla.value()) {
+                        tree.matches.push(TcEmatch::parse(&item?)?);
+                    }
+                }
+                kind => log::warn!("Unknown TCA_EMATCH_TREE NLA {kind}"),
+            }
+        }
+        if nmatches != tree.matches.len() {
+            return Err(DecodeError::from(format!(
+                "Invalid ematch tree, header holds {nmatches} matches, \
+                 got {}",
+                tree.matches.len()
+            )));
+        }
+        Ok(tree)
+    }
+}
+
+enum TcEmatchTreeAttribute<'a> {
+    Header(&'a TcEmatchTree),
+    List(Vec<TcEmatchListItem<'a>>),
+}
+
+impl Nla for TcEmatchTreeAttribute<'_> {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Header(_) => TCF_EMATCH_TREE_HDR_LEN,
+            Self::List(v) => v.as_slice().buffer_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Header(tree) => {
+                let mut buffer = TcEmatchTreeHeaderBuffer::new(buffer);
+                buffer.set_nmatches(tree.matches.len() as u16);
+                buffer.set_progid(tree.progid);
+            }
+            Self::List(v) => v.as_slice().emit(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Header(_) => TCA_EMATCH_TREE_HDR,
+            Self::List(_) => TCA_EMATCH_TREE_LIST,
+        }
+    }
+}
+
+// Kernel requires the NLA type of each match to be its 1-based position.
+struct TcEmatchListItem<'a>(u16, &'a TcEmatch);
+
+impl Nla for TcEmatchListItem<'_> {
+    fn value_len(&self) -> usize {
+        self.1.buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.1.emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        self.0
+    }
+}
+
+const TCF_EM_REL_AND: u16 = 1 << 0;
+const TCF_EM_REL_OR: u16 = 1 << 1;
+const TCF_EM_INVERT: u16 = 1 << 2;
+const TCF_EM_SIMPLE: u16 = 1 << 3;
+
+bitflags! {
+    /// Flags of ematch. A match without `And` or `Or` relation ends the
+    /// (sub-)expression.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[non_exhaustive]
+    pub struct TcEmatchFlags: u16 {
+        /// Logical AND with the next match.
+        const And = TCF_EM_REL_AND;
+        /// Logical OR with the next match.
+        const Or = TCF_EM_REL_OR;
+        /// Negate the result of this match.
+        const Invert = TCF_EM_INVERT;
+        /// Data of the match is a simple `u32`.
+        const Simple = TCF_EM_SIMPLE;
+        const _ = !0;
+    }
+}
+
+/// Single match of [`TcEmatchTree`] (`struct tcf_ematch_hdr` followed by
+/// match specific data).
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct TcEmatch {
+    pub matchid: u16,
+    pub flags: TcEmatchFlags,
+    pub data: TcEmatchData,
+}
+
+impl TcEmatch {
+    pub fn new(data: TcEmatchData) -> Self {
+        Self {
+            matchid: 0,
+            flags: TcEmatchFlags::empty(),
+            data,
+        }
+    }
+
+    /// Combine this match with the next one using logical AND.
+    #[must_use]
+    pub fn and(mut self) -> Self {
+        self.flags.remove(TcEmatchFlags::Or);
+        self.flags.insert(TcEmatchFlags::And);
+        self
+    }
+
+    /// Combine this match with the next one using logical OR.
+    #[must_use]
+    pub fn or(mut self) -> Self {
+        self.flags.remove(TcEmatchFlags::And);
+        self.flags.insert(TcEmatchFlags::Or);
+        self
+    }
+
+    /// Negate the result of this match.
+    #[must_use]
+    pub fn invert(mut self) -> Self {
+        self.flags.toggle(TcEmatchFlags::Invert);
+        self
+    }
+}
+
+const TCF_EMATCH_HDR_LEN: usize = 8;
+
+buffer!(TcEmatchBuffer(TCF_EMATCH_HDR_LEN) {
+    matchid: (u16, 0..2),
+    kind: (u16, 2..4),
+    flags: (u16, 4..6),
+    pad: (u16, 6..8),
+    data: (slice, TCF_EMATCH_HDR_LEN..),
+});
+
+impl Emitable for TcEmatch {
+    fn buffer_len(&self) -> usize {
+        TCF_EMATCH_HDR_LEN + self.data.buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcEmatchBuffer::new(buffer);
+        buffer.set_matchid(self.matchid);
+        buffer.set_kind(self.data.kind());
+        buffer.set_flags(self.flags.bits());
+        buffer.set_pad(0);
+        self.data.emit(buffer.data_mut());
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcEmatch {
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let buf = TcEmatchBuffer::new_checked(buf.value())?;
+        Ok(Self {
+            matchid: buf.matchid(),
+            flags: TcEmatchFlags::from_bits_retain(buf.flags()),
+            data: TcEmatchData::parse(buf.kind(), buf.data())?,
+        })
+    }
+}
+
+/// Match specific data of [`TcEmatch`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcEmatchData {
+    /// Sub-expression starting at specified position of
+    /// [`TcEmatchTree::matches`].
+    Container(u32),
+    Cmp(TcEmatchCmp),
+    Nbyte(TcEmatchNbyte),
+    U32(TcU32Key),
+    Meta(Vec<TcEmatchMetaOption>),
+    /// CAN identifiers matched by the canid ematch. Kernel expects the
+    /// extended frame format (EFF) filters to be placed first.
+    Canid(Vec<TcEmatchCanFilter>),
+    Ipset(TcEmatchIpset),
+    /// Match kind unknown to this crate, holding `TCF_EM_*` kind and data.
+    Other(u16, Vec<u8>),
+}
+
+impl TcEmatchData {
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Container(_) => TCF_EM_CONTAINER,
+            Self::Cmp(_) => TCF_EM_CMP,
+            Self::Nbyte(_) => TCF_EM_NBYTE,
+            Self::U32(_) => TCF_EM_U32,
+            Self::Meta(_) => TCF_EM_META,
+            Self::Canid(_) => TCF_EM_CANID,
+            Self::Ipset(_) => TCF_EM_IPSET,
+            Self::Other(kind, _) => *kind,
+        }
+    }
+
+    fn parse(kind: u16, payload: &[u8]) -> Result<Self, DecodeError> {
+        Ok(match kind {
+            TCF_EM_CONTAINER => Self::Container(parse_u32(payload)?),
+            TCF_EM_CMP => Self::Cmp(TcEmatchCmp::parse(
+                &TcEmatchCmpBuffer::new_checked(payload)?,
+            )?),
+            TCF_EM_NBYTE => Self::Nbyte(TcEmatchNbyte::parse(
+                &TcEmatchNbyteBuffer::new_checked(payload)?,
+            )?),
+            TCF_EM_U32 => Self::U32(TcU32Key::parse(
+                &TcU32KeyBuffer::new_checked(payload)?,
+            )?),
+            TCF_EM_META => {
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    nlas.push(TcEmatchMetaOption::parse(&nla?)?);
+                }
+                Self::Meta(nlas)
+            }
+            TCF_EM_CANID => {
+                let mut filters = vec![];
+                for chunk in payload.chunks(CAN_FILTER_LEN) {
+                    filters.push(TcEmatchCanFilter::parse(
+                        &TcEmatchCanFilterBuffer::new_checked(chunk)?,
+                    )?);
+                }
+                Self::Canid(filters)
+            }
+            TCF_EM_IPSET => Self::Ipset(TcEmatchIpset::parse(
+                &TcEmatchIpsetBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(kind, payload.to_vec()),
+        })
+    }
+}
+
+impl Emitable for TcEmatchData {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Container(_) => 4,
+            Self::Cmp(v) => v.buffer_len(),
+            Self::Nbyte(v) => v.buffer_len(),
+            Self::U32(v) => v.buffer_len(),
+            Self::Meta(v) => v.as_slice().buffer_len(),
+            Self::Canid(v) => v.len() * CAN_FILTER_LEN,
+            Self::Ipset(v) => v.buffer_len(),
+            Self::Other(_, v) => v.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Container(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Cmp(v) => v.emit(buffer),
+            Self::Nbyte(v) => v.emit(buffer),
+            Self::U32(v) => v.emit(buffer),
+            Self::Meta(v) => v.as_slice().emit(buffer),
+            Self::Canid(v) => {
+                for (i, filter) in v.iter().enumerate() {
+                    filter.emit(
+                        &mut buffer
+                            [i * CAN_FILTER_LEN..(i + 1) * CAN_FILTER_LEN],
+                    );
+                }
+            }
+            Self::Ipset(v) => v.emit(buffer),
+            Self::Other(_, v) => buffer.copy_from_slice(v.as_slice()),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: MIT
+
+/// Flow filter
+///
+/// Classifies packets by mapping or hashing a set of flow keys to a class.
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::TcEmatchTree;
+use crate::tc::{TcAction, TcHandle};
+
+const TCA_FLOW_KEYS: u16 = 1;
+const TCA_FLOW_MODE: u16 = 2;
+const TCA_FLOW_BASECLASS: u16 = 3;
+const TCA_FLOW_RSHIFT: u16 = 4;
+const TCA_FLOW_ADDEND: u16 = 5;
+const TCA_FLOW_MASK: u16 = 6;
+const TCA_FLOW_XOR: u16 = 7;
+const TCA_FLOW_DIVISOR: u16 = 8;
+const TCA_FLOW_ACT: u16 = 9;
+const TCA_FLOW_POLICE: u16 = 10;
+const TCA_FLOW_EMATCHES: u16 = 11;
+const TCA_FLOW_PERTURB: u16 = 12;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct TcFilterFlow {}
+
+impl TcFilterFlow {
+    pub const KIND: &'static str = "flow";
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcFilterFlowOption {
+    Keys(TcFlowKeys),
+    Mode(TcFlowMode),
+    BaseClass(TcHandle),
+    Rshift(u32),
+    Addend(u32),
+    Mask(u32),
+    Xor(u32),
+    Divisor(u32),
+    Action(Vec<TcAction>),
+    Police(Vec<u8>),
+    Ematches(TcEmatchTree),
+    /// Hash perturbation period in seconds.
+    Perturb(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterFlowOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Keys(_)
+            | Self::Mode(_)
+            | Self::BaseClass(_)
+            | Self::Rshift(_)
+            | Self::Addend(_)
+            | Self::Mask(_)
+            | Self::Xor(_)
+            | Self::Divisor(_)
+            | Self::Perturb(_) => 4,
+            Self::Action(acts) => acts.as_slice().buffer_len(),
+            Self::Police(b) => b.len(),
+            Self::Ematches(t) => t.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Keys(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::Mode(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::BaseClass(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Rshift(v)
+            | Self::Addend(v)
+            | Self::Mask(v)
+            | Self::Xor(v)
+            | Self::Divisor(v)
+            | Self::Perturb(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Action(acts) => acts.as_slice().emit(buffer),
+            Self::Police(b) => buffer.copy_from_slice(b.as_slice()),
+            Self::Ematches(t) => t.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Keys(_) => TCA_FLOW_KEYS,
+            Self::Mode(_) => TCA_FLOW_MODE,
+            Self::BaseClass(_) => TCA_FLOW_BASECLASS,
+            Self::Rshift(_) => TCA_FLOW_RSHIFT,
+            Self::Addend(_) => TCA_FLOW_ADDEND,
+            Self::Mask(_) => TCA_FLOW_MASK,
+            Self::Xor(_) => TCA_FLOW_XOR,
+            Self::Divisor(_) => TCA_FLOW_DIVISOR,
+            Self::Action(_) => TCA_FLOW_ACT,
+            Self::Police(_) => TCA_FLOW_POLICE,
+            Self::Ematches(_) => TCA_FLOW_EMATCHES,
+            Self::Perturb(_) => TCA_FLOW_PERTURB,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterFlowOption
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FLOW_KEYS => {
+                Self::Keys(TcFlowKeys::from_bits_retain(parse_u32(payload)?))
+            }
+            TCA_FLOW_MODE => Self::Mode(parse_u32(payload)?.into()),
+            TCA_FLOW_BASECLASS => Self::BaseClass(parse_u32(payload)?.into()),
+            TCA_FLOW_RSHIFT => Self::Rshift(parse_u32(payload)?),
+            TCA_FLOW_ADDEND => Self::Addend(parse_u32(payload)?),
+            TCA_FLOW_MASK => Self::Mask(parse_u32(payload)?),
+            TCA_FLOW_XOR => Self::Xor(parse_u32(payload)?),
+            TCA_FLOW_DIVISOR => Self::Divisor(parse_u32(payload)?),
+            TCA_FLOW_ACT => {
+                let mut acts = vec![];
+                for act in NlasIterator::new(payload) {
+                    let act = act?;
+                    acts.push(TcAction::parse(&act)?);
+                }
+                Self::Action(acts)
+            }
+            TCA_FLOW_POLICE => Self::Police(payload.to_vec()),
+            TCA_FLOW_EMATCHES => Self::Ematches(TcEmatchTree::parse(payload)?),
+            TCA_FLOW_PERTURB => Self::Perturb(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const FLOW_MODE_MAP: u32 = 0;
+const FLOW_MODE_HASH: u32 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcFlowMode {
+    /// Map flow keys to class using rshift/addend/mask/xor/divisor.
+    #[default]
+    Map,
+    /// Hash flow keys into divisor buckets.
+    Hash,
+    Other(u32),
+}
+
+impl From<u32> for TcFlowMode {
+    fn from(d: u32) -> Self {
+        match d {
+            FLOW_MODE_MAP => Self::Map,
+            FLOW_MODE_HASH => Self::Hash,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcFlowMode> for u32 {
+    fn from(v: TcFlowMode) -> u32 {
+        match v {
+            TcFlowMode::Map => FLOW_MODE_MAP,
+            TcFlowMode::Hash => FLOW_MODE_HASH,
+            TcFlowMode::Other(d) => d,
+        }
+    }
+}
+
+const FLOW_KEY_SRC: u32 = 1 << 0;
+const FLOW_KEY_DST: u32 = 1 << 1;
+const FLOW_KEY_PROTO: u32 = 1 << 2;
+const FLOW_KEY_PROTO_SRC: u32 = 1 << 3;
+const FLOW_KEY_PROTO_DST: u32 = 1 << 4;
+const FLOW_KEY_IIF: u32 = 1 << 5;
+const FLOW_KEY_PRIORITY: u32 = 1 << 6;
+const FLOW_KEY_MARK: u32 = 1 << 7;
+const FLOW_KEY_NFCT: u32 = 1 << 8;
+const FLOW_KEY_NFCT_SRC: u32 = 1 << 9;
+const FLOW_KEY_NFCT_DST: u32 = 1 << 10;
+const FLOW_KEY_NFCT_PROTO_SRC: u32 = 1 << 11;
+const FLOW_KEY_NFCT_PROTO_DST: u32 = 1 << 12;
+const FLOW_KEY_RTCLASSID: u32 = 1 << 13;
+const FLOW_KEY_SKUID: u32 = 1 << 14;
+const FLOW_KEY_SKGID: u32 = 1 << 15;
+const FLOW_KEY_VLAN_TAG: u32 = 1 << 16;
+const FLOW_KEY_RXHASH: u32 = 1 << 17;
+
+bitflags! {
+    /// Flow keys used by flow filter.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[non_exhaustive]
+    pub struct TcFlowKeys: u32 {
+        const Src = FLOW_KEY_SRC;
+        const Dst = FLOW_KEY_DST;
+        const Proto = FLOW_KEY_PROTO;
+        const ProtoSrc = FLOW_KEY_PROTO_SRC;
+        const ProtoDst = FLOW_KEY_PROTO_DST;
+        const Iif = FLOW_KEY_IIF;
+        const Priority = FLOW_KEY_PRIORITY;
+        const Mark = FLOW_KEY_MARK;
+        const Nfct = FLOW_KEY_NFCT;
+        const NfctSrc = FLOW_KEY_NFCT_SRC;
+        const NfctDst = FLOW_KEY_NFCT_DST;
+        const NfctProtoSrc = FLOW_KEY_NFCT_PROTO_SRC;
+        const NfctProtoDst = FLOW_KEY_NFCT_PROTO_DST;
+        const RtClassId = FLOW_KEY_RTCLASSID;
+        const SkUid = FLOW_KEY_SKUID;
+        const SkGid = FLOW_KEY_SKGID;
+        const VlanTag = FLOW_KEY_VLAN_TAG;
+        const RxHash = FLOW_KEY_RXHASH;
+        const _ = !0;
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: MIT
+
+/// Firewall mark filter
+///
+/// Classifies packets by their firewall mark (`skb->mark`), optionally
+/// masked.
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tc::{TcAction, TcHandle};
+
+const TCA_FW_CLASSID: u16 = 1;
+const TCA_FW_POLICE: u16 = 2;
+const TCA_FW_INDEV: u16 = 3;
+const TCA_FW_ACT: u16 = 4;
+const TCA_FW_MASK: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct TcFilterFw {}
+
+impl TcFilterFw {
+    pub const KIND: &'static str = "fw";
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcFilterFwOption {
+    ClassId(TcHandle),
+    Police(Vec<u8>),
+    /// Only match packets received on this interface.
+    Indev(String),
+    Action(Vec<TcAction>),
+    /// Mask applied to firewall mark before matching it against the filter
+    /// handle.
+    Mask(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterFwOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ClassId(_) | Self::Mask(_) => 4,
+            Self::Police(b) => b.len(),
+            Self::Indev(s) => s.len() + 1,
+            Self::Action(acts) => acts.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ClassId(i) => NativeEndian::write_u32(buffer, (*i).into()),
+            Self::Mask(i) => NativeEndian::write_u32(buffer, *i),
+            Self::Police(b) => buffer.copy_from_slice(b.as_slice()),
+            Self::Indev(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Action(acts) => acts.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ClassId(_) => TCA_FW_CLASSID,
+            Self::Police(_) => TCA_FW_POLICE,
+            Self::Indev(_) => TCA_FW_INDEV,
+            Self::Action(_) => TCA_FW_ACT,
+            Self::Mask(_) => TCA_FW_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterFwOption
+{
+    type Error = DecodeError;
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FW_CLASSID => Self::ClassId(parse_u32(payload)?.into()),
+            TCA_FW_POLICE => Self::Police(payload.to_vec()),
+            TCA_FW_INDEV => Self::Indev(parse_string(payload)?),
+            TCA_FW_ACT => {
+                let mut acts = vec![];
+                for act in NlasIterator::new(payload) {
+                    let act = act?;
+                    acts.push(TcAction::parse(&act)?);
+                }
+                Self::Action(acts)
+            }
+            TCA_FW_MASK => Self::Mask(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
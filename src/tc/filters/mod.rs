@@ -1,17 +1,34 @@
 // SPDX-License-Identifier: MIT
 
+mod basic;
+mod cls_route;
 mod cls_u32;
+mod ematch;
+mod flow;
 mod flower;
+mod fw;
 mod matchall;
 mod u32_flags;
 
+pub use self::basic::{TcFilterBasic, TcFilterBasicOption};
+pub use self::cls_route::{TcFilterRoute, TcFilterRouteOption};
 pub use self::cls_u32::{
     TcFilterU32, TcFilterU32Option, TcU32Key, TcU32Selector,
     TcU32SelectorBuffer,
 };
+pub use self::ematch::{
+    TcEmatch, TcEmatchAlign, TcEmatchCanFilter, TcEmatchCmp, TcEmatchData,
+    TcEmatchFlags, TcEmatchIpset, TcEmatchLayer, TcEmatchMetaHeader,
+    TcEmatchMetaOption, TcEmatchMetaType, TcEmatchMetaValue, TcEmatchNbyte,
+    TcEmatchOperand, TcEmatchTree,
+};
+pub use self::flow::{
+    TcFilterFlow, TcFilterFlowOption, TcFlowKeys, TcFlowMode,
+};
 pub use self::flower::{
     TcFilterFlower, TcFilterFlowerMplsLseOption, TcFilterFlowerMplsOption,
     TcFilterFlowerOption,
 };
+pub use self::fw::{TcFilterFw, TcFilterFwOption};
 pub use self::matchall::{TcFilterMatchAll, TcFilterMatchAllOption};
 pub use u32_flags::{TcU32OptionFlags, TcU32SelectorFlags};
@@ -2,6 +2,7 @@
 
 mod actions;
 mod attribute;
+mod extension;
 mod filters;
 mod header;
 mod message;
@@ -10,20 +11,28 @@ mod qdiscs;
 mod stats;
 
 pub use self::actions::{
-    TcAction, TcActionAttribute, TcActionGeneric, TcActionGenericBuffer,
-    TcActionMessage, TcActionMessageAttribute, TcActionMessageBuffer,
-    TcActionMessageFlags, TcActionMessageFlagsWithSelector, TcActionMirror,
-    TcActionMirrorOption, TcActionNat, TcActionNatOption, TcActionOption,
-    TcActionTunnelKey, TcActionTunnelKeyOption, TcActionType, TcMirror,
-    TcMirrorActionType, TcMirrorBuffer, TcNat, TcNatBuffer, TcNatFlags,
-    TcTunnelKey, Tcf,
+    TcAction, TcActionAttribute, TcActionFlags, TcActionFlagsWithSelector,
+    TcActionGeneric, TcActionGenericBuffer, TcActionHwStatsType,
+    TcActionHwStatsWithSelector, TcActionMessage, TcActionMessageAttribute,
+    TcActionMessageBuffer, TcActionMessageFlags,
+    TcActionMessageFlagsWithSelector, TcActionMirror, TcActionMirrorOption,
+    TcActionNat, TcActionNatOption, TcActionOption, TcActionTunnelKey,
+    TcActionTunnelKeyOption, TcActionType, TcMirror, TcMirrorActionType,
+    TcMirrorBuffer, TcNat, TcNatBuffer, TcNatFlags, TcTunnelKey, Tcf,
 };
 pub use self::attribute::TcAttribute;
+pub use self::extension::{TcExtension, TcExtensionNla, TcExtensionParser};
 pub use self::filters::{
-    TcFilterFlower, TcFilterFlowerMplsLseOption, TcFilterFlowerMplsOption,
-    TcFilterFlowerOption, TcFilterMatchAll, TcFilterMatchAllOption,
-    TcFilterU32, TcFilterU32Option, TcU32Key, TcU32OptionFlags, TcU32Selector,
-    TcU32SelectorBuffer, TcU32SelectorFlags,
+    TcEmatch, TcEmatchAlign, TcEmatchCanFilter, TcEmatchCmp, TcEmatchData,
+    TcEmatchFlags, TcEmatchIpset, TcEmatchLayer, TcEmatchMetaHeader,
+    TcEmatchMetaOption, TcEmatchMetaType, TcEmatchMetaValue, TcEmatchNbyte,
+    TcEmatchOperand, TcEmatchTree, TcFilterBasic, TcFilterBasicOption,
+    TcFilterFlow, TcFilterFlowOption, TcFilterFlower,
+    TcFilterFlowerMplsLseOption, TcFilterFlowerMplsOption,
+    TcFilterFlowerOption, TcFilterFw, TcFilterFwOption, TcFilterMatchAll,
+    TcFilterMatchAllOption, TcFilterRoute, TcFilterRouteOption, TcFilterU32,
+    TcFilterU32Option, TcFlowKeys, TcFlowMode, TcU32Key, TcU32OptionFlags,
+    TcU32Selector, TcU32SelectorBuffer, TcU32SelectorFlags,
 };
 pub use self::header::{TcHandle, TcHeader, TcMessageBuffer};
 pub use self::message::TcMessage;
@@ -35,7 +44,8 @@ pub use self::qdiscs::{
 };
 pub use self::stats::{
     TcStats, TcStats2, TcStatsBasic, TcStatsBasicBuffer, TcStatsBuffer,
-    TcStatsQueue, TcStatsQueueBuffer, TcXstats,
+    TcStatsQueue, TcStatsQueueBuffer, TcStatsRateEst, TcStatsRateEst64,
+    TcStatsRateEst64Buffer, TcStatsRateEstBuffer, TcXstats,
 };
 
 pub(crate) use self::options::VecTcOption;
@@ -7,8 +7,10 @@ use netlink_packet_utils::{
 };
 
 use super::{
-    TcFilterFlower, TcFilterFlowerOption, TcFilterMatchAll,
-    TcFilterMatchAllOption, TcFilterU32, TcFilterU32Option, TcQdiscFqCodel,
+    TcExtensionNla, TcFilterBasic, TcFilterBasicOption, TcFilterFlow,
+    TcFilterFlowOption, TcFilterFlower, TcFilterFlowerOption, TcFilterFw,
+    TcFilterFwOption, TcFilterMatchAll, TcFilterMatchAllOption, TcFilterRoute,
+    TcFilterRouteOption, TcFilterU32, TcFilterU32Option, TcQdiscFqCodel,
     TcQdiscFqCodelOption, TcQdiscIngress, TcQdiscIngressOption,
 };
 
@@ -23,6 +25,12 @@ pub enum TcOption {
     U32(TcFilterU32Option),
     // matchall options
     MatchAll(TcFilterMatchAllOption),
+    Fw(TcFilterFwOption),
+    Route(TcFilterRouteOption),
+    Basic(TcFilterBasicOption),
+    Flow(TcFilterFlowOption),
+    // Options of kind unknown to this crate, built by the user
+    Extension(TcExtensionNla),
     // Other options
     Other(DefaultNla),
 }
@@ -35,6 +43,11 @@ impl Nla for TcOption {
             Self::U32(u) => u.value_len(),
             Self::Flower(u) => u.value_len(),
             Self::MatchAll(m) => m.value_len(),
+            Self::Fw(u) => u.value_len(),
+            Self::Route(u) => u.value_len(),
+            Self::Basic(u) => u.value_len(),
+            Self::Flow(u) => u.value_len(),
+            Self::Extension(e) => e.value_len(),
             Self::Other(o) => o.value_len(),
         }
     }
@@ -46,6 +59,11 @@ impl Nla for TcOption {
             Self::Flower(u) => u.emit_value(buffer),
             Self::U32(u) => u.emit_value(buffer),
             Self::MatchAll(m) => m.emit_value(buffer),
+            Self::Fw(u) => u.emit_value(buffer),
+            Self::Route(u) => u.emit_value(buffer),
+            Self::Basic(u) => u.emit_value(buffer),
+            Self::Flow(u) => u.emit_value(buffer),
+            Self::Extension(e) => e.emit_value(buffer),
             Self::Other(o) => o.emit_value(buffer),
         }
     }
@@ -57,6 +75,11 @@ impl Nla for TcOption {
             Self::Flower(u) => u.kind(),
             Self::U32(u) => u.kind(),
             Self::MatchAll(m) => m.kind(),
+            Self::Fw(u) => u.kind(),
+            Self::Route(u) => u.kind(),
+            Self::Basic(u) => u.kind(),
+            Self::Flow(u) => u.kind(),
+            Self::Extension(e) => e.kind(),
             Self::Other(o) => o.kind(),
         }
     }
@@ -85,6 +108,14 @@ where
             TcFilterMatchAll::KIND => {
                 Self::MatchAll(TcFilterMatchAllOption::parse(buf)?)
             }
+            TcFilterFw::KIND => Self::Fw(TcFilterFwOption::parse(buf)?),
+            TcFilterRoute::KIND => {
+                Self::Route(TcFilterRouteOption::parse(buf)?)
+            }
+            TcFilterBasic::KIND => {
+                Self::Basic(TcFilterBasicOption::parse(buf)?)
+            }
+            TcFilterFlow::KIND => Self::Flow(TcFilterFlowOption::parse(buf)?),
             _ => Self::Other(DefaultNla::parse(buf)?),
         })
     }
@@ -105,6 +136,10 @@ where
             TcFilterU32::KIND
             | TcFilterMatchAll::KIND
             | TcFilterFlower::KIND
+            | TcFilterFw::KIND
+            | TcFilterRoute::KIND
+            | TcFilterBasic::KIND
+            | TcFilterFlow::KIND
             | TcQdiscIngress::KIND
             | TcQdiscFqCodel::KIND => {
                 let mut nlas = vec![];
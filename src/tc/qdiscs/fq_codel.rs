@@ -47,12 +47,12 @@ impl<T: AsRef<[u8]> + ?Sized> Parseable<T> for TcFqCodelXstats {
         match buf_type {
             TCA_FQ_CODEL_XSTATS_QDISC => {
                 Ok(Self::Qdisc(TcFqCodelQdStats::parse(
-                    &TcFqCodelQdStatsBuffer::new(&buf.as_ref()[4..]),
+                    &TcFqCodelQdStatsBuffer::new_checked(&buf.as_ref()[4..])?,
                 )?))
             }
             TCA_FQ_CODEL_XSTATS_CLASS => {
                 Ok(Self::Class(TcFqCodelClStats::parse(
-                    &TcFqCodelClStatsBuffer::new(&buf.as_ref()[4..]),
+                    &TcFqCodelClStatsBuffer::new_checked(&buf.as_ref()[4..])?,
                 )?))
             }
             _ => Ok(Self::Other(buf.as_ref().to_vec())),
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Rate estimator (`gnet_stats_rate_est`)
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub struct TcStatsRateEst {
+    /// bytes per second
+    pub bps: u32,
+    /// packets per second
+    pub pps: u32,
+}
+
+const STATS_RATE_EST_LEN: usize = 8;
+
+buffer!(TcStatsRateEstBuffer(STATS_RATE_EST_LEN) {
+    bps: (u32, 0..4),
+    pps: (u32, 4..8),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcStatsRateEstBuffer<T>> for TcStatsRateEst {
+    type Error = DecodeError;
+    fn parse(buf: &TcStatsRateEstBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bps: buf.bps(),
+            pps: buf.pps(),
+        })
+    }
+}
+
+impl Emitable for TcStatsRateEst {
+    fn buffer_len(&self) -> usize {
+        STATS_RATE_EST_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcStatsRateEstBuffer::new(buffer);
+        buffer.set_bps(self.bps);
+        buffer.set_pps(self.pps);
+    }
+}
+
+/// 64 bits rate estimator (`gnet_stats_rate_est64`)
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub struct TcStatsRateEst64 {
+    /// bytes per second
+    pub bps: u64,
+    /// packets per second
+    pub pps: u64,
+}
+
+const STATS_RATE_EST64_LEN: usize = 16;
+
+buffer!(TcStatsRateEst64Buffer(STATS_RATE_EST64_LEN) {
+    bps: (u64, 0..8),
+    pps: (u64, 8..16),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcStatsRateEst64Buffer<T>> for TcStatsRateEst64 {
+    type Error = DecodeError;
+    fn parse(buf: &TcStatsRateEst64Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bps: buf.bps(),
+            pps: buf.pps(),
+        })
+    }
+}
+
+impl Emitable for TcStatsRateEst64 {
+    fn buffer_len(&self) -> usize {
+        STATS_RATE_EST64_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcStatsRateEst64Buffer::new(buffer);
+        buffer.set_bps(self.bps);
+        buffer.set_pps(self.pps);
+    }
+}
@@ -1,23 +1,27 @@
 // SPDX-License-Identifier: MIT
 
+use byteorder::{ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u64,
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
 use super::{
     TcStatsBasic, TcStatsBasicBuffer, TcStatsQueue, TcStatsQueueBuffer,
-    TcXstats,
+    TcStatsRateEst, TcStatsRateEst64, TcStatsRateEst64Buffer,
+    TcStatsRateEstBuffer, TcXstats,
 };
 
 const TCA_STATS_BASIC: u16 = 1;
-// const TCA_STATS_RATE_EST: u16 = 2; // TODO
+const TCA_STATS_RATE_EST: u16 = 2;
 const TCA_STATS_QUEUE: u16 = 3;
 const TCA_STATS_APP: u16 = 4;
-// const TCA_STATS_RATE_EST64: u16 = 5; // TODO
+const TCA_STATS_RATE_EST64: u16 = 5;
 // const TCA_STATS_PAD: u16 = 6;
 const TCA_STATS_BASIC_HW: u16 = 7;
+const TCA_STATS_PKT64: u16 = 8;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -26,6 +30,11 @@ pub enum TcStats2 {
     Basic(TcStatsBasic),
     Queue(TcStatsQueue),
     BasicHw(TcStatsBasic),
+    RateEst(TcStatsRateEst),
+    RateEst64(TcStatsRateEst64),
+    /// 64 bits packet counter, kernel sends it along with [`Self::Basic`]
+    /// when the packet count overflows 32 bits.
+    Pkt64(u64),
     Other(DefaultNla),
 }
 
@@ -36,6 +45,9 @@ impl Nla for TcStats2 {
             Self::Basic(v) => v.buffer_len(),
             Self::Queue(v) => v.buffer_len(),
             Self::BasicHw(v) => v.buffer_len(),
+            Self::RateEst(v) => v.buffer_len(),
+            Self::RateEst64(v) => v.buffer_len(),
+            Self::Pkt64(_) => 8,
             Self::Other(ref nla) => nla.value_len(),
         }
     }
@@ -46,6 +58,9 @@ impl Nla for TcStats2 {
             Self::Basic(v) => v.emit(buffer),
             Self::Queue(v) => v.emit(buffer),
             Self::BasicHw(v) => v.emit(buffer),
+            Self::RateEst(v) => v.emit(buffer),
+            Self::RateEst64(v) => v.emit(buffer),
+            Self::Pkt64(v) => NativeEndian::write_u64(buffer, *v),
             Self::Other(ref nla) => nla.emit_value(buffer),
         }
     }
@@ -56,6 +71,9 @@ impl Nla for TcStats2 {
             Self::Basic(_) => TCA_STATS_BASIC,
             Self::Queue(_) => TCA_STATS_QUEUE,
             Self::BasicHw(_) => TCA_STATS_BASIC_HW,
+            Self::RateEst(_) => TCA_STATS_RATE_EST,
+            Self::RateEst64(_) => TCA_STATS_RATE_EST64,
+            Self::Pkt64(_) => TCA_STATS_PKT64,
             Self::Other(ref nla) => nla.kind(),
         }
     }
@@ -74,14 +92,21 @@ where
         Ok(match buf.kind() {
             TCA_STATS_APP => Self::App(TcXstats::parse_with_param(buf, kind)?),
             TCA_STATS_BASIC => Self::Basic(TcStatsBasic::parse(
-                &TcStatsBasicBuffer::new(payload),
+                &TcStatsBasicBuffer::new_checked(payload)?,
             )?),
             TCA_STATS_QUEUE => Self::Queue(TcStatsQueue::parse(
-                &TcStatsQueueBuffer::new(payload),
+                &TcStatsQueueBuffer::new_checked(payload)?,
             )?),
             TCA_STATS_BASIC_HW => Self::BasicHw(TcStatsBasic::parse(
-                &TcStatsBasicBuffer::new(payload),
+                &TcStatsBasicBuffer::new_checked(payload)?,
             )?),
+            TCA_STATS_RATE_EST => Self::RateEst(TcStatsRateEst::parse(
+                &TcStatsRateEstBuffer::new_checked(payload)?,
+            )?),
+            TCA_STATS_RATE_EST64 => Self::RateEst64(TcStatsRateEst64::parse(
+                &TcStatsRateEst64Buffer::new_checked(payload)?,
+            )?),
+            TCA_STATS_PKT64 => Self::Pkt64(parse_u64(payload)?),
             _ => Self::Other(DefaultNla::parse(buf)?),
         })
     }
@@ -40,11 +40,20 @@ where
         buf: &NlaBuffer<&'a T>,
         kind: &str,
     ) -> Result<TcXstats, DecodeError> {
-        Ok(match kind {
+        let payload = buf.value();
+        // The same TCA_STATS_APP/TCA_XSTATS attribute is used by qdiscs,
+        // classes and actions, its layout is only known to the owner of
+        // `kind`. Keep the raw bytes if they do not match the layout we
+        // expect instead of failing the whole message.
+        let parsed = match kind {
             TcQdiscFqCodel::KIND => {
-                TcXstats::FqCodel(TcFqCodelXstats::parse(buf.value())?)
+                TcFqCodelXstats::parse(payload).map(TcXstats::FqCodel)
             }
-            _ => TcXstats::Other(buf.value().to_vec()),
-        })
+            _ => return Ok(TcXstats::Other(payload.to_vec())),
+        };
+        Ok(parsed.unwrap_or_else(|e| {
+            log::warn!("Failed to parse xstats of {kind}: {e}");
+            TcXstats::Other(payload.to_vec())
+        }))
     }
 }
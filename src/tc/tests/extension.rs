@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use std::any::Any;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::NlaBuffer, parsers::parse_u32, DecodeError, Emitable, Parseable,
+};
+
+use crate::tc::{
+    TcAction, TcActionAttribute, TcActionOption, TcAttribute, TcExtension,
+    TcExtensionNla, TcHandle, TcHeader, TcMessage, TcMessageBuffer, TcOption,
+};
+
+const TCA_EXAMPLE_LIMIT: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq)]
+struct ExampleLimit(u32);
+
+impl TcExtension for ExampleLimit {
+    fn kind(&self) -> u16 {
+        TCA_EXAMPLE_LIMIT
+    }
+
+    fn value_len(&self) -> usize {
+        4
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(buffer, self.0)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn parse_example(
+    buf: &NlaBuffer<&[u8]>,
+) -> Result<Box<dyn TcExtension>, DecodeError> {
+    match buf.kind() {
+        TCA_EXAMPLE_LIMIT => {
+            Ok(Box::new(ExampleLimit(parse_u32(buf.value())?)))
+        }
+        kind => Err(format!("unknown example option {kind}").into()),
+    }
+}
+
+#[test]
+fn test_qdisc_extension() {
+    let expected = TcMessage {
+        header: TcHeader {
+            index: 2,
+            handle: TcHandle { major: 1, minor: 0 },
+            parent: TcHandle::ROOT,
+            ..Default::default()
+        },
+        attributes: vec![
+            TcAttribute::Kind("example_qdisc".to_string()),
+            TcAttribute::Options(vec![TcOption::Extension(
+                TcExtensionNla::new(ExampleLimit(1000)),
+            )]),
+        ],
+    };
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+
+    let parsed = TcMessage::parse(&TcMessageBuffer::new(&buf)).unwrap();
+    let TcAttribute::Options(options) = &parsed.attributes[1] else {
+        panic!("Options not found")
+    };
+    let [TcOption::Other(nla)] = options.as_slice() else {
+        panic!("Unknown kind should be parsed as single Other")
+    };
+    let options =
+        TcExtensionNla::parse_nested_with(nla, &parse_example).unwrap();
+    assert_eq!(options, vec![TcExtensionNla::new(ExampleLimit(1000))]);
+    assert_eq!(
+        options[0].downcast_ref::<ExampleLimit>(),
+        Some(&ExampleLimit(1000))
+    );
+}
+
+#[test]
+fn test_action_extension() {
+    let expected = TcAction {
+        tab: 1,
+        attributes: vec![
+            TcActionAttribute::Kind("example_act".to_string()),
+            TcActionAttribute::Options(vec![TcActionOption::Extension(
+                TcExtensionNla::new(ExampleLimit(7)),
+            )]),
+        ],
+    };
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+
+    let parsed =
+        TcAction::parse(&NlaBuffer::new_checked(buf.as_slice()).unwrap())
+            .unwrap();
+    let TcActionAttribute::Options(options) = &parsed.attributes[1] else {
+        panic!("Options not found")
+    };
+    let [TcActionOption::Other(nla)] = options.as_slice() else {
+        panic!("Unknown kind should be parsed as Other")
+    };
+    assert_eq!(
+        TcExtensionNla::parse_with(nla, &parse_example).unwrap(),
+        TcExtensionNla::new(ExampleLimit(7))
+    );
+}
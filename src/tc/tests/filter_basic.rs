@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    Emitable, Parseable,
+};
+
+use crate::tc::{
+    TcEmatch, TcEmatchAlign, TcEmatchCanFilter, TcEmatchCmp, TcEmatchData,
+    TcEmatchFlags, TcEmatchIpset, TcEmatchLayer, TcEmatchMetaHeader,
+    TcEmatchMetaOption, TcEmatchMetaType, TcEmatchMetaValue, TcEmatchNbyte,
+    TcEmatchOperand, TcEmatchTree, TcFilterBasicOption, TcHandle, TcU32Key,
+};
+
+fn parse_options(raw: &[u8]) -> Vec<TcFilterBasicOption> {
+    NlasIterator::new(raw)
+        .map(|nla| TcFilterBasicOption::parse(&nla.unwrap()).unwrap())
+        .collect()
+}
+
+// Options of
+//      tc filter add dev dummy1 parent 1: basic \
+//          match 'cmp(u16 at 0 layer network eq 0x4500)' classid 1:1
+#[test]
+fn test_basic_cmp() {
+    let raw = vec![
+        0x08, 0x00, 0x01, 0x00, // TCA_BASIC_CLASSID
+        0x01, 0x00, 0x01, 0x00, // 1:1
+        0x28, 0x00, 0x02, 0x00, // TCA_BASIC_EMATCHES
+        0x08, 0x00, 0x01, 0x00, // TCA_EMATCH_TREE_HDR
+        0x01, 0x00, 0x02, 0x00, // nmatches 1, progid TCF_EM_PROG_TC
+        0x1c, 0x00, 0x02, 0x00, // TCA_EMATCH_TREE_LIST
+        0x18, 0x00, 0x01, 0x00, // match 1
+        0x00, 0x00, 0x01, 0x00, // matchid 0, kind TCF_EM_CMP
+        0x00, 0x00, 0x00, 0x00, // flags TCF_EM_REL_END, pad
+        0x00, 0x45, 0x00, 0x00, // val 0x4500
+        0x00, 0x00, 0x00, 0x00, // mask
+        0x00, 0x00, // off
+        0x02, // align TCF_EM_ALIGN_U16, flags 0
+        0x01, // layer TCF_LAYER_NETWORK, opnd TCF_EM_OPND_EQ
+    ];
+
+    let expected = vec![
+        TcFilterBasicOption::ClassId(TcHandle { major: 1, minor: 1 }),
+        TcFilterBasicOption::Ematches(TcEmatchTree::new(vec![TcEmatch::new(
+            TcEmatchData::Cmp(TcEmatchCmp {
+                val: 0x4500,
+                mask: 0,
+                off: 0,
+                align: TcEmatchAlign::U16,
+                trans: false,
+                layer: TcEmatchLayer::Network,
+                opnd: TcEmatchOperand::Eq,
+            }),
+        )])),
+    ];
+
+    assert_eq!(parse_options(&raw), expected);
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+// Expression equivalent of
+//      meta(nf_mark gt 10) and not (nbyte("ab" at 2) or u32(...)) and
+//      canid(sff 0x123) and ipset(foo src)
+#[test]
+fn test_basic_ematch_tree_parse_back() {
+    let tree = TcEmatchTree::new(vec![
+        TcEmatch::new(TcEmatchData::Meta(vec![
+            TcEmatchMetaOption::Header(TcEmatchMetaHeader {
+                left: TcEmatchMetaValue {
+                    value_type: TcEmatchMetaType::Int,
+                    id: 11,
+                    shift: 0,
+                    op: TcEmatchOperand::Gt,
+                },
+                right: TcEmatchMetaValue {
+                    value_type: TcEmatchMetaType::Int,
+                    id: 1,
+                    shift: 0,
+                    op: TcEmatchOperand::Eq,
+                },
+            }),
+            TcEmatchMetaOption::RightValue(vec![10, 0, 0, 0]),
+        ]))
+        .and(),
+        TcEmatch::new(TcEmatchData::Container(4)).invert().and(),
+        TcEmatch::new(TcEmatchData::Canid(vec![TcEmatchCanFilter {
+            can_id: 0x123,
+            can_mask: 0x7ff,
+        }]))
+        .and(),
+        TcEmatch::new(TcEmatchData::Ipset(TcEmatchIpset {
+            index: 3,
+            dim: 1,
+            flags: 1,
+        })),
+        TcEmatch::new(TcEmatchData::Nbyte(TcEmatchNbyte {
+            off: 2,
+            layer: TcEmatchLayer::Transport,
+            needle: b"ab".to_vec(),
+        }))
+        .or(),
+        TcEmatch::new(TcEmatchData::U32(TcU32Key {
+            mask: 0xffff0000,
+            val: 0x00160000,
+            off: 0,
+            offmask: 0,
+        })),
+    ]);
+    assert_eq!(
+        tree.matches[1].flags,
+        TcEmatchFlags::And | TcEmatchFlags::Invert
+    );
+
+    let orig = TcFilterBasicOption::Ematches(tree);
+    let mut buf = vec![0; orig.buffer_len()];
+    orig.emit(&mut buf);
+    let parsed =
+        TcFilterBasicOption::parse(&NlaBuffer::new_checked(&buf).unwrap())
+            .unwrap();
+    assert_eq!(parsed, orig);
+}
+
+#[test]
+fn test_basic_ematch_tree_nmatches_mismatch() {
+    let raw = vec![
+        0x10, 0x00, 0x02, 0x00, // TCA_BASIC_EMATCHES
+        0x08, 0x00, 0x01, 0x00, // TCA_EMATCH_TREE_HDR
+        0x02, 0x00, 0x02, 0x00, // nmatches 2, progid TCF_EM_PROG_TC
+        0x04, 0x00, 0x02, 0x00, // empty TCA_EMATCH_TREE_LIST
+    ];
+    assert!(TcFilterBasicOption::parse(
+        &NlaBuffer::new_checked(raw.as_slice()).unwrap()
+    )
+    .is_err());
+}
+
+#[test]
+fn test_basic_ematch_nbyte_needle_len_limit() {
+    for (needle_len, emitted_len) in [(4095, 4095), (4096, 4095)] {
+        let nbyte = TcEmatchNbyte {
+            off: 0,
+            layer: TcEmatchLayer::Network,
+            needle: vec![0xab; needle_len],
+        };
+        let mut buf = vec![0; nbyte.buffer_len()];
+        nbyte.emit(&mut buf);
+
+        assert_eq!(buf.len(), 4 + emitted_len);
+        // len:12 and layer:4
+        assert_eq!(
+            u16::from_ne_bytes([buf[2], buf[3]]),
+            emitted_len as u16
+                | (u8::from(TcEmatchLayer::Network) as u16) << 12
+        );
+        assert_eq!(&buf[4..], &nbyte.needle[..emitted_len]);
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{nla::NlasIterator, Emitable, Parseable};
+
+use crate::tc::{
+    TcFilterFlowOption, TcFilterFwOption, TcFilterRouteOption, TcFlowKeys,
+    TcFlowMode, TcHandle,
+};
+
+// Options of
+//      tc filter add dev dummy1 parent 1: handle 0x10/0xff fw classid 1:10
+#[test]
+fn test_fw_options() {
+    let raw = vec![
+        0x08, 0x00, 0x01, 0x00, // TCA_FW_CLASSID
+        0x10, 0x00, 0x01, 0x00, // 1:10
+        0x08, 0x00, 0x05, 0x00, // TCA_FW_MASK
+        0xff, 0x00, 0x00, 0x00, // 0xff
+        0x09, 0x00, 0x03, 0x00, // TCA_FW_INDEV
+        0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00, 0x00, // "eth0"
+    ];
+    let expected = vec![
+        TcFilterFwOption::ClassId(TcHandle {
+            major: 1,
+            minor: 0x10,
+        }),
+        TcFilterFwOption::Mask(0xff),
+        TcFilterFwOption::Indev("eth0".to_string()),
+    ];
+
+    let parsed: Vec<TcFilterFwOption> = NlasIterator::new(&raw)
+        .map(|nla| TcFilterFwOption::parse(&nla.unwrap()).unwrap())
+        .collect();
+    assert_eq!(parsed, expected);
+
+    let mut buf = vec![0; expected.as_slice().buffer_len()];
+    expected.as_slice().emit(&mut buf);
+    assert_eq!(buf, raw);
+}
+
+#[test]
+fn test_route_options_parse_back() {
+    let orig = vec![
+        TcFilterRouteOption::ClassId(TcHandle { major: 1, minor: 2 }),
+        TcFilterRouteOption::To(10),
+        TcFilterRouteOption::From(20),
+    ];
+    let mut buf = vec![0; orig.as_slice().buffer_len()];
+    orig.as_slice().emit(&mut buf);
+    let parsed: Vec<TcFilterRouteOption> = NlasIterator::new(&buf)
+        .map(|nla| TcFilterRouteOption::parse(&nla.unwrap()).unwrap())
+        .collect();
+    assert_eq!(parsed, orig);
+}
+
+#[test]
+fn test_flow_options_parse_back() {
+    let orig = vec![
+        TcFilterFlowOption::Keys(TcFlowKeys::Src | TcFlowKeys::ProtoSrc),
+        TcFilterFlowOption::Mode(TcFlowMode::Hash),
+        TcFilterFlowOption::BaseClass(TcHandle { major: 1, minor: 1 }),
+        TcFilterFlowOption::Divisor(1024),
+        TcFilterFlowOption::Perturb(10),
+    ];
+    let mut buf = vec![0; orig.as_slice().buffer_len()];
+    orig.as_slice().emit(&mut buf);
+    let parsed: Vec<TcFilterFlowOption> = NlasIterator::new(&buf)
+        .map(|nla| TcFilterFlowOption::parse(&nla.unwrap()).unwrap())
+        .collect();
+    assert_eq!(parsed, orig);
+}
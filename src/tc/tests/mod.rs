@@ -1,8 +1,14 @@
 // SPDX-License-Identifier: MIT
 
+#[cfg(test)]
+mod extension;
+#[cfg(test)]
+mod filter_basic;
 #[cfg(test)]
 mod filter_flower;
 #[cfg(test)]
+mod filter_fw;
+#[cfg(test)]
 mod filter_matchall;
 #[cfg(test)]
 mod filter_u32;
@@ -10,3 +16,5 @@ mod filter_u32;
 mod qdisc_fq_codel;
 #[cfg(test)]
 mod qdisc_ingress;
+#[cfg(test)]
+mod stats2;